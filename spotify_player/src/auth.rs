@@ -10,6 +10,19 @@ use librespot_core::{
 
 use crate::config;
 
+#[derive(Debug)]
+/// An error of the cached credentials being missing or rejected,
+/// which requires re-authenticating the application
+pub struct InvalidCredentialsError(String);
+
+impl std::fmt::Display for InvalidCredentialsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidCredentialsError {}
+
 #[derive(Clone)]
 pub struct AuthConfig {
     pub cache: Cache,
@@ -64,6 +77,23 @@ fn read_user_auth_details(user: Option<String>) -> Result<(String, String)> {
     Ok((username, password))
 }
 
+/// Creates a new Librespot session by authenticating with Spotify's username and password,
+/// whose credentials are stored in the cache folder to create later sessions
+pub async fn new_session_with_password(
+    auth_config: &AuthConfig,
+    username: String,
+    password: String,
+) -> Result<Session> {
+    let (session, _) = Session::connect(
+        auth_config.session_config.clone(),
+        Credentials::with_password(username, password),
+        Some(auth_config.cache.clone()),
+        true,
+    )
+    .await?;
+    Ok(session)
+}
+
 pub async fn new_session_with_new_creds(auth_config: &AuthConfig) -> Result<Session> {
     tracing::info!("Creating a new session with new authentication credentials");
 
@@ -72,15 +102,8 @@ pub async fn new_session_with_new_creds(auth_config: &AuthConfig) -> Result<Sess
     for i in 0..3 {
         let (username, password) = read_user_auth_details(user)?;
         user = Some(username.clone());
-        match Session::connect(
-            auth_config.session_config.clone(),
-            Credentials::with_password(username, password),
-            Some(auth_config.cache.clone()),
-            true,
-        )
-        .await
-        {
-            Ok((session, _)) => {
+        match new_session_with_password(auth_config, username, password).await {
+            Ok(session) => {
                 println!("Successfully authenticated as {}", user.unwrap_or_default());
                 return Ok(session);
             }
@@ -108,7 +131,7 @@ pub async fn new_session(auth_config: &AuthConfig, reauth: bool) -> Result<Sessi
                 eprintln!("{msg}");
                 new_session_with_new_creds(auth_config).await
             } else {
-                anyhow::bail!(msg);
+                Err(InvalidCredentialsError(msg.to_string()).into())
             }
         }
         Some(creds) => {
@@ -127,9 +150,10 @@ pub async fn new_session(auth_config: &AuthConfig, reauth: bool) -> Result<Sessi
                     Ok(session)
                 }
                 Err(err) => match err {
-                    SessionError::AuthenticationError(err) => {
-                        anyhow::bail!("Failed to authenticate using cached credentials: {err:#}");
-                    }
+                    SessionError::AuthenticationError(err) => Err(InvalidCredentialsError(
+                        format!("Failed to authenticate using cached credentials: {err:#}"),
+                    )
+                    .into()),
                     SessionError::IoError(err) => {
                         anyhow::bail!("{err:#}\nPlease check your internet connection.");
                     }
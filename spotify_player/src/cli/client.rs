@@ -24,7 +24,7 @@ use rspotify::{
 use super::*;
 
 pub async fn start_socket(client: Client, socket: UdpSocket, state: Option<SharedState>) {
-    let mut buf = [0; MAX_REQUEST_SIZE];
+    let mut buf = vec![0; MAX_REQUEST_SIZE];
 
     loop {
         match socket.recv_from(&mut buf).await {
@@ -372,6 +372,7 @@ async fn handle_playback_request(
 
             PlayerRequest::StartPlayback(Playback::URIs(ids, None), None)
         }
+        Command::StartURIs(ids) => PlayerRequest::StartPlayback(Playback::URIs(ids, None), None),
         Command::StartContext {
             context_type,
             id_or_name,
@@ -384,6 +385,14 @@ async fn handle_playback_request(
                 ItemId::Artist(id) => ContextId::Artist(id),
                 _ => unreachable!(),
             };
+            // a playlist configured to start in shuffle is shuffled even without the `--shuffle` flag
+            let default_shuffle = match context_id {
+                ContextId::Playlist(ref id) => {
+                    crate::config::get_config().app_config.playlist_shuffle(id)
+                }
+                _ => None,
+            };
+            let shuffle = shuffle || default_shuffle.unwrap_or(false);
 
             PlayerRequest::StartPlayback(Playback::Context(context_id, None), Some(shuffle))
         }
@@ -421,9 +430,14 @@ async fn handle_playback_request(
                 progress + chrono::Duration::try_milliseconds(position_offset_ms).unwrap(),
             )
         }
+        Command::SeekTo(position_ms) => PlayerRequest::SeekTrack(
+            chrono::Duration::try_milliseconds(position_ms as i64)
+                .context("invalid seek position")?,
+        ),
     };
 
     if let Some(ref state) = state {
+        crate::client::reset_auto_mix_on_new_playback(state, &player_request);
         // A non-null application's state indicates there is a running application instance.
         // To reduce the latency of the CLI command, the player request is handled asynchronously
         // knowing that the application will outlive the asynchronous task.
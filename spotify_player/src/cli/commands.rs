@@ -1,7 +1,7 @@
 use clap::{builder::EnumValueParser, value_parser, Arg, ArgAction, ArgGroup, Command};
 use clap_complete::Shell;
 
-use super::{ContextType, ItemType, Key};
+use super::{CompletionItem, ContextType, ItemType, Key};
 
 pub fn init_connect_subcommand() -> Command {
     add_id_or_name_group(Command::new("connect").about("Connect to a Spotify device"))
@@ -12,11 +12,35 @@ pub fn init_get_subcommand() -> Command {
         .about("Get Spotify data")
         .subcommand_required(true)
         .subcommand(
-            Command::new("key").about("Get data by key").arg(
-                Arg::new("key")
-                    .value_parser(EnumValueParser::<Key>::new())
-                    .required(true),
-            ),
+            Command::new("key")
+                .about("Get data by key")
+                .arg(
+                    Arg::new("key")
+                        .value_parser(EnumValueParser::<Key>::new())
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("tmux")
+                        .long("tmux")
+                        .action(ArgAction::SetTrue)
+                        .help(
+                        "Format the playback as a tmux status string (only for the `playback` key)",
+                    ),
+                )
+                .arg(
+                    Arg::new("max_title_length")
+                        .long("max-title-length")
+                        .value_parser(value_parser!(usize))
+                        .default_value("30")
+                        .help("Maximum number of characters of the tmux status string's title"),
+                )
+                .arg(
+                    Arg::new("max_artists_length")
+                        .long("max-artists-length")
+                        .value_parser(value_parser!(usize))
+                        .default_value("20")
+                        .help("Maximum number of characters of the tmux status string's artists"),
+                ),
         )
         .subcommand(add_id_or_name_group(
             Command::new("item").about("Get a Spotify item's data").arg(
@@ -121,6 +145,13 @@ pub fn init_playback_subcommand() -> Command {
                         .required(true),
                 ),
         )
+        .subcommand(
+            Command::new("play-uris")
+                .about("Start a playback of a list of track URIs (one per line)")
+                .arg(Arg::new("file").help(
+                    "The file containing the track URIs. If not specified or `-`, read from stdin",
+                )),
+        )
 }
 
 pub fn init_search_command() -> Command {
@@ -141,6 +172,27 @@ pub fn init_like_command() -> Command {
         )
 }
 
+pub fn init_status_command() -> Command {
+    Command::new("status")
+        .about("Print a one-line playback status of a running instance (e.g. for status bars)")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .short('f')
+                .default_value("{status} {track} - {artists}")
+                .help(
+                    "Status format with the {status}, {track}, {artists} and {album} placeholders",
+                ),
+        )
+        .arg(
+            Arg::new("max_length")
+                .long("max-length")
+                .value_parser(value_parser!(usize))
+                .default_value("50")
+                .help("Maximum number of characters of the status"),
+        )
+}
+
 pub fn init_authenticate_command() -> Command {
     Command::new("authenticate").about("Authenticate the application")
 }
@@ -156,6 +208,52 @@ pub fn init_generate_command() -> Command {
         )
 }
 
+pub fn init_complete_command() -> Command {
+    Command::new("complete")
+        .about("List the names of an item type, used by the shell completions")
+        .hide(true)
+        .arg(
+            Arg::new("item")
+                .value_parser(EnumValueParser::<CompletionItem>::new())
+                .required(true),
+        )
+}
+
+#[cfg(all(feature = "media-control", target_os = "macos"))]
+pub fn init_now_playing_command() -> Command {
+    Command::new("now-playing").about(
+        "Publish the playback of a running instance (e.g. a daemon) to the macOS Now Playing",
+    )
+}
+
+pub fn init_data_subcommand() -> Command {
+    Command::new("data")
+        .about("Export or import the locally stored user data (blocklist, bookmarks, history)")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("export")
+                .about("Export the locally stored user data as JSON")
+                .arg(Arg::new("file").help(
+                    "The file to export the data to. If not specified or `-`, write to stdout",
+                )),
+        )
+        .subcommand(
+            Command::new("import")
+                .about("Import user data exported by `data export`, merging it with the locally stored data")
+                .arg(Arg::new("file").help(
+                    "The file to import the data from. If not specified or `-`, read from stdin",
+                ))
+                .arg(
+                    Arg::new("force")
+                        .long("force")
+                        .short('f')
+                        .action(ArgAction::SetTrue)
+                        .help("Import the data even if an instance of the application is running"),
+                )
+                .after_help("The import is refused while the application is running, as the running instance overrides the imported data when storing its own."),
+        )
+}
+
 pub fn init_playlist_subcommand() -> Command {
     Command::new("playlist")
         .about("Playlist editing")
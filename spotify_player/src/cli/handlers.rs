@@ -10,11 +10,15 @@ use clap_complete::{generate, Shell};
 use rspotify::clients::BaseClient;
 use std::net::UdpSocket;
 
+/// the longest duration to wait for a response of a running instance in the commands
+/// that don't create a new client (e.g. `status`)
+const RUNNING_CLIENT_RESPONSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
 fn receive_response(socket: &UdpSocket) -> Result<Response> {
     // read response from the server's socket, which can be split into
     // smaller chunks of data
     let mut data = Vec::new();
-    let mut buf = [0; 4096];
+    let mut buf = vec![0; MAX_REQUEST_SIZE];
     loop {
         let (n_bytes, _) = socket.recv_from(&mut buf)?;
         if n_bytes == 0 {
@@ -27,6 +31,18 @@ fn receive_response(socket: &UdpSocket) -> Result<Response> {
     Ok(serde_json::from_slice(&data)?)
 }
 
+/// Send a request to the client's socket and receive its response
+pub(super) fn send_request(socket: &UdpSocket, request: &Request) -> Result<Response> {
+    let request_buf = serde_json::to_vec(request)?;
+    anyhow::ensure!(
+        request_buf.len() <= MAX_REQUEST_SIZE,
+        "the request's size ({} bytes) exceeds the limit ({MAX_REQUEST_SIZE} bytes)",
+        request_buf.len()
+    );
+    socket.send(&request_buf)?;
+    receive_response(socket)
+}
+
 fn get_id_or_name(args: &ArgMatches) -> IdOrName {
     match args
         .get_one::<Id>("id_or_name")
@@ -132,44 +148,73 @@ fn handle_playback_subcommand(args: &ArgMatches) -> Result<Request> {
                 .expect("position_offset_ms is required");
             Command::Seek(*position_offset_ms)
         }
+        "play-uris" => {
+            let content = match args.get_one::<String>("file").map(String::as_str) {
+                None | Some("-") => std::io::read_to_string(std::io::stdin())
+                    .context("read track URIs from stdin")?,
+                Some(path) => std::fs::read_to_string(path)
+                    .with_context(|| format!("read track URIs from {path}"))?,
+            };
+            // each non-empty line that is not a comment (starting with `#`) is a track URI
+            let ids = content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(|l| {
+                    TrackId::from_id_or_uri(l)
+                        .map(|id| id.into_static())
+                        .with_context(|| format!("invalid track URI: {l}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if ids.is_empty() {
+                anyhow::bail!("no track URI provided");
+            }
+            Command::StartURIs(ids)
+        }
         _ => unreachable!(),
     };
 
     Ok(Request::Playback(command))
 }
 
+/// Connect to the client's socket of a running `spotify_player` instance,
+/// returning whether such an instance is found
+pub(super) fn connect_to_running_client(socket: &UdpSocket, port: u16) -> Result<bool> {
+    socket.connect(("127.0.0.1", port))?;
+
+    // send an empty buffer as a connection request to the client
+    socket.send(&[])?;
+    match socket.recv(&mut [0; 1]) {
+        Ok(_) => Ok(true),
+        Err(err) if err.kind() == std::io::ErrorKind::ConnectionRefused => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
 /// Tries to connect to a running client, if exists, by sending a connection request
 /// to the client via a UDP socket.
 /// If no running client found, create a new client running in a separate thread to
 /// handle the socket request.
 fn try_connect_to_client(socket: &UdpSocket, configs: &config::Configs) -> Result<()> {
     let port = configs.app_config.client_port;
-    socket.connect(("127.0.0.1", port))?;
-
-    // send an empty buffer as a connection request to the client
-    socket.send(&[])?;
-    if let Err(err) = socket.recv(&mut [0; 1]) {
-        if let std::io::ErrorKind::ConnectionRefused = err.kind() {
-            // no running `spotify_player` instance found,
-            // initialize a new client to handle the current CLI command
+    if !connect_to_running_client(socket, port)? {
+        // no running `spotify_player` instance found,
+        // initialize a new client to handle the current CLI command
 
-            let auth_config = AuthConfig::new(configs)?;
-            let rt = tokio::runtime::Runtime::new()?;
-            let session = rt.block_on(new_session(&auth_config, false))?;
+        let auth_config = AuthConfig::new(configs)?;
+        let rt = tokio::runtime::Runtime::new()?;
+        let session = rt.block_on(new_session(&auth_config, false))?;
 
-            // create a Spotify API client
-            let client =
-                client::Client::new(session, auth_config, configs.app_config.client_id.clone());
-            rt.block_on(client.refresh_token())?;
+        // create a Spotify API client
+        let client =
+            client::Client::new(session, auth_config, configs.app_config.client_id.clone());
+        rt.block_on(client.refresh_token())?;
 
-            // create a client socket for handling CLI commands
-            let client_socket = rt.block_on(tokio::net::UdpSocket::bind(("127.0.0.1", port)))?;
+        // create a client socket for handling CLI commands
+        let client_socket = rt.block_on(tokio::net::UdpSocket::bind(("127.0.0.1", port)))?;
 
-            // spawn a thread to handle the CLI request
-            std::thread::spawn(move || rt.block_on(start_socket(client, client_socket, None)));
-        } else {
-            return Err(err.into());
-        }
+        // spawn a thread to handle the CLI request
+        std::thread::spawn(move || rt.block_on(start_socket(client, client_socket, None)));
     }
 
     Ok(())
@@ -187,18 +232,40 @@ pub fn handle_cli_subcommand(cmd: &str, args: &ArgMatches) -> Result<()> {
             rt.block_on(new_session_with_new_creds(&auth_config))?;
             std::process::exit(0);
         }
+        "data" => {
+            handle_data_subcommand(&socket, args, configs)?;
+            std::process::exit(0);
+        }
         "generate" => {
             let gen = *args
                 .get_one::<Shell>("shell")
                 .expect("shell argument is required");
             let mut cmd = init_cli()?;
             let name = cmd.get_name().to_string();
-            generate(gen, &mut cmd, name, &mut std::io::stdout());
+            let mut script = vec![];
+            generate(gen, &mut cmd, name.clone(), &mut script);
+            let script = String::from_utf8(script)?;
+            print!("{}", with_dynamic_completions(gen, &name, script));
+            std::process::exit(0);
+        }
+        "complete" => {
+            handle_complete_subcommand(&socket, args, configs)?;
             std::process::exit(0);
         }
         _ => {}
     }
 
+    if cmd == "status" {
+        return handle_status_subcommand(&socket, args, configs);
+    }
+
+    // the now-playing helper connects to the running instance by itself, so that
+    // it doesn't spawn an in-process client when the instance isn't running yet
+    #[cfg(all(feature = "media-control", target_os = "macos"))]
+    if cmd == "now-playing" {
+        return super::now_playing::run(socket, configs.app_config.client_port);
+    }
+
     try_connect_to_client(&socket, configs).context("try to connect to a client")?;
 
     // construct a socket request based on the CLI command and its arguments
@@ -219,24 +286,362 @@ pub fn handle_cli_subcommand(cmd: &str, args: &ArgMatches) -> Result<()> {
         _ => unreachable!(),
     };
 
-    // send the request to the client's socket
-    let request_buf = serde_json::to_vec(&request)?;
-    assert!(request_buf.len() <= MAX_REQUEST_SIZE);
-    socket.send(&request_buf)?;
+    // the playback got by `get key playback --tmux` is printed as a tmux status string
+    let tmux_lengths = match args.subcommand() {
+        Some(("key", args)) if cmd == "get" && args.get_flag("tmux") => {
+            anyhow::ensure!(
+                matches!(args.get_one::<Key>("key"), Some(Key::Playback)),
+                "the `--tmux` option is only supported for the `playback` key"
+            );
+            Some((
+                *args
+                    .get_one::<usize>("max_title_length")
+                    .expect("max_title_length should have a default value"),
+                *args
+                    .get_one::<usize>("max_artists_length")
+                    .expect("max_artists_length should have a default value"),
+            ))
+        }
+        _ => None,
+    };
 
-    // receive and handle a response from the client's socket
-    match receive_response(&socket)? {
+    // send the request to the client's socket and handle its response
+    match send_request(&socket, &request)? {
         Response::Err(err) => {
             eprintln!("{}", String::from_utf8_lossy(&err));
             std::process::exit(1);
         }
         Response::Ok(data) => {
-            println!("{}", String::from_utf8_lossy(&data).replace("\\n", "\n"));
+            match tmux_lengths {
+                Some((max_title_length, max_artists_length)) => {
+                    let playback = serde_json::from_slice(&data)?;
+                    println!(
+                        "{}",
+                        format_tmux_status(playback, max_title_length, max_artists_length)
+                    );
+                }
+                None => println!("{}", String::from_utf8_lossy(&data).replace("\\n", "\n")),
+            }
             std::process::exit(0);
         }
     }
 }
 
+/// Print a one-line status of a running instance's playback, which is read from the instance's
+/// cached state without making any API request. Nothing is printed if no instance is running,
+/// so that status bars polling the status don't start a new client on every poll.
+fn handle_status_subcommand(
+    socket: &UdpSocket,
+    args: &ArgMatches,
+    configs: &config::Configs,
+) -> Result<()> {
+    socket.set_read_timeout(Some(RUNNING_CLIENT_RESPONSE_TIMEOUT))?;
+    if !connect_to_running_client(socket, configs.app_config.client_port)? {
+        println!();
+        std::process::exit(0);
+    }
+
+    let playback = match send_request(socket, &Request::Get(GetRequest::Key(Key::Playback)))? {
+        Response::Ok(data) => serde_json::from_slice(&data)?,
+        Response::Err(err) => {
+            eprintln!("{}", String::from_utf8_lossy(&err));
+            std::process::exit(1);
+        }
+    };
+    let format = args
+        .get_one::<String>("format")
+        .expect("format should have a default value");
+    let max_length = *args
+        .get_one::<usize>("max_length")
+        .expect("max_length should have a default value");
+    println!("{}", format_status(playback, format, max_length));
+    std::process::exit(0);
+}
+
+/// Format a playback using a format string with the `{status}`, `{track}`, `{artists}`
+/// and `{album}` placeholders, truncating the formatted status to the given number of characters
+fn format_status(
+    playback: Option<CurrentPlaybackContext>,
+    format: &str,
+    max_length: usize,
+) -> String {
+    let Some(playback) = playback else {
+        return String::new();
+    };
+    let (track, artists, album) = match playback.item {
+        Some(PlayableItem::Track(track)) => (
+            track.name,
+            crate::utils::map_join(&track.artists, |a| &a.name, ", "),
+            track.album.name,
+        ),
+        Some(PlayableItem::Episode(episode)) => {
+            (episode.name, episode.show.publisher, episode.show.name)
+        }
+        None => return String::new(),
+    };
+
+    let configs = config::get_config();
+    let status = if playback.is_playing {
+        &configs.app_config.play_icon
+    } else {
+        &configs.app_config.pause_icon
+    };
+    let status = format
+        .replace("{status}", status)
+        .replace("{track}", &track)
+        .replace("{artists}", &artists)
+        .replace("{album}", &album);
+    truncate(&status, max_length)
+}
+
+/// Format a playback as a tmux status string (e.g. for the `status-right` option),
+/// in which the title and the artists are truncated to the given numbers of characters
+fn format_tmux_status(
+    playback: Option<CurrentPlaybackContext>,
+    max_title_length: usize,
+    max_artists_length: usize,
+) -> String {
+    let Some(playback) = playback else {
+        return String::new();
+    };
+    let (title, artists) = match playback.item {
+        Some(PlayableItem::Track(track)) => (
+            track.name,
+            crate::utils::map_join(&track.artists, |a| &a.name, ", "),
+        ),
+        Some(PlayableItem::Episode(episode)) => (episode.name, episode.show.name),
+        None => return String::new(),
+    };
+
+    let configs = config::get_config();
+    let (icon, color) = if playback.is_playing {
+        (&configs.app_config.play_icon, "green")
+    } else {
+        (&configs.app_config.pause_icon, "yellow")
+    };
+    format!(
+        "#[fg={color}]{icon}#[default] #[bold]{}#[nobold] - {}",
+        escape_tmux(&truncate(&title, max_title_length)),
+        escape_tmux(&truncate(&artists, max_artists_length)),
+    )
+}
+
+/// Truncate a text to a number of characters, ending the truncated text with an ellipsis
+fn truncate(text: &str, max_length: usize) -> String {
+    if text.chars().count() <= max_length {
+        return text.to_string();
+    }
+    let mut truncated = text
+        .chars()
+        .take(max_length.saturating_sub(1))
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+/// Escape a text's `#` characters, which start a tmux format
+fn escape_tmux(text: &str) -> String {
+    text.replace('#', "##")
+}
+
+/// Print the names of an item type, one per line, which are used by the shell completions
+/// to complete an item's name. The names are read from the file caches, or from a running
+/// instance for the devices, so that completing a name never creates a new client.
+fn handle_complete_subcommand(
+    socket: &UdpSocket,
+    args: &ArgMatches,
+    configs: &config::Configs,
+) -> Result<()> {
+    let item = args
+        .get_one::<CompletionItem>("item")
+        .expect("item argument is required");
+    let data = crate::state::UserData::new_from_file_caches(&configs.cache_folder);
+    let names: Vec<String> = match item {
+        CompletionItem::Playlists => data.playlists.into_iter().map(|p| p.name).collect(),
+        CompletionItem::Albums => data.saved_albums.into_iter().map(|a| a.name).collect(),
+        CompletionItem::Artists => data.followed_artists.into_iter().map(|a| a.name).collect(),
+        CompletionItem::Devices => {
+            socket.set_read_timeout(Some(RUNNING_CLIENT_RESPONSE_TIMEOUT))?;
+            if !connect_to_running_client(socket, configs.app_config.client_port)? {
+                return Ok(());
+            }
+            match send_request(socket, &Request::Get(GetRequest::Key(Key::Devices)))? {
+                Response::Ok(data) => serde_json::from_slice::<Vec<Device>>(&data)?
+                    .into_iter()
+                    .map(|d| d.name)
+                    .collect(),
+                Response::Err(_) => return Ok(()),
+            }
+        }
+    };
+    for name in names {
+        println!("{name}");
+    }
+    Ok(())
+}
+
+/// Add the completions of the items' names to a generated completion script, because
+/// the generated completions can't complete dynamic values.
+///
+/// The bash and zsh completion functions are wrapped by a function completing the
+/// `--name` option and falling back to the generated function otherwise.
+/// Other shells' scripts are returned unchanged.
+fn with_dynamic_completions(shell: Shell, bin: &str, script: String) -> String {
+    match shell {
+        Shell::Fish => script + &fish_dynamic_completions(bin),
+        Shell::Bash => {
+            script.replace(
+                &format!("complete -F _{bin} "),
+                &format!("complete -F _{bin}_dynamic "),
+            ) + &bash_dynamic_completions(bin)
+        }
+        Shell::Zsh => {
+            // the dynamic completion function must be defined before the script's last block,
+            // which calls the completion function when the script is autoloaded
+            let last_block = format!(
+                "if [ \"$funcstack[1]\" = \"_{bin}\" ]; then\n    _{bin} \"$@\"\nelse\n    compdef _{bin} {bin}\nfi\n"
+            );
+            script.replace(
+                &last_block,
+                &(zsh_dynamic_completions(bin)
+                    + &last_block.replace(&format!("_{bin} "), &format!("_{bin}_dynamic "))),
+            )
+        }
+        _ => script,
+    }
+}
+
+/// Get the bash function completing the items' names, which wraps the generated `_{bin}` function
+fn bash_dynamic_completions(bin: &str) -> String {
+    format!(
+        r#"
+_{bin}_dynamic() {{
+    local item=""
+    if [[ "${{COMP_WORDS[COMP_CWORD-1]}}" == "--name" || "${{COMP_WORDS[COMP_CWORD-1]}}" == "-n" ]]; then
+        case " ${{COMP_WORDS[*]}} " in
+            *" connect "*) item=devices ;;
+            *" context "*|*" item "*|*" radio "*)
+                case " ${{COMP_WORDS[*]}} " in
+                    *" playlist "*) item=playlists ;;
+                    *" album "*) item=albums ;;
+                    *" artist "*) item=artists ;;
+                esac
+                ;;
+        esac
+    fi
+    if [[ -n "$item" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=( $(compgen -W "$({bin} complete $item)" -- "${{COMP_WORDS[COMP_CWORD]}}") )
+        return 0
+    fi
+    _{bin} "$@"
+}}
+"#
+    )
+}
+
+/// Get the zsh function completing the items' names, which wraps the generated `_{bin}` function
+fn zsh_dynamic_completions(bin: &str) -> String {
+    format!(
+        r#"
+_{bin}_dynamic() {{
+    local item=""
+    if [[ "$words[CURRENT-1]" == (-n|--name) ]]; then
+        if (( $words[(I)connect] )); then
+            item=devices
+        elif (( $words[(I)(context|item|radio)] )); then
+            if (( $words[(I)playlist] )); then
+                item=playlists
+            elif (( $words[(I)album] )); then
+                item=albums
+            elif (( $words[(I)artist] )); then
+                item=artists
+            fi
+        fi
+    fi
+    if [[ -n "$item" ]]; then
+        local -a names
+        names=("${{(@f)$({bin} complete $item)}}")
+        compadd -a names
+        return
+    fi
+    _{bin} "$@"
+}}
+
+"#
+    )
+}
+
+/// Get the fish completions of the items' names, which are appended to the generated
+/// fish completions
+fn fish_dynamic_completions(bin: &str) -> String {
+    let complete = |condition: &str, item: &str| {
+        format!(
+            "complete -c {bin} -n \"{condition}\" -l name -s n -f -a \"({bin} complete {item})\"\n"
+        )
+    };
+    let complete_item = |item_type: &str, item: &str| {
+        complete(
+            &format!("__fish_seen_subcommand_from context item radio; and __fish_seen_subcommand_from {item_type}"),
+            item,
+        )
+    };
+    [
+        complete("__fish_seen_subcommand_from connect", "devices"),
+        complete_item("playlist", "playlists"),
+        complete_item("album", "albums"),
+        complete_item("artist", "artists"),
+    ]
+    .concat()
+}
+
+fn handle_data_subcommand(
+    socket: &UdpSocket,
+    args: &ArgMatches,
+    configs: &config::Configs,
+) -> Result<()> {
+    let (cmd, args) = args.subcommand().expect("data subcommand is required");
+    let cache_folder = &configs.cache_folder;
+    let file = args
+        .get_one::<String>("file")
+        .map(String::as_str)
+        .filter(|f| *f != "-");
+
+    match cmd {
+        "export" => {
+            let data = LocalUserData::load(cache_folder)?;
+            let json = serde_json::to_string_pretty(&data)?;
+            match file {
+                None => println!("{json}"),
+                Some(path) => std::fs::write(path, json)
+                    .with_context(|| format!("write user data to {path}"))?,
+            }
+        }
+        "import" => {
+            if !args.get_flag("force") {
+                socket.set_read_timeout(Some(RUNNING_CLIENT_RESPONSE_TIMEOUT))?;
+                if connect_to_running_client(socket, configs.app_config.client_port)? {
+                    anyhow::bail!(
+                        "an instance of the application is running, stop it before importing the data or use `--force` to import anyway"
+                    );
+                }
+            }
+            let content = match file {
+                None => std::io::read_to_string(std::io::stdin())
+                    .context("read user data from stdin")?,
+                Some(path) => std::fs::read_to_string(path)
+                    .with_context(|| format!("read user data from {path}"))?,
+            };
+            let imported: LocalUserData =
+                serde_json::from_str(&content).context("parse user data")?;
+            let summary = imported.import_into(cache_folder)?;
+            println!("{summary}");
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
 fn handle_playlist_subcommand(args: &ArgMatches) -> Result<Request> {
     let (cmd, args) = args.subcommand().expect("playlist subcommand is required");
     let command = match cmd {
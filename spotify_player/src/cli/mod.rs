@@ -1,12 +1,18 @@
 mod client;
 mod commands;
 mod handlers;
+#[cfg(all(feature = "media-control", target_os = "macos"))]
+mod now_playing;
 
-use crate::config;
+use crate::{
+    config,
+    state::{load_data_from_file_cache, store_data_into_file_cache, FileCacheKey},
+};
 use rspotify::model::*;
 use serde::{Deserialize, Serialize};
 
-const MAX_REQUEST_SIZE: usize = 4096;
+// the maximum payload size of an UDP datagram
+const MAX_REQUEST_SIZE: usize = 65507;
 
 pub use client::start_socket;
 pub use handlers::handle_cli_subcommand;
@@ -23,6 +29,15 @@ pub enum Key {
     Queue,
 }
 
+#[derive(Debug, clap::ValueEnum, Clone)]
+/// An item type whose names can be completed by the shell completions
+pub enum CompletionItem {
+    Devices,
+    Playlists,
+    Albums,
+    Artists,
+}
+
 #[derive(Debug, Serialize, Deserialize, clap::ValueEnum, Clone)]
 pub enum ContextType {
     Playlist,
@@ -58,6 +73,107 @@ pub enum IdOrName {
     Name(String),
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+/// The locally stored user data, exported to (or imported from) a JSON file
+/// to sync the data between machines
+pub struct LocalUserData {
+    /// the tracks (identified by URIs) in the blocklist
+    pub blocked_tracks: std::collections::BTreeSet<String>,
+    /// the bookmarks of tracks and episodes (identified by URIs)
+    pub bookmarks: std::collections::BTreeMap<String, Vec<crate::state::Bookmark>>,
+    /// the numbers of times each track (identified by URI) was skipped
+    pub skip_counts: std::collections::BTreeMap<String, u32>,
+    #[cfg(feature = "listening-log")]
+    /// the plays recorded in the listening log
+    pub listening_history: Vec<crate::listening_log::PlayRecord>,
+}
+
+impl LocalUserData {
+    /// Load the locally stored user data from the cache folder
+    pub fn load(cache_folder: &std::path::Path) -> anyhow::Result<Self> {
+        Ok(Self {
+            blocked_tracks: load_data_from_file_cache(FileCacheKey::BlockedTracks, cache_folder)
+                .unwrap_or_default(),
+            bookmarks: load_data_from_file_cache(FileCacheKey::Bookmarks, cache_folder)
+                .unwrap_or_default(),
+            skip_counts: load_data_from_file_cache(FileCacheKey::SkipCounts, cache_folder)
+                .unwrap_or_default(),
+            #[cfg(feature = "listening-log")]
+            listening_history: match crate::listening_log::ListeningLog::open_read_only(
+                cache_folder,
+            )? {
+                Some(log) => log.plays()?,
+                None => vec![],
+            },
+        })
+    }
+
+    /// Merge the user data into the data stored in the cache folder,
+    /// returning a summary of the imported data
+    pub fn import_into(self, cache_folder: &std::path::Path) -> anyhow::Result<String> {
+        let mut data = Self::load(cache_folder)?;
+
+        let n_blocked_tracks = self
+            .blocked_tracks
+            .into_iter()
+            .filter(|uri| data.blocked_tracks.insert(uri.clone()))
+            .count();
+
+        let mut n_bookmarks = 0;
+        for (uri, bookmarks) in self.bookmarks {
+            let stored = data.bookmarks.entry(uri).or_default();
+            for bookmark in bookmarks {
+                if !stored
+                    .iter()
+                    .any(|b| b.name == bookmark.name && b.position == bookmark.position)
+                {
+                    stored.push(bookmark);
+                    n_bookmarks += 1;
+                }
+            }
+            stored.sort_by_key(|b| b.position);
+        }
+
+        // keep the larger count of a track's skips, so importing the same data twice is a no-op
+        for (uri, count) in self.skip_counts {
+            let stored = data.skip_counts.entry(uri).or_default();
+            *stored = (*stored).max(count);
+        }
+
+        store_data_into_file_cache(
+            FileCacheKey::BlockedTracks,
+            cache_folder,
+            &data.blocked_tracks,
+        )?;
+        store_data_into_file_cache(FileCacheKey::Bookmarks, cache_folder, &data.bookmarks)?;
+        store_data_into_file_cache(FileCacheKey::SkipCounts, cache_folder, &data.skip_counts)?;
+
+        #[allow(unused_mut)]
+        let mut summary =
+            format!("Imported {n_blocked_tracks} blocked tracks and {n_bookmarks} bookmarks");
+        #[cfg(feature = "listening-log")]
+        {
+            let n_plays =
+                open_listening_log(cache_folder)?.import_plays(&self.listening_history)?;
+            summary += &format!(", and {n_plays} plays of the listening history");
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(feature = "listening-log")]
+fn open_listening_log(
+    cache_folder: &std::path::Path,
+) -> anyhow::Result<crate::listening_log::ListeningLog> {
+    crate::listening_log::ListeningLog::open(
+        cache_folder,
+        config::get_config()
+            .app_config
+            .listening_log_retention_in_days,
+    )
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PlaylistCommand {
     New {
@@ -96,6 +212,7 @@ pub enum Command {
         random: bool,
     },
     StartRadio(ItemType, IdOrName),
+    StartURIs(Vec<TrackId<'static>>),
     PlayPause,
     Play,
     Pause,
@@ -108,6 +225,8 @@ pub enum Command {
         is_offset: bool,
     },
     Seek(i64),
+    /// seek to an absolute position (in milliseconds) of the current playback
+    SeekTo(u64),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -163,6 +282,12 @@ pub fn init_cli() -> anyhow::Result<clap::Command> {
         .subcommand(commands::init_playlist_subcommand())
         .subcommand(commands::init_generate_command())
         .subcommand(commands::init_search_command())
+        .subcommand(commands::init_data_subcommand())
+        .subcommand(commands::init_status_command())
+        .subcommand(commands::init_complete_command());
+    #[cfg(all(feature = "media-control", target_os = "macos"))]
+    let cmd = cmd.subcommand(commands::init_now_playing_command());
+    let cmd = cmd
         .arg(
             clap::Arg::new("theme")
                 .short('t')
@@ -170,6 +295,13 @@ pub fn init_cli() -> anyhow::Result<clap::Command> {
                 .value_name("THEME")
                 .help("Application theme"),
         )
+        .arg(
+            clap::Arg::new("market")
+                .short('m')
+                .long("market")
+                .value_name("COUNTRY_CODE")
+                .help("Market (ISO 3166-1 alpha-2 country code) used in Spotify API requests"),
+        )
         .arg(
             clap::Arg::new("config-folder")
                 .short('c')
@@ -185,6 +317,12 @@ pub fn init_cli() -> anyhow::Result<clap::Command> {
                 .value_name("FOLDER")
                 .default_value(default_cache_folder.into_os_string())
                 .help("Path to the application's cache folder"),
+        )
+        .arg(
+            clap::Arg::new("kiosk")
+                .long("kiosk")
+                .action(clap::ArgAction::SetTrue)
+                .help("Run the application in the read-only kiosk mode"),
         );
 
     #[cfg(feature = "daemon")]
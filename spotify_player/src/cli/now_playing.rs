@@ -0,0 +1,160 @@
+use std::net::UdpSocket;
+use std::sync::mpsc;
+
+use anyhow::Result;
+use rspotify::model::CurrentPlaybackContext;
+use souvlaki::{MediaControlEvent, MediaControls, MediaPosition, PlatformConfig, SeekDirection};
+
+use super::{
+    handlers::{connect_to_running_client, send_request},
+    Command, GetRequest, Key, Request, Response,
+};
+use crate::config;
+
+/// the longest duration to wait for a media event before polling the playback again
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// the longest duration to wait for a response of the running `spotify_player` instance
+const RESPONSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// the shortest and the longest durations to wait before reconnecting to the instance,
+/// e.g. when the helper is started before the instance (a daemon) on login
+const MIN_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Run the now-playing helper, which publishes the playback of the `spotify_player` instance
+/// listening on the given port (e.g. a daemon) to the macOS Now Playing, and forwards the media
+/// events (e.g. a headphone's play/pause button) back to the instance
+pub fn run(socket: UdpSocket, port: u16) -> Result<()> {
+    socket.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+    std::thread::spawn(move || {
+        if let Err(err) = watch_playback(&socket, port) {
+            eprintln!("The now-playing helper stopped: {err:#}");
+            std::process::exit(1);
+        }
+    });
+
+    // MacOS requires an event loop running in the main thread to receive media events
+    let event_loop = winit::event_loop::EventLoop::new()?;
+    event_loop.run(move |_, _| {})?;
+    Ok(())
+}
+
+fn watch_playback(socket: &UdpSocket, port: u16) -> Result<()> {
+    let mut controls = MediaControls::new(PlatformConfig {
+        dbus_name: "spotify_player",
+        display_name: "Spotify Player",
+        hwnd: None,
+    })
+    .map_err(|err| anyhow::anyhow!("create the media controls: {err:?}"))?;
+
+    let (event_tx, event_rx) = mpsc::channel();
+    controls
+        .attach(move |e| event_tx.send(e).unwrap_or_default())
+        .map_err(|err| anyhow::anyhow!("attach the media event handler: {err:?}"))?;
+
+    let mut item_uri = String::new();
+    let mut reconnect_delay = MIN_RECONNECT_DELAY;
+    let mut connected = false;
+    loop {
+        if !connected {
+            // the instance isn't running (yet), so retry with an exponential backoff
+            match connect_to_running_client(socket, port) {
+                Ok(true) => connected = true,
+                Ok(false) => eprintln!(
+                    "No running instance found, retrying in {}s",
+                    reconnect_delay.as_secs()
+                ),
+                Err(err) => eprintln!(
+                    "Failed to connect to the running instance, retrying in {}s: {err:#}",
+                    reconnect_delay.as_secs()
+                ),
+            }
+            if !connected {
+                std::thread::sleep(reconnect_delay);
+                reconnect_delay = (reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            }
+        }
+
+        let playback = match send_request(socket, &Request::Get(GetRequest::Key(Key::Playback))) {
+            Ok(Response::Ok(data)) => {
+                reconnect_delay = MIN_RECONNECT_DELAY;
+                serde_json::from_slice::<Option<CurrentPlaybackContext>>(&data)?
+            }
+            Ok(Response::Err(err)) => {
+                eprintln!(
+                    "Failed to get the playback: {}",
+                    String::from_utf8_lossy(&err)
+                );
+                None
+            }
+            Err(err) => {
+                // the instance is stopped, so reconnect to it
+                eprintln!("Lost the connection to the running instance: {err:#}");
+                connected = false;
+                continue;
+            }
+        };
+        let progress = playback.as_ref().and_then(|p| p.progress);
+        crate::media_control::update_controls(
+            &mut controls,
+            playback.as_ref(),
+            progress,
+            &mut item_uri,
+        )
+        .map_err(|err| anyhow::anyhow!("update the media controls: {err:?}"))?;
+
+        // a media event is handled right away, after which the playback is polled again
+        // to reflect the event's changes
+        let event = match event_rx.recv_timeout(POLL_INTERVAL) {
+            Ok(event) => event,
+            Err(mpsc::RecvTimeoutError::Timeout) => continue,
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                anyhow::bail!("the media event handler is disconnected")
+            }
+        };
+        if let Some(command) = event_command(event) {
+            match send_request(socket, &Request::Playback(command)) {
+                Ok(Response::Ok(_)) => {}
+                Ok(Response::Err(err)) => eprintln!(
+                    "Failed to handle a media event: {}",
+                    String::from_utf8_lossy(&err)
+                ),
+                Err(err) => eprintln!("Failed to send a media event: {err:#}"),
+            }
+        }
+    }
+}
+
+/// Get the playback command handling a media event
+fn event_command(event: MediaControlEvent) -> Option<Command> {
+    let seek = |direction: SeekDirection, dur: std::time::Duration| {
+        let offset_ms = dur.as_millis() as i64;
+        match direction {
+            SeekDirection::Forward => Command::Seek(offset_ms),
+            SeekDirection::Backward => Command::Seek(-offset_ms),
+        }
+    };
+
+    Some(match event {
+        MediaControlEvent::Play => Command::Play,
+        MediaControlEvent::Pause | MediaControlEvent::Stop => Command::Pause,
+        MediaControlEvent::Toggle => Command::PlayPause,
+        MediaControlEvent::Next => Command::Next,
+        MediaControlEvent::Previous => Command::Previous,
+        MediaControlEvent::Seek(direction) => seek(
+            direction,
+            std::time::Duration::from_secs(config::get_config().app_config.seek_duration_in_secs),
+        ),
+        MediaControlEvent::SeekBy(direction, dur) => seek(direction, dur),
+        MediaControlEvent::SetPosition(MediaPosition(position)) => {
+            Command::SeekTo(position.as_millis() as u64)
+        }
+        MediaControlEvent::SetVolume(volume) => Command::Volume {
+            percent: (volume * 100.0) as i8,
+            is_offset: false,
+        },
+        _ => return None,
+    })
+}
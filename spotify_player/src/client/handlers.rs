@@ -2,17 +2,89 @@ use anyhow::Context;
 use rspotify::model::PlayableItem;
 use tracing::Instrument;
 
-use crate::{config, state::*};
+use crate::{
+    auth::InvalidCredentialsError, config, i18n::tr, state::*, ui::single_line_input::LineInput,
+};
 
-#[cfg(feature = "lyric-finder")]
 use crate::utils::map_join;
 
-use super::ClientRequest;
+use super::{ClientRequest, PlayerRequest, PlayerWebhookEvent, WebhookTrack};
 
 struct PlayerEventHandlerState {
     add_track_to_queue_req_timer: std::time::Instant,
+    /// the last track automatically skipped for being too short, blocked or unplayable
+    last_auto_skipped_track_id: Option<TrackId<'static>>,
+    /// the last playing track checked for being unplayable in the playing context,
+    /// and whether it is unplayable
+    last_unplayable_check: Option<(TrackId<'static>, bool)>,
+    /// the last status line emitted in the screen reader mode
+    last_status: String,
+    last_prefetched_track_id: Option<TrackId<'static>>,
+    /// the last playing track followed by the track table's selection
+    last_followed_track_id: Option<TrackId<'static>>,
+    /// the last played track and the last time the playback was recorded in the metrics
+    last_played_track_id: Option<TrackId<'static>>,
+    /// the last observed progress and the duration of the last played track
+    last_played_track_progress: (chrono::Duration, chrono::Duration),
+    playback_recorded_at: std::time::Instant,
+    queue_refresh_timer: std::time::Instant,
+    playlist_refresh_timer: std::time::Instant,
+    /// the last time the tracks' tempos of each context (identified by URI) were requested
+    context_tempos_requested_at: std::collections::HashMap<String, std::time::Instant>,
+    /// the last playback state (the current track, whether it's playing, and the upcoming
+    /// queue items' URIs) sent to the player event webhook
+    last_webhook_track: Option<WebhookTrack>,
+    last_webhook_is_playing: Option<bool>,
+    last_webhook_queue: Option<Vec<String>>,
+    /// the sender of the player events posted (in order) to the player event webhook
+    webhook_pub: Option<flume::Sender<PlayerWebhookEvent>>,
+    /// the last time each album cover image (identified by URL) was requested
+    #[cfg(feature = "image")]
+    album_cover_requested_at: std::collections::HashMap<String, std::time::Instant>,
+    #[cfg(feature = "listening-log")]
+    listening_log: Option<crate::listening_log::ListeningLog>,
 }
 
+/// a track changed earlier than this duration before its end is considered skipped
+const TRACK_SKIP_THRESHOLD: chrono::TimeDelta = chrono::TimeDelta::seconds(10);
+
+/// the time before the end of the current track to prefetch the next track's data,
+/// which avoids UI hitching on the track transition
+const NEXT_TRACK_PREFETCH_TIME: chrono::TimeDelta = chrono::TimeDelta::seconds(10);
+
+/// the interval to refresh the queue and the playback while the queue page is shown,
+/// so that changes made from another device are reflected
+const QUEUE_PAGE_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// the interval to re-request the tracks' tempos of a context that are still unknown,
+/// e.g. because the context has been refreshed or its previous request failed
+const CONTEXT_TEMPOS_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// the interval to re-request an album cover image that is still missing from the image cache,
+/// e.g. because its previous request failed or it was evicted from the cache
+#[cfg(feature = "image")]
+const ALBUM_COVER_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// the duration after the user moves a track table's selection, during which the selection
+/// doesn't follow the playing track
+const TRACK_SELECTION_IDLE_DURATION: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// the duration the context views need to be unchanged before being stored into the file cache
+const CONTEXT_VIEWS_STORE_DELAY: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// the interval between checks of the access token's expiry
+const TOKEN_REFRESH_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// the interval between checks of the auto-archived playlists, which are archived once a week
+const PLAYLIST_AUTO_ARCHIVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// the maximum number of player events waiting to be sent to the player event webhook,
+/// after which new events are dropped
+const MAX_PENDING_WEBHOOK_EVENTS: usize = 100;
+
+/// the longest duration to wait for the player event webhook to handle an event
+const WEBHOOK_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// starts the client's request handler
 pub async fn start_client_handler(
     state: SharedState,
@@ -20,18 +92,32 @@ pub async fn start_client_handler(
     client_sub: flume::Receiver<ClientRequest>,
 ) {
     while let Ok(request) = client_sub.recv_async().await {
-        if let Err(err) = client.check_valid_session(&state).await {
-            tracing::error!("{err:#}");
-            continue;
+        // an authentication request replaces the invalid session, so it skips the session check
+        if !matches!(request, ClientRequest::Authenticate { .. }) {
+            if let Err(err) = client.check_valid_session(&state).await {
+                tracing::error!("{err:#}");
+                if err.downcast_ref::<InvalidCredentialsError>().is_some() {
+                    prompt_authentication(&state, &client);
+                }
+                continue;
+            }
         }
 
         let state = state.clone();
         let client = client.clone();
         let span = tracing::info_span!("client_request", request = ?request);
+        let request_type = client_request_type(&request);
 
         tokio::task::spawn(
             async move {
-                if let Err(err) = client.handle_request(&state, request).await {
+                let started_at = std::time::Instant::now();
+                let result = client.handle_request(&state, request).await;
+                crate::metrics::record_client_request(
+                    &request_type,
+                    started_at.elapsed(),
+                    result.is_err(),
+                );
+                if let Err(err) = result {
                     tracing::error!("Failed to handle client request: {err:#}");
                 }
             }
@@ -40,12 +126,67 @@ pub async fn start_client_handler(
     }
 }
 
+/// Prompt the user to re-authenticate the application inside the running application,
+/// which is needed when the cached credentials become invalid (e.g. after a password change)
+fn prompt_authentication(state: &SharedState, client: &super::Client) {
+    if state.is_daemon {
+        return;
+    }
+    let mut ui = state.ui.lock();
+    if matches!(ui.popup, Some(PopupState::Authenticate { .. })) {
+        return;
+    }
+    let username = client
+        .auth_config
+        .cache
+        .credentials()
+        .map(|creds| creds.username)
+        .unwrap_or_default();
+    let current_field = if username.is_empty() {
+        AuthenticateCurrentField::Username
+    } else {
+        AuthenticateCurrentField::Password
+    };
+    ui.popup = Some(PopupState::Authenticate {
+        username: LineInput::new(username.chars().collect()),
+        password: LineInput::default(),
+        current_field,
+    });
+    ui.show_toast(tr("Authentication required"));
+}
+
+/// Get a client request's type (e.g. `GetContext` or `Player::NextTrack`)
+/// from the name of its enum variant
+fn client_request_type(request: &ClientRequest) -> String {
+    let variant_name = |repr: String| {
+        repr.split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    };
+    match request {
+        ClientRequest::Player(request) => {
+            format!("Player::{}", variant_name(format!("{request:?}")))
+        }
+        request => variant_name(format!("{request:?}")),
+    }
+}
+
 fn handle_playback_change_event(
     state: &SharedState,
     client_pub: &flume::Sender<ClientRequest>,
     handler_state: &mut PlayerEventHandlerState,
 ) -> anyhow::Result<()> {
     let player = state.player.read();
+
+    // update the playback session's metrics
+    let is_playing = player
+        .buffered_playback
+        .as_ref()
+        .is_some_and(|p| p.is_playing);
+    crate::metrics::record_playback(is_playing, handler_state.playback_recorded_at.elapsed());
+    handler_state.playback_recorded_at = std::time::Instant::now();
+
     let (playback, track) = match (
         player.buffered_playback.as_ref(),
         player.current_playing_track(),
@@ -54,11 +195,51 @@ fn handle_playback_change_event(
         _ => return Ok(()),
     };
 
+    let mut skipped_track_id = None;
+    if track.id != handler_state.last_played_track_id {
+        crate::metrics::record_played_track();
+
+        // the last played track is skipped if it changed before its end,
+        // unless it was automatically skipped
+        let (progress, duration) = handler_state.last_played_track_progress;
+        if progress + TRACK_SKIP_THRESHOLD < duration
+            && handler_state.last_played_track_id != handler_state.last_auto_skipped_track_id
+        {
+            skipped_track_id = handler_state.last_played_track_id.clone();
+        }
+        handler_state.last_played_track_id = track.id.clone();
+    }
+    handler_state.last_played_track_progress = (
+        player.playback_progress().unwrap_or_default(),
+        track.duration,
+    );
+
+    #[cfg(feature = "listening-log")]
+    if let Some(log) = handler_state.listening_log.as_mut() {
+        if let Err(err) = log.record_playback(
+            track,
+            player.playback_progress(),
+            player.playing_context_id().map(|id| id.uri()),
+            &playback.device_name,
+        ) {
+            tracing::error!("Failed to record the playback in the listening log: {err:#}");
+        }
+    }
+
     if let Some(progress) = player.playback_progress() {
         // update the playback when the current track ends
         if progress >= track.duration && playback.is_playing {
             client_pub.send(ClientRequest::GetCurrentPlayback)?;
         }
+
+        // prefetch the next track's data slightly before the current track ends
+        if progress + NEXT_TRACK_PREFETCH_TIME >= track.duration
+            && playback.is_playing
+            && track.id != handler_state.last_prefetched_track_id
+        {
+            client_pub.send(ClientRequest::PrefetchNextTrack)?;
+            handler_state.last_prefetched_track_id = track.id.clone();
+        }
     }
 
     if let Some(queue) = player.queue.as_ref() {
@@ -92,14 +273,172 @@ fn handle_playback_change_event(
         }
     }
 
+    let mut toast = None;
+
+    // skip blocked tracks, unplayable tracks and short tracks (e.g. interludes) when playing an album
+    let skip_threshold = config::get_config()
+        .app_config
+        .album_auto_skip_threshold_in_secs;
+    let is_short_album_track = skip_threshold > 0
+        && matches!(player.playing_context_id(), Some(ContextId::Album(_)))
+        && track.duration.num_seconds() < skip_threshold as i64;
+    let (is_blocked_track, is_unavailable_track) = match track.id {
+        Some(ref id) => {
+            let data = state.data.read();
+            // the playing context's tracks are only scanned once per playing track
+            let is_unavailable_track = match handler_state.last_unplayable_check {
+                Some((ref checked_id, is_unplayable)) if checked_id == id => is_unplayable,
+                _ => match player
+                    .playing_context_id()
+                    .and_then(|context_id| data.caches.context.get(&context_id.uri()))
+                {
+                    Some(context) => {
+                        let is_unplayable = context
+                            .tracks()
+                            .iter()
+                            .any(|t| t.id == *id && !t.is_playable());
+                        handler_state.last_unplayable_check = Some((id.clone(), is_unplayable));
+                        is_unplayable
+                    }
+                    // the check is retried once the playing context is loaded
+                    None => false,
+                },
+            };
+            (
+                data.user_data.blocked_tracks.contains(&id.uri()),
+                is_unavailable_track,
+            )
+        }
+        None => (false, false),
+    };
+    if (is_short_album_track || is_blocked_track || is_unavailable_track)
+        && playback.is_playing
+        && track.id != handler_state.last_auto_skipped_track_id
+    {
+        tracing::info!(
+            "auto-skip the track {} (blocked: {is_blocked_track}, unavailable: {is_unavailable_track})",
+            track.name
+        );
+        client_pub.send(ClientRequest::Player(PlayerRequest::NextTrack))?;
+        handler_state.last_auto_skipped_track_id = track.id.clone();
+
+        toast = Some(format!("{} {}", tr("Skipped"), track.name));
+    }
+
+    // emit a concise status line when the playback changes in the screen reader mode
+    if config::get_config().app_config.screen_reader_mode {
+        let status = format!(
+            "{}: {} {} {}",
+            if playback.is_playing {
+                tr("Playing")
+            } else {
+                tr("Paused")
+            },
+            track.name,
+            tr("by"),
+            map_join(&track.artists, |a| &a.name, ", ")
+        );
+        if status != handler_state.last_status {
+            handler_state.last_status.clone_from(&status);
+            toast = toast.or(Some(status));
+        }
+    }
+
+    if let Some(webhook_pub) = handler_state.webhook_pub.clone() {
+        for event in player_webhook_events(handler_state, &player, playback.is_playing, track) {
+            // drop the event instead of piling up events when the webhook is slow or hung
+            match webhook_pub.try_send(event) {
+                Err(flume::TrySendError::Full(_)) => {
+                    tracing::warn!(
+                        "Dropped a player event, as the player event webhook is falling behind"
+                    );
+                }
+                result => result?,
+            }
+        }
+    }
+
+    // the player state's lock needs to be released before acquiring
+    // the data state's write lock or the UI state's lock
+    drop(player);
+    if let Some(id) = skipped_track_id {
+        state.data.write().user_data.record_skipped_track(&id);
+    }
+    if let Some(toast) = toast {
+        state.ui.lock().show_toast(toast);
+    }
+
     Ok(())
 }
 
+/// Get the player events to send to the player event webhook, i.e. the changes
+/// of the playback state since the last time it was sent to the webhook
+fn player_webhook_events(
+    handler_state: &mut PlayerEventHandlerState,
+    player: &PlayerState,
+    is_playing: bool,
+    track: &rspotify::model::FullTrack,
+) -> Vec<PlayerWebhookEvent> {
+    let mut events = vec![];
+
+    let webhook_track = WebhookTrack {
+        uri: track.id.as_ref().map(|id| id.uri()).unwrap_or_default(),
+        name: track.name.clone(),
+        artists: track.artists.iter().map(|a| a.name.clone()).collect(),
+        album: track.album.name.clone(),
+        duration_ms: track.duration.num_milliseconds(),
+    };
+    if handler_state.last_webhook_track.as_ref() != Some(&webhook_track) {
+        handler_state.last_webhook_track = Some(webhook_track.clone());
+        events.push(PlayerWebhookEvent::TrackChanged {
+            track: webhook_track,
+        });
+    } else if handler_state
+        .last_webhook_is_playing
+        .is_some_and(|p| p != is_playing)
+    {
+        events.push(if is_playing {
+            PlayerWebhookEvent::Resumed {
+                track: webhook_track,
+            }
+        } else {
+            PlayerWebhookEvent::Paused {
+                track: webhook_track,
+            }
+        });
+    }
+    handler_state.last_webhook_is_playing = Some(is_playing);
+
+    if let Some(queue) = player.queue.as_ref() {
+        let uris = queue
+            .queue
+            .iter()
+            .filter_map(|item| item.id().map(|id| id.uri()))
+            .collect::<Vec<_>>();
+        // the initial queue is not a change
+        if handler_state
+            .last_webhook_queue
+            .as_ref()
+            .is_some_and(|q| *q != uris)
+        {
+            events.push(PlayerWebhookEvent::QueueChanged {
+                queue: uris.clone(),
+            });
+        }
+        handler_state.last_webhook_queue = Some(uris);
+    }
+
+    events
+}
+
 fn handle_page_change_event(
     state: &SharedState,
     client_pub: &flume::Sender<ClientRequest>,
+    handler_state: &mut PlayerEventHandlerState,
 ) -> anyhow::Result<()> {
-    match state.ui.lock().current_page_mut() {
+    let mut ui = state.ui.lock();
+    let ui = &mut *ui;
+    match ui.history.last_mut().expect("non-empty history") {
         PageState::Context {
             id,
             context_page_type,
@@ -125,19 +464,93 @@ fn handle_page_change_event(
                             ContextId::Playlist(_) => ContextPageUIState::new_playlist(),
                             ContextId::Tracks(_) => ContextPageUIState::new_tracks(),
                         });
+
+                        // restore the context's remembered view state
+                        if let Some(view) = state.data.read().context_views.get(&id.uri()) {
+                            if let Some(page_state) = page_state.as_mut() {
+                                page_state.focus_window_state_mut().select(view.selected);
+                            }
+                            if let Some(filter) = view.filter.as_ref() {
+                                if ui.popup.is_none() {
+                                    let mut query = LineInput::default();
+                                    query.insert_str(filter);
+                                    ui.popup = Some(PopupState::Search { query });
+                                }
+                            }
+                        }
                     }
                     None => {
                         *page_state = None;
                     }
                 }
+            } else if let (Some(id), Some(page_state)) = (id.as_ref(), page_state.as_mut()) {
+                // remember the current view state of the context
+                let selected = page_state
+                    .focus_window_state_mut()
+                    .selected()
+                    .unwrap_or_default();
+                let filter = match ui.popup {
+                    Some(PopupState::Search { ref query }) => Some(query.get_text()),
+                    None => Some(String::new()),
+                    // other popups don't affect the remembered filter
+                    _ => None,
+                };
+                state.data.write().update_context_view(&id.uri(), |view| {
+                    view.selected = selected;
+                    if let Some(filter) = filter {
+                        view.filter = Some(filter).filter(|f| !f.is_empty());
+                    }
+                });
             }
 
             // request new context's data if not found in memory
+            // and not already being loaded (or failed to load)
             if let Some(id) = id {
-                if !matches!(id, ContextId::Tracks(_))
-                    && !state.data.read().caches.context.contains_key(&id.uri())
+                let uri = id.uri();
+                let data = state.data.read();
+                if !data.caches.context.contains_key(&uri)
+                    && !data.context_loading_status.contains_key(&uri)
+                {
+                    if let Some(request) = ClientRequest::get_context(id) {
+                        client_pub.send(request)?;
+                    }
+                }
+
+                // periodically refresh the shown playlist to reflect changes made elsewhere,
+                // e.g. by the playlist's collaborators
+                let refresh_interval = std::time::Duration::from_secs(
+                    config::get_config()
+                        .app_config
+                        .playlist_refresh_interval_in_secs,
+                );
+                if let ContextId::Playlist(playlist_id) = id {
+                    if !refresh_interval.is_zero()
+                        && data.caches.context.contains_key(&uri)
+                        && handler_state.playlist_refresh_timer.elapsed() >= refresh_interval
+                    {
+                        client_pub
+                            .send(ClientRequest::RefreshPlaylistContext(playlist_id.clone()))?;
+                        handler_state.playlist_refresh_timer = std::time::Instant::now();
+                    }
+                }
+
+                // the tempo filter requires the tempos of the context's tracks
+                let has_unknown_tempos = data
+                    .caches
+                    .context
+                    .get(&uri)
+                    .is_some_and(|c| c.tracks().iter().any(|t| t.tempo.is_none()));
+                if ui.track_tempo_filter.is_some()
+                    && has_unknown_tempos
+                    && handler_state
+                        .context_tempos_requested_at
+                        .get(&uri)
+                        .is_none_or(|t| t.elapsed() >= CONTEXT_TEMPOS_RETRY_INTERVAL)
                 {
-                    client_pub.send(ClientRequest::GetContext(id.clone()))?;
+                    client_pub.send(ClientRequest::GetContextTrackTempos(id.clone()))?;
+                    handler_state
+                        .context_tempos_requested_at
+                        .insert(uri, std::time::Instant::now());
                 }
             }
         }
@@ -162,20 +575,121 @@ fn handle_page_change_event(
                 }
             }
         }
+        PageState::Queue { .. }
+            if handler_state.queue_refresh_timer.elapsed() >= QUEUE_PAGE_REFRESH_INTERVAL =>
+        {
+            client_pub.send(ClientRequest::GetCurrentPlayback)?;
+            client_pub.send(ClientRequest::GetCurrentUserQueue)?;
+            handler_state.queue_refresh_timer = std::time::Instant::now();
+        }
         _ => {}
     }
 
     Ok(())
 }
 
+/// Request the cover images of albums rendered in an album grid that are not yet in the image cache
+#[cfg(feature = "image")]
+fn handle_missing_album_covers_event(
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+    handler_state: &mut PlayerEventHandlerState,
+) -> anyhow::Result<()> {
+    let missing_urls = std::mem::take(&mut state.ui.lock().missing_album_cover_urls);
+
+    let urls = missing_urls
+        .into_iter()
+        .filter(
+            |url| match handler_state.album_cover_requested_at.get(url) {
+                Some(requested_at) => requested_at.elapsed() >= ALBUM_COVER_RETRY_INTERVAL,
+                None => true,
+            },
+        )
+        .collect::<Vec<_>>();
+    if urls.is_empty() {
+        return Ok(());
+    }
+
+    for url in &urls {
+        handler_state
+            .album_cover_requested_at
+            .insert(url.clone(), std::time::Instant::now());
+    }
+    client_pub.send(ClientRequest::GetAlbumCoverImages(urls))?;
+
+    Ok(())
+}
+
+/// Select the playing track in the open context page's track table when the playing track
+/// changes, unless the user has moved the track table's selection recently
+fn handle_followed_track_event(state: &SharedState, handler_state: &mut PlayerEventHandlerState) {
+    if !config::get_config().app_config.follow_playing_track {
+        return;
+    }
+    let track_id = state
+        .player
+        .read()
+        .current_playing_track()
+        .and_then(|t| t.id.clone());
+    if track_id.is_none() || track_id == handler_state.last_followed_track_id {
+        return;
+    }
+    handler_state.last_followed_track_id = track_id.clone();
+
+    let mut ui = state.ui.lock();
+    if ui
+        .track_selection_moved_at
+        .is_some_and(|t| t.elapsed() < TRACK_SELECTION_IDLE_DURATION)
+    {
+        return;
+    }
+    let uri = match ui.current_page() {
+        // an artist's top tracks are followed only if its track table is focused
+        PageState::Context {
+            state: Some(ContextPageUIState::Artist { focus, .. }),
+            ..
+        } if *focus != ArtistFocusState::TopTracks => return,
+        PageState::Context { id: Some(id), .. } => id.uri(),
+        _ => return,
+    };
+    let row = {
+        let data = state.data.read();
+        let Some(context) = data.caches.context.get(&uri) else {
+            return;
+        };
+        ui.track_table_items(context.tracks())
+            .iter()
+            .position(|t| Some(&t.id) == track_id.as_ref())
+    };
+    if let Some(row) = row {
+        ui.current_page_mut().select(row);
+    }
+}
+
 fn handle_player_event(
     state: &SharedState,
     client_pub: &flume::Sender<ClientRequest>,
     handler_state: &mut PlayerEventHandlerState,
 ) -> anyhow::Result<()> {
-    handle_page_change_event(state, client_pub).context("handle page change event")?;
+    handle_page_change_event(state, client_pub, handler_state)
+        .context("handle page change event")?;
     handle_playback_change_event(state, client_pub, handler_state)
         .context("handle playback change event")?;
+    #[cfg(feature = "image")]
+    handle_missing_album_covers_event(state, client_pub, handler_state)
+        .context("handle missing album covers event")?;
+    handle_followed_track_event(state, handler_state);
+
+    // store the context views once they are unchanged for a while, e.g. after the user stops
+    // scrolling a context's track table, to avoid writing the file cache on every change
+    let should_store_context_views = state
+        .data
+        .read()
+        .context_views_changed_at
+        .is_some_and(|t| t.elapsed() >= CONTEXT_VIEWS_STORE_DELAY);
+    if should_store_context_views {
+        state.data.write().store_context_views();
+    }
 
     Ok(())
 }
@@ -211,6 +725,42 @@ pub async fn start_player_event_watchers(
     let refresh_duration = std::time::Duration::from_secs(1);
     let mut handler_state = PlayerEventHandlerState {
         add_track_to_queue_req_timer: std::time::Instant::now(),
+        last_auto_skipped_track_id: None,
+        last_unplayable_check: None,
+        last_status: String::new(),
+        last_prefetched_track_id: None,
+        last_followed_track_id: None,
+        last_played_track_id: None,
+        last_played_track_progress: (chrono::Duration::zero(), chrono::Duration::zero()),
+        playback_recorded_at: std::time::Instant::now(),
+        queue_refresh_timer: std::time::Instant::now(),
+        playlist_refresh_timer: std::time::Instant::now(),
+        context_tempos_requested_at: std::collections::HashMap::new(),
+        last_webhook_track: None,
+        last_webhook_is_playing: None,
+        last_webhook_queue: None,
+        webhook_pub: configs
+            .app_config
+            .player_event_webhook_url
+            .clone()
+            .map(|url| {
+                let (webhook_pub, webhook_sub) = flume::bounded(MAX_PENDING_WEBHOOK_EVENTS);
+                tokio::task::spawn(start_player_webhook_sender(url, webhook_sub));
+                webhook_pub
+            }),
+        #[cfg(feature = "image")]
+        album_cover_requested_at: std::collections::HashMap::new(),
+        #[cfg(feature = "listening-log")]
+        listening_log: match crate::listening_log::ListeningLog::open(
+            &configs.cache_folder,
+            configs.app_config.listening_log_retention_in_days,
+        ) {
+            Ok(log) => Some(log),
+            Err(err) => {
+                tracing::error!("Failed to open the listening log: {err:#}");
+                None
+            }
+        },
     };
 
     loop {
@@ -220,3 +770,54 @@ pub async fn start_player_event_watchers(
         }
     }
 }
+
+/// starts a sender posting the player events to the player event webhook one by one,
+/// so that the webhook receives the events in the order they happen
+async fn start_player_webhook_sender(
+    url: String,
+    webhook_sub: flume::Receiver<PlayerWebhookEvent>,
+) {
+    let http = match reqwest::Client::builder()
+        .timeout(WEBHOOK_REQUEST_TIMEOUT)
+        .build()
+    {
+        Ok(http) => http,
+        Err(err) => {
+            tracing::error!("Failed to create the player event webhook's HTTP client: {err:#}");
+            return;
+        }
+    };
+    while let Ok(event) = webhook_sub.recv_async().await {
+        let result = http
+            .post(&url)
+            .json(&event)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(err) = result {
+            tracing::warn!("Failed to send a player event to the webhook: {err:#}");
+        }
+    }
+}
+
+/// starts a watcher that periodically refreshes the access token before its expiry
+pub async fn start_token_refresher(client_pub: flume::Sender<ClientRequest>) {
+    loop {
+        tokio::time::sleep(TOKEN_REFRESH_CHECK_INTERVAL).await;
+        client_pub
+            .send_async(ClientRequest::RefreshToken)
+            .await
+            .unwrap_or_default();
+    }
+}
+
+/// starts a watcher that periodically archives the configured `auto_archived_playlists`
+pub async fn start_playlist_auto_archiver(client_pub: flume::Sender<ClientRequest>) {
+    loop {
+        client_pub
+            .send_async(ClientRequest::AutoArchivePlaylists)
+            .await
+            .unwrap_or_default();
+        tokio::time::sleep(PLAYLIST_AUTO_ARCHIVE_INTERVAL).await;
+    }
+}
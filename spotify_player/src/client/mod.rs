@@ -1,12 +1,18 @@
 use std::ops::Deref;
-use std::{borrow::Cow, collections::HashMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::config;
+use crate::i18n::tr;
 use crate::{auth::AuthConfig, state::*};
 
 use anyhow::Context as _;
 use anyhow::Result;
 use librespot_core::session::Session;
+use rand::seq::SliceRandom;
 use rspotify::{
     http::Query,
     model::{FullPlaylist, Market, Page, SimplifiedPlaylist},
@@ -23,6 +29,16 @@ use serde::Deserialize;
 
 const SPOTIFY_API_ENDPOINT: &str = "https://api.spotify.com/v1";
 
+/// the maximum difference between a polled playback's progress and the interpolated progress
+/// that is considered as a polling jitter, in which case the interpolated progress is kept
+const PLAYBACK_PROGRESS_JITTER_TOLERANCE: chrono::TimeDelta = chrono::TimeDelta::milliseconds(750);
+
+/// the access token is refreshed once its remaining lifetime is shorter than this duration
+const TOKEN_REFRESH_MARGIN: chrono::TimeDelta = chrono::TimeDelta::minutes(5);
+
+/// the number of recently saved albums whose tracks are included in the recently added tracks
+const RECENTLY_SAVED_ALBUMS_LIMIT: u32 = 10;
+
 /// The application's Spotify client
 #[derive(Clone)]
 pub struct Client {
@@ -40,8 +56,37 @@ impl Deref for Client {
     }
 }
 
+/// gets the market used in Spotify API requests, which is either the configured market
+/// or the market associated with the user's access token
+fn market() -> Market {
+    match config::get_config().app_config.market() {
+        Some(country) => Market::Country(country),
+        None => Market::FromToken,
+    }
+}
+
+/// gets the country used in Spotify browse API requests.
+/// Unlike other requests, browse requests don't accept the `from_token` market.
+fn browse_country() -> Option<Market> {
+    config::get_config()
+        .app_config
+        .market()
+        .map(Market::Country)
+}
+
 fn market_query() -> Query<'static> {
-    Query::from([("market", "from_token")])
+    Query::from([("market", market().into())])
+}
+
+/// disables the auto-mix mode when a player request starts a new playback,
+/// so the fades don't keep firing on a queue that wasn't ordered for the mix
+pub fn reset_auto_mix_on_new_playback(state: &SharedState, request: &PlayerRequest) {
+    #[cfg(feature = "streaming")]
+    if matches!(request, PlayerRequest::StartPlayback(..)) {
+        state.player.write().is_auto_mix_enabled = false;
+    }
+    #[cfg(not(feature = "streaming"))]
+    let _ = (state, request);
 }
 
 impl Client {
@@ -57,12 +102,18 @@ impl Client {
         }
     }
 
-    /// Create a new client session
+    /// Create a new client session using the cached credentials
+    async fn new_session(&self, state: &SharedState) -> Result<()> {
+        let session = crate::auth::new_session(&self.auth_config, false).await?;
+        self.use_session(state, session).await;
+        Ok(())
+    }
+
+    /// Use a new session for the client
     // unused variables:
     // - `state` when the `streaming` feature is not enabled
     #[allow(unused_variables)]
-    async fn new_session(&self, state: &SharedState) -> Result<()> {
-        let session = crate::auth::new_session(&self.auth_config, false).await?;
+    async fn use_session(&self, state: &SharedState, session: Session) {
         *self.session.lock().await = Some(session);
 
         tracing::info!("Used a new session for Spotify client.");
@@ -80,8 +131,6 @@ impl Client {
                 }
             });
         }
-
-        Ok(())
     }
 
     /// Check if the current session is valid and if invalid, create a new session
@@ -121,9 +170,23 @@ impl Client {
                 // because `TransferPlayback` doesn't require an active playback
                 self.transfer_playback(&device_id, Some(force_play)).await?;
                 tracing::info!("Transferred playback to device with id={}", device_id);
+                // point the buffered playback to the new device, so that subsequent player requests
+                // are sent to the new device even before the playback state is refreshed
+                if let Some(playback) = playback.as_mut() {
+                    playback.device_id = Some(device_id);
+                    playback.is_playing = force_play;
+                }
                 return Ok(playback);
             }
             PlayerRequest::StartPlayback(p, shuffle) => {
+                // Fall back to the playlist's configured default shuffle state
+                // if the request doesn't specify one
+                let shuffle = shuffle.or(match p {
+                    Playback::Context(ContextId::Playlist(ref id), _) => {
+                        config::get_config().app_config.playlist_shuffle(id)
+                    }
+                    _ => None,
+                });
                 // Set the playback's shuffle state if specified in the request
                 if let (Some(shuffle), Some(playback)) = (shuffle, playback.as_mut()) {
                     playback.shuffle_state = shuffle;
@@ -229,6 +292,13 @@ impl Client {
             ClientRequest::GetBrowseCategories => {
                 let categories = self.browse_categories().await?;
                 state.data.write().browse.categories = categories;
+                // the genre seeds are used to browse the recommendations of a category
+                if state.data.read().browse.genre_seeds.is_none() {
+                    match self.recommendation_genre_seeds().await {
+                        Ok(seeds) => state.data.write().browse.genre_seeds = Some(seeds),
+                        Err(err) => tracing::warn!("Failed to get the genre seeds: {err:#}"),
+                    }
+                }
             }
             ClientRequest::GetBrowseCategoryPlaylists(category) => {
                 let playlists = self.browse_category_playlists(&category.id).await?;
@@ -239,15 +309,36 @@ impl Client {
                     .category_playlists
                     .insert(category.id, playlists);
             }
+            ClientRequest::GetNewReleases => {
+                let albums = self.new_releases().await?;
+                state.data.write().browse.new_releases = Some(albums);
+            }
+            ClientRequest::GetFeaturedPlaylists => {
+                let playlists = self.browse_featured_playlists().await?;
+                state.data.write().browse.featured_playlists = Some(playlists);
+            }
             #[cfg(feature = "lyric-finder")]
             ClientRequest::GetLyric { track, artists } => {
-                let client = lyric_finder::Client::from_http_client(&self.http);
                 let query = format!("{track} {artists}");
 
-                if !state.data.read().caches.lyrics.contains_key(&query) {
-                    let result = client.get_lyric(&query).await.context(format!(
-                        "failed to get lyric for track {track} - artists {artists}"
-                    ))?;
+                if !crate::metrics::record_cache_lookup(
+                    "lyrics",
+                    state.data.read().caches.lyrics.contains_key(&query),
+                ) {
+                    // a failure isn't cached, so the lyric is requested again when reopening the page
+                    let result = match crate::lyrics::get_lyric(&self.http, &track, &artists).await
+                    {
+                        Ok(result) => result,
+                        Err(err) => {
+                            state
+                                .ui
+                                .lock()
+                                .show_toast(tr("Failed to get the lyric").to_string());
+                            return Err(err.context(format!(
+                                "failed to get lyric for track {track} - artists {artists}"
+                            )));
+                        }
+                    };
 
                     state
                         .data
@@ -264,11 +355,45 @@ impl Client {
             ClientRequest::RestartIntegratedClient => {
                 self.new_session(state).await?;
             }
+            #[cfg(feature = "streaming")]
+            ClientRequest::TakeOverPlayback => {
+                self.take_over_playback(state).await?;
+            }
+            #[cfg(feature = "streaming")]
+            ClientRequest::StartAutoMix => {
+                self.start_auto_mix(state).await?;
+            }
             ClientRequest::GetCurrentUser => {
                 let user = self.current_user().await?;
                 state.data.write().user_data.user = Some(user);
             }
             ClientRequest::Player(request) => {
+                let transferred_device_id = match &request {
+                    PlayerRequest::TransferPlayback(device_id, _) => Some(device_id.clone()),
+                    _ => None,
+                };
+                // optimistically move the progress bar to the seek position
+                if let PlayerRequest::SeekTrack(position) = request {
+                    state.player.write().set_playback_progress(position);
+                }
+                reset_auto_mix_on_new_playback(state, &request);
+                let playback = state.player.read().buffered_playback.clone();
+                let playback = self.handle_player_request(request, playback).await?;
+                state.player.write().buffered_playback = playback;
+                match transferred_device_id {
+                    Some(device_id) => self.update_playback_after_transfer(state, device_id),
+                    None => self.update_playback(state),
+                }
+            }
+            ClientRequest::StartShuffledLibraryPlayback(source) => {
+                let track_ids = self.shuffled_library_track_ids(state, source).await?;
+                if track_ids.is_empty() {
+                    return Ok(());
+                }
+
+                let request =
+                    PlayerRequest::StartPlayback(Playback::URIs(track_ids, None), Some(true));
+                reset_auto_mix_on_new_playback(state, &request);
                 let playback = state.player.read().buffered_playback.clone();
                 let playback = self.handle_player_request(request, playback).await?;
                 state.player.write().buffered_playback = playback;
@@ -278,11 +403,24 @@ impl Client {
                 self.retrieve_current_playback(state, true).await?;
             }
             ClientRequest::GetDevices => {
-                let devices = self.device().await?;
-                state.player.write().devices = devices
+                let devices = self
+                    .device()
+                    .await?
                     .into_iter()
                     .filter_map(Device::try_from_device)
-                    .collect();
+                    .collect::<Vec<_>>();
+
+                // open the device list popup with the active device selected, unless
+                // another popup has been opened while the devices were being refreshed
+                let mut list_state = crate::utils::new_list_state();
+                list_state.select(Some(
+                    devices.iter().position(|d| d.is_active).unwrap_or_default(),
+                ));
+                state.player.write().devices = devices;
+                let mut ui = state.ui.lock();
+                if ui.popup.is_none() {
+                    ui.popup = Some(PopupState::DeviceList(list_state));
+                }
             }
             ClientRequest::GetUserPlaylists => {
                 let playlists = self.current_user_playlists().await?;
@@ -316,7 +454,10 @@ impl Client {
             }
             ClientRequest::GetUserTopTracks => {
                 let uri = &USER_TOP_TRACKS_ID.uri;
-                if !state.data.read().caches.context.contains_key(uri) {
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(uri),
+                ) {
                     let tracks = self.current_user_top_tracks().await?;
                     state.data.write().caches.context.insert(
                         uri.to_owned(),
@@ -328,6 +469,40 @@ impl Client {
                     );
                 }
             }
+            ClientRequest::GetUserRecentlyAddedTracks => {
+                let uri = &USER_RECENTLY_ADDED_TRACKS_ID.uri;
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(uri),
+                ) {
+                    let tracks = self.current_user_recently_added_tracks().await?;
+                    state.data.write().caches.context.insert(
+                        uri.to_owned(),
+                        Context::Tracks {
+                            tracks,
+                            desc: "User's recently added tracks".to_string(),
+                        },
+                        *TTL_CACHE_DURATION,
+                    );
+                }
+            }
+            ClientRequest::GetUserUnsortedLikedTracks => {
+                let uri = &USER_UNSORTED_LIKED_TRACKS_ID.uri;
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(uri),
+                ) {
+                    let tracks = self.current_user_unsorted_liked_tracks(state).await?;
+                    state.data.write().caches.context.insert(
+                        uri.to_owned(),
+                        Context::Tracks {
+                            tracks,
+                            desc: "User's liked tracks not in any playlist".to_string(),
+                        },
+                        *TTL_CACHE_DURATION,
+                    );
+                }
+            }
             ClientRequest::GetUserSavedTracks => {
                 let tracks = self.current_user_saved_tracks().await?;
                 let tracks_hm = tracks
@@ -352,46 +527,140 @@ impl Client {
                     *TTL_CACHE_DURATION,
                 );
             }
-            ClientRequest::GetUserRecentlyPlayedTracks => {
-                let uri = &USER_RECENTLY_PLAYED_TRACKS_ID.uri;
-                if !state.data.read().caches.context.contains_key(uri) {
-                    let tracks = self.current_user_recently_played_tracks().await?;
-                    state.data.write().caches.context.insert(
-                        uri.to_owned(),
-                        Context::Tracks {
-                            tracks,
-                            desc: "User's recently played tracks".to_string(),
-                        },
+            ClientRequest::GetUserSavedEpisodes => {
+                let episodes = self.current_user_saved_episodes().await?;
+                store_data_into_file_cache(
+                    FileCacheKey::SavedEpisodes,
+                    &config::get_config().cache_folder,
+                    &episodes,
+                )
+                .context("store user's saved episodes into the cache folder")?;
+                state.data.write().user_data.saved_episodes = episodes;
+            }
+            #[cfg(feature = "image")]
+            ClientRequest::GetAlbumCoverImages(urls) => {
+                for url in urls {
+                    if let Err(err) = self
+                        .cache_cover_image(state, &url, &Self::album_cover_image_path(&url))
+                        .await
+                    {
+                        tracing::warn!("Failed to get the album cover image {url}: {err:#}");
+                    }
+                }
+            }
+            ClientRequest::GetTrackCredits(track_id) => {
+                let uri = track_id.uri();
+                if !crate::metrics::record_cache_lookup(
+                    "track_credits",
+                    state.data.read().caches.track_credits.contains_key(&uri),
+                ) {
+                    // the track's available markets are only returned if no market is specified
+                    let track = self.spotify.track(track_id, None).await?;
+                    state.data.write().caches.track_credits.insert(
+                        uri,
+                        track.into(),
+                        *TTL_CACHE_DURATION,
+                    );
+                }
+            }
+            ClientRequest::GetAudiobookChapters(audiobook_id) => {
+                if !state
+                    .data
+                    .read()
+                    .caches
+                    .chapters
+                    .contains_key(&audiobook_id)
+                {
+                    // a show without chapters is cached with no chapters, so that it isn't
+                    // displayed as being loaded forever
+                    let chapters = match self.audiobook_chapters(&audiobook_id).await {
+                        Ok(chapters) => chapters,
+                        Err(err) => {
+                            tracing::warn!("Failed to get chapters of {audiobook_id}: {err:#}");
+                            vec![]
+                        }
+                    };
+                    state.data.write().caches.chapters.insert(
+                        audiobook_id,
+                        chapters,
+                        *TTL_CACHE_DURATION,
+                    );
+                }
+            }
+            ClientRequest::GetShowEpisodes(show_id) => {
+                if !state
+                    .data
+                    .read()
+                    .caches
+                    .show_episodes
+                    .contains_key(show_id.id())
+                {
+                    let episodes = self.show_episodes(show_id.as_ref()).await?;
+                    state.data.write().caches.show_episodes.insert(
+                        show_id.id().to_string(),
+                        episodes,
                         *TTL_CACHE_DURATION,
                     );
                 }
             }
+            ClientRequest::GetUserRecentlyPlayedTracks => {
+                let tracks = self.current_user_recently_played_tracks().await?;
+                state.data.write().user_data.recently_played_tracks = tracks;
+            }
             ClientRequest::GetContext(context) => {
                 let uri = context.uri();
-                if !state.data.read().caches.context.contains_key(&uri) {
-                    let context = match context {
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(&uri),
+                ) {
+                    state
+                        .data
+                        .write()
+                        .context_loading_status
+                        .insert(uri.clone(), LoadingStatus::Loading);
+
+                    let result = match context {
                         ContextId::Playlist(playlist_id) => {
-                            self.playlist_context(playlist_id).await?
-                        }
-                        ContextId::Album(album_id) => self.album_context(album_id).await?,
-                        ContextId::Artist(artist_id) => self.artist_context(artist_id).await?,
-                        ContextId::Tracks(_) => {
-                            anyhow::bail!(
-                                "`GetContext` request for `tracks` context is not supported!"
-                            );
+                            self.playlist_context(playlist_id).await
                         }
+                        ContextId::Album(album_id) => self.album_context(album_id).await,
+                        ContextId::Artist(artist_id) => self.artist_context(artist_id).await,
+                        ContextId::Tracks(_) => Err(anyhow::anyhow!(
+                            "`GetContext` request for `tracks` context is not supported!"
+                        )),
                     };
 
-                    state
-                        .data
-                        .write()
-                        .caches
-                        .context
-                        .insert(uri, context, *TTL_CACHE_DURATION);
+                    let mut data = state.data.write();
+                    match result {
+                        Ok(mut context) => {
+                            data.context_loading_status.remove(&uri);
+                            // restore the context's remembered track order
+                            if let Some(view) = data.context_views.get(&uri) {
+                                view.sort_tracks(context.tracks_mut());
+                            }
+                            data.caches
+                                .context
+                                .insert(uri, context, *TTL_CACHE_DURATION);
+                        }
+                        Err(err) => {
+                            data.context_loading_status
+                                .insert(uri, LoadingStatus::Failed);
+                            return Err(err);
+                        }
+                    }
                 }
             }
+            ClientRequest::RefreshPlaylistContext(playlist_id) => {
+                self.refresh_playlist_context(state, playlist_id).await?;
+            }
+            ClientRequest::GetContextTrackTempos(context_id) => {
+                self.context_track_tempos(state, context_id).await?;
+            }
             ClientRequest::Search(query) => {
-                if !state.data.read().caches.search.contains_key(&query) {
+                if !crate::metrics::record_cache_lookup(
+                    "search",
+                    state.data.read().caches.search.contains_key(&query),
+                ) {
                     let results = self.search(&query).await?;
 
                     state
@@ -407,7 +676,10 @@ impl Client {
                 seed_name: name,
             } => {
                 let radio_uri = format!("radio:{uri}");
-                if !state.data.read().caches.context.contains_key(&radio_uri) {
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(&radio_uri),
+                ) {
                     let tracks = self.radio_tracks(uri).await?;
 
                     state.data.write().caches.context.insert(
@@ -420,38 +692,185 @@ impl Client {
                     );
                 }
             }
+            ClientRequest::GetGenreTracks(genre) => {
+                let genre_uri = format!("genre:{genre}");
+                if !crate::metrics::record_cache_lookup(
+                    "context",
+                    state.data.read().caches.context.contains_key(&genre_uri),
+                ) {
+                    state
+                        .data
+                        .write()
+                        .context_loading_status
+                        .insert(genre_uri.clone(), LoadingStatus::Loading);
+
+                    let result = self.genre_tracks(state, &genre).await;
+
+                    let mut data = state.data.write();
+                    match result {
+                        Ok(tracks) => {
+                            data.context_loading_status.remove(&genre_uri);
+                            data.caches.context.insert(
+                                genre_uri,
+                                Context::Tracks {
+                                    tracks,
+                                    desc: format!("{genre} Recommendations"),
+                                },
+                                *TTL_CACHE_DURATION,
+                            );
+                        }
+                        Err(err) => {
+                            data.context_loading_status
+                                .insert(genre_uri, LoadingStatus::Failed);
+                            return Err(err);
+                        }
+                    }
+                }
+            }
             ClientRequest::AddTrackToQueue(track_id) => {
-                self.add_item_to_queue(PlayableId::Track(track_id), None)
-                    .await?
+                self.add_item_to_queue(PlayableId::Track(track_id.as_ref()), None)
+                    .await?;
+                // update the queue to reflect the newly added track
+                let queue = self.current_user_queue().await?;
+                let track_name = queue.queue.iter().rev().find_map(|item| match item {
+                    rspotify_model::PlayableItem::Track(t) if t.id.as_ref() == Some(&track_id) => {
+                        Some(t.name.clone())
+                    }
+                    _ => None,
+                });
+                state.player.write().queue = Some(queue);
+                state.ui.lock().show_toast(match track_name {
+                    Some(name) => format!("{}: {name}", tr("Added to the queue")),
+                    None => tr("Added to the queue").to_string(),
+                });
+            }
+            ClientRequest::PlayTrackNext(track_id) => {
+                self.play_track_next(state, track_id).await?;
+                state.player.write().queue = Some(self.current_user_queue().await?);
+            }
+            ClientRequest::ReplaceQueue(items) => {
+                self.replace_queue(state, items).await?;
+            }
+            ClientRequest::AddEpisodeToQueue(episode_id) => {
+                self.add_item_to_queue(PlayableId::Episode(episode_id), None)
+                    .await?;
+                state.player.write().queue = Some(self.current_user_queue().await?);
+                state.ui.lock().show_toast(tr("Added to the queue"));
             }
-            ClientRequest::AddTrackToPlaylist(playlist_id, track_id) => {
-                self.add_track_to_playlist(state, playlist_id, track_id)
+            ClientRequest::AddTracksToPlaylist(playlist_id, tracks) => {
+                self.add_tracks_to_playlist(state, playlist_id, tracks)
                     .await?;
             }
             ClientRequest::AddAlbumToQueue(album_id) => {
                 let album_context = self.album_context(album_id).await?;
 
-                if let Context::Album { album: _, tracks } = album_context {
+                if let Context::Album { album, tracks } = album_context {
                     for track in tracks {
                         self.add_item_to_queue(PlayableId::Track(track.id), None)
                             .await?;
                     }
+                    state.ui.lock().show_toast(format!(
+                        "{}: {}",
+                        tr("Added to the queue"),
+                        album.name
+                    ));
                 }
+                state.player.write().queue = Some(self.current_user_queue().await?);
             }
             ClientRequest::DeleteTrackFromPlaylist(playlist_id, track_id) => {
                 self.delete_track_from_playlist(state, playlist_id, track_id)
                     .await?;
             }
+            ClientRequest::ArchivePlaylist(playlist_id) => {
+                let name = match state.data.read().caches.context.get(&playlist_id.uri()) {
+                    Some(Context::Playlist { playlist, .. }) => playlist.name.clone(),
+                    _ => anyhow::bail!("expect the playlist's context to be loaded"),
+                };
+                let archive_name = format!("{name} — {}", chrono::Local::now().format("%Y-%m"));
+                self.archive_playlist(state, playlist_id, archive_name, false)
+                    .await?;
+            }
+            ClientRequest::AutoArchivePlaylists => {
+                self.auto_archive_playlists(state).await?;
+            }
+            ClientRequest::RefreshToken => {
+                self.refresh_token_if_expiring(TOKEN_REFRESH_MARGIN).await?;
+            }
+            ClientRequest::Authenticate { username, password } => {
+                match crate::auth::new_session_with_password(
+                    &self.auth_config,
+                    username.clone(),
+                    password.0,
+                )
+                .await
+                {
+                    Ok(session) => {
+                        self.use_session(state, session).await;
+                        state
+                            .ui
+                            .lock()
+                            .show_toast(format!("{}: {username}", tr("Authenticated")));
+                    }
+                    Err(err) => {
+                        tracing::warn!("Failed to authenticate: {err:#}");
+                        let mut ui = state.ui.lock();
+                        ui.popup = Some(PopupState::Authenticate {
+                            username: crate::ui::single_line_input::LineInput::new(
+                                username.chars().collect(),
+                            ),
+                            password: Default::default(),
+                            current_field: AuthenticateCurrentField::Password,
+                        });
+                        ui.show_toast(tr("Failed to authenticate"));
+                    }
+                }
+            }
+            ClientRequest::MergePlaylists {
+                source_id,
+                target_id,
+            } => {
+                self.merge_playlists(state, source_id, target_id).await?;
+            }
             ClientRequest::AddToLibrary(item) => {
                 self.add_to_library(state, item).await?;
             }
+            ClientRequest::AddAlbumsToLibrary(albums) => {
+                self.add_albums_to_library(state, albums).await?;
+            }
             ClientRequest::DeleteFromLibrary(id) => {
                 self.delete_from_library(state, id).await?;
             }
+            ClientRequest::DeleteItemsFromLibrary(ids) => {
+                self.delete_items_from_library(state, ids).await;
+            }
+            ClientRequest::DeleteSavedEpisode(id) => {
+                self.api_delete("me/episodes", &serde_json::json!({ "ids": [id.id()] }))
+                    .await?;
+                state
+                    .data
+                    .write()
+                    .user_data
+                    .saved_episodes
+                    .retain(|e| e.id != id);
+            }
+            ClientRequest::PlayNextChapter => {
+                self.play_next_chapter(state).await?;
+            }
             ClientRequest::GetCurrentUserQueue => {
                 let queue = self.current_user_queue().await?;
                 state.player.write().queue = Some(queue);
             }
+            ClientRequest::PrefetchNextTrack => {
+                let queue = self.current_user_queue().await?;
+                #[cfg(feature = "image")]
+                if let Some(rspotify::model::PlayableItem::Track(track)) = queue.queue.first() {
+                    if let Some(url) = crate::utils::get_track_album_image_url(track) {
+                        self.cache_cover_image(state, url, &Self::track_cover_image_path(track))
+                            .await?;
+                    }
+                }
+                state.player.write().queue = Some(queue);
+            }
             ClientRequest::ReorderPlaylistItems {
                 playlist_id,
                 insert_index,
@@ -537,6 +956,46 @@ impl Client {
         }
     }
 
+    /// Transfer the current playback from a remote device to the integrated device,
+    /// preserving the playback's position
+    #[cfg(feature = "streaming")]
+    async fn take_over_playback(&self, state: &SharedState) -> Result<()> {
+        if self.stream_conn.lock().is_none() {
+            anyhow::bail!("the integrated device is not running");
+        }
+        let device_id = self.session().await.device_id().to_string();
+
+        let (progress, is_playing) = {
+            let player = state.player.read();
+            match player.buffered_playback {
+                Some(ref playback) if playback.device_id.as_deref() != Some(device_id.as_str()) => {
+                    (player.playback_progress(), playback.is_playing)
+                }
+                // no remote playback to take over
+                _ => return Ok(()),
+            }
+        };
+        let timer = std::time::Instant::now();
+
+        self.transfer_playback(&device_id, Some(is_playing)).await?;
+        tracing::info!("Took over the playback on the integrated device (id={device_id})");
+
+        // The transferred playback may not resume at the remote device's latest position,
+        // so explicitly seek to the position estimated at the transfer's completion
+        if let Some(progress) = progress {
+            let position = if is_playing {
+                progress + chrono::TimeDelta::from_std(timer.elapsed()).unwrap_or_default()
+            } else {
+                progress
+            };
+            self.seek_track(position, Some(&device_id)).await?;
+        }
+
+        self.retrieve_current_playback(state, true).await?;
+        self.update_playback(state);
+        Ok(())
+    }
+
     pub fn update_playback(&self, state: &SharedState) {
         // After handling a request changing the player's playback,
         // update the playback state by making multiple get-playback requests.
@@ -559,10 +1018,34 @@ impl Client {
         });
     }
 
+    pub fn update_playback_after_transfer(&self, state: &SharedState, device_id: String) {
+        // Right after a playback transfer, Spotify server usually still reports the old device.
+        // Refreshing the playback at that point would point the buffered playback back to the old device,
+        // so wait until the new device is reported before updating the playback state.
+        let client = self.clone();
+        let state = state.clone();
+        tokio::task::spawn(async move {
+            let delay = std::time::Duration::from_millis(500);
+            for _ in 0..10 {
+                tokio::time::sleep(delay).await;
+                match client.current_playback(None, None::<Vec<_>>).await {
+                    Ok(Some(playback)) if playback.device.id.as_ref() == Some(&device_id) => break,
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(
+                        "Encountered an error when waiting for the transferred playback: {err:#}"
+                    ),
+                }
+            }
+            if let Err(err) = client.retrieve_current_playback(&state, false).await {
+                tracing::error!("Encountered an error when updating the playback state: {err:#}");
+            }
+        });
+    }
+
     /// Get Spotify's available browse categories
     pub async fn browse_categories(&self) -> Result<Vec<Category>> {
         let first_page = self
-            .categories_manual(Some("EN"), None, Some(50), None)
+            .categories_manual(Some("EN"), browse_country(), Some(50), None)
             .await?;
 
         Ok(first_page.items.into_iter().map(Category::from).collect())
@@ -571,12 +1054,39 @@ impl Client {
     /// Get Spotify's available browse playlists of a given category
     pub async fn browse_category_playlists(&self, category_id: &str) -> Result<Vec<Playlist>> {
         let first_page = self
-            .category_playlists_manual(category_id, None, Some(50), None)
+            .category_playlists_manual(category_id, browse_country(), Some(50), None)
             .await?;
 
         Ok(first_page.items.into_iter().map(Playlist::from).collect())
     }
 
+    /// Get Spotify's featured playlists
+    pub async fn browse_featured_playlists(&self) -> Result<Vec<Playlist>> {
+        let featured = self
+            .featured_playlists(Some("EN"), browse_country(), None, Some(50), None)
+            .await?;
+
+        Ok(featured
+            .playlists
+            .items
+            .into_iter()
+            .map(Playlist::from)
+            .collect())
+    }
+
+    /// Get Spotify's new album releases
+    pub async fn new_releases(&self) -> Result<Vec<Album>> {
+        let first_page = self
+            .new_releases_manual(browse_country(), Some(50), None)
+            .await?;
+
+        Ok(first_page
+            .items
+            .into_iter()
+            .filter_map(Album::try_from_simplified_album)
+            .collect())
+    }
+
     /// Find an available device. If found, return the device's ID.
     async fn find_available_device(&self) -> Result<Option<String>> {
         let devices = self.device().await?.into_iter().collect::<Vec<_>>();
@@ -617,41 +1127,109 @@ impl Client {
 
         // Prioritize the `default_device` specified in the application's configurations,
         // otherwise, use the first available device.
+        let default_device = configs.app_config.default_device();
         let id = devices
             .iter()
-            .position(|d| d.0 == configs.app_config.default_device)
+            .position(|d| d.0 == default_device)
             .unwrap_or_default();
 
         Ok(Some(devices.remove(id).1))
     }
 
+    /// Get the user's liked tracks that are not in any of the user's playlists
+    pub async fn current_user_unsorted_liked_tracks(
+        &self,
+        state: &SharedState,
+    ) -> Result<Vec<Track>> {
+        let liked_tracks = self.current_user_saved_tracks().await?;
+        let playlist_ids = state
+            .data
+            .read()
+            .user_data
+            .playlists
+            .iter()
+            .map(|p| p.id.clone())
+            .collect::<Vec<_>>();
+
+        let mut playlist_track_uris = HashSet::new();
+        for id in playlist_ids {
+            let tracks = self.playlist_tracks(state, id).await?;
+            playlist_track_uris.extend(tracks.iter().map(|t| t.id.uri()));
+        }
+
+        Ok(liked_tracks
+            .into_iter()
+            .filter(|t| !playlist_track_uris.contains(&t.id.uri()))
+            .collect())
+    }
+
     /// Get the saved (liked) tracks of the current user
     pub async fn current_user_saved_tracks(&self) -> Result<Vec<Track>> {
         let first_page = self
-            .current_user_saved_tracks_manual(Some(Market::FromToken), Some(50), None)
+            .current_user_saved_tracks_manual(Some(market()), Some(50), None)
             .await?;
         let tracks = self.all_paging_items(first_page, &market_query()).await?;
         Ok(tracks
             .into_iter()
-            .filter_map(|t| Track::try_from_full_track(t.track))
+            .filter_map(|t| {
+                let mut track = Track::try_from_full_track(t.track)?;
+                track.added_at = t.added_at.timestamp() as u64;
+                Some(track)
+            })
             .collect())
     }
 
-    /// Get the recently played tracks of the current user
-    pub async fn current_user_recently_played_tracks(&self) -> Result<Vec<Track>> {
+    /// Get the tracks recently added to the current user's library, which are the liked tracks
+    /// and the tracks of the recently saved albums, ordered from the most recently added
+    pub async fn current_user_recently_added_tracks(&self) -> Result<Vec<Track>> {
+        let mut tracks = self.current_user_saved_tracks().await?;
+
+        let saved_albums = self
+            .current_user_saved_albums_manual(
+                Some(market()),
+                Some(RECENTLY_SAVED_ALBUMS_LIMIT),
+                None,
+            )
+            .await?
+            .items;
+        for saved_album in saved_albums {
+            let added_at = saved_album.added_at.timestamp() as u64;
+            let first_page = saved_album.album.tracks.clone();
+            let album: Album = saved_album.album.into();
+            let album_tracks = self.all_paging_items(first_page, &Query::new()).await?;
+            tracks.extend(
+                album_tracks
+                    .into_iter()
+                    .filter_map(Track::try_from_simplified_track)
+                    .map(|mut t| {
+                        t.album = Some(album.clone());
+                        t.added_at = added_at;
+                        t
+                    }),
+            );
+        }
+
+        // the sort is stable, so an album's tracks are kept in the album's order
+        tracks.sort_by_key(|t| std::cmp::Reverse(t.added_at));
+        Ok(tracks)
+    }
+
+    /// Get the recently played tracks of the current user, including
+    /// a track's repeated plays, ordered from the most recently played
+    pub async fn current_user_recently_played_tracks(&self) -> Result<Vec<PlayedTrack>> {
         let first_page = self.current_user_recently_played(Some(50), None).await?;
 
         let play_histories = self.all_cursor_based_paging_items(first_page).await?;
-
-        // de-duplicate the tracks returned from the recently-played API
-        let mut tracks = Vec::<Track>::new();
-        for history in play_histories {
-            if !tracks.iter().any(|t| t.name == history.track.name) {
-                if let Some(track) = Track::try_from_full_track(history.track) {
-                    tracks.push(track);
-                }
-            }
-        }
+        let mut tracks = play_histories
+            .into_iter()
+            .filter_map(|history| {
+                Some(PlayedTrack {
+                    track: Track::try_from_full_track(history.track)?,
+                    played_at: history.played_at,
+                })
+            })
+            .collect::<Vec<_>>();
+        tracks.sort_by_key(|t| std::cmp::Reverse(t.played_at));
         Ok(tracks)
     }
 
@@ -713,7 +1291,7 @@ impl Client {
     /// Get all saved albums of the current user
     pub async fn current_user_saved_albums(&self) -> Result<Vec<Album>> {
         let first_page = self
-            .current_user_saved_albums_manual(Some(Market::FromToken), Some(50), None)
+            .current_user_saved_albums_manual(Some(market()), Some(50), None)
             .await?;
 
         let albums = self.all_paging_items(first_page, &Query::new()).await?;
@@ -722,37 +1300,170 @@ impl Client {
         Ok(albums.into_iter().map(|a| a.album.into()).collect())
     }
 
-    /// Get all albums of an artist
-    pub async fn artist_albums(&self, artist_id: ArtistId<'_>) -> Result<Vec<Album>> {
-        let payload = market_query();
+    /// Get all saved episodes of the current user
+    pub async fn current_user_saved_episodes(&self) -> Result<Vec<Episode>> {
+        #[derive(Deserialize)]
+        struct SavedEpisode {
+            episode: rspotify_model::FullEpisode,
+        }
 
-        let mut singles = {
-            let first_page = self
-                .artist_albums_manual(
-                    artist_id.as_ref(),
-                    Some(rspotify_model::AlbumType::Single),
-                    Some(Market::FromToken),
-                    Some(50),
-                    None,
-                )
-                .await?;
-            self.all_paging_items(first_page, &payload).await
-        }?;
-        let mut albums = {
-            let first_page = self
-                .artist_albums_manual(
-                    artist_id.as_ref(),
-                    Some(rspotify_model::AlbumType::Album),
-                    Some(Market::FromToken),
-                    Some(50),
-                    None,
-                )
-                .await?;
-            self.all_paging_items(first_page, &payload).await
-        }?;
-        albums.append(&mut singles);
+        // rspotify doesn't provide an API to get the user's saved episodes
+        let mut payload = market_query();
+        payload.insert("limit", "50");
+        let first_page = self
+            .http_get::<Page<SavedEpisode>>(
+                &format!("{SPOTIFY_API_ENDPOINT}/me/episodes"),
+                &payload,
+            )
+            .await?;
 
-        // converts `rspotify_model::SimplifiedAlbum` into `state::Album`
+        let episodes = self.all_paging_items(first_page, &market_query()).await?;
+        Ok(episodes.into_iter().map(|e| e.episode.into()).collect())
+    }
+
+    /// Get all episodes of a show
+    pub async fn show_episodes(&self, show_id: ShowId<'_>) -> Result<Vec<Episode>> {
+        let show = self.get_a_show(show_id, Some(market())).await?;
+        let episodes = self
+            .all_paging_items(show.episodes, &market_query())
+            .await?;
+        Ok(episodes
+            .into_iter()
+            .map(|e| Episode::from_simplified_episode(e, show.id.clone(), show.name.clone()))
+            .collect())
+    }
+
+    /// Get all chapters of an audiobook
+    pub async fn audiobook_chapters(&self, audiobook_id: &str) -> Result<Vec<Chapter>> {
+        #[derive(Deserialize)]
+        struct ResumePoint {
+            fully_played: bool,
+            resume_position_ms: u64,
+        }
+        #[derive(Deserialize)]
+        struct SimplifiedChapter {
+            id: String,
+            name: String,
+            chapter_number: usize,
+            duration_ms: u64,
+            resume_point: Option<ResumePoint>,
+        }
+
+        // rspotify doesn't support audiobooks, so the chapters are retrieved using the Web API.
+        // Getting chapters of a show that is not an audiobook fails.
+        let mut payload = market_query();
+        payload.insert("limit", "50");
+        let first_page = self
+            .http_get::<Page<SimplifiedChapter>>(
+                &format!("{SPOTIFY_API_ENDPOINT}/audiobooks/{audiobook_id}/chapters"),
+                &payload,
+            )
+            .await
+            .context(format!("get chapters of audiobook {audiobook_id}"))?;
+
+        let chapters = self.all_paging_items(first_page, &market_query()).await?;
+        Ok(chapters
+            .into_iter()
+            .filter_map(|c| {
+                let (resume_position, fully_played) = match c.resume_point {
+                    Some(p) => (
+                        Some(std::time::Duration::from_millis(p.resume_position_ms))
+                            .filter(|d| !p.fully_played && !d.is_zero()),
+                        p.fully_played,
+                    ),
+                    None => (None, false),
+                };
+                Some(Chapter {
+                    id: EpisodeId::from_id(c.id).ok()?,
+                    name: c.name,
+                    chapter_number: c.chapter_number,
+                    duration: std::time::Duration::from_millis(c.duration_ms),
+                    resume_position,
+                    fully_played,
+                })
+            })
+            .collect())
+    }
+
+    /// Play the chapter following the currently playing chapter of an audiobook
+    async fn play_next_chapter(&self, state: &SharedState) -> Result<()> {
+        let (episode_uri, audiobook_id) = match state.player.read().current_playing_episode() {
+            Some(episode) => (episode.id.uri(), episode.show.id.id().to_string()),
+            None => anyhow::bail!("no audiobook chapter is playing"),
+        };
+
+        let cached_chapters = state
+            .data
+            .read()
+            .caches
+            .chapters
+            .get(&audiobook_id)
+            .cloned();
+        let chapters = match cached_chapters {
+            Some(chapters) => chapters,
+            None => {
+                let chapters = self.audiobook_chapters(&audiobook_id).await?;
+                state.data.write().caches.chapters.insert(
+                    audiobook_id,
+                    chapters.clone(),
+                    *TTL_CACHE_DURATION,
+                );
+                chapters
+            }
+        };
+
+        let next_chapter = chapters
+            .iter()
+            .position(|c| c.id.uri() == episode_uri)
+            .and_then(|i| chapters.get(i + 1))
+            .context("no next chapter in the audiobook")?;
+
+        let playback = state.player.read().buffered_playback.clone();
+        let playback = self
+            .handle_player_request(
+                PlayerRequest::StartPlayback(
+                    Playback::Episode(next_chapter.id.clone(), None),
+                    None,
+                ),
+                playback,
+            )
+            .await?;
+        state.player.write().buffered_playback = playback;
+        self.update_playback(state);
+        Ok(())
+    }
+
+    /// Get all albums of an artist
+    pub async fn artist_albums(&self, artist_id: ArtistId<'_>) -> Result<Vec<Album>> {
+        let payload = market_query();
+
+        let mut singles = {
+            let first_page = self
+                .artist_albums_manual(
+                    artist_id.as_ref(),
+                    Some(rspotify_model::AlbumType::Single),
+                    Some(market()),
+                    Some(50),
+                    None,
+                )
+                .await?;
+            self.all_paging_items(first_page, &payload).await
+        }?;
+        let mut albums = {
+            let first_page = self
+                .artist_albums_manual(
+                    artist_id.as_ref(),
+                    Some(rspotify_model::AlbumType::Album),
+                    Some(market()),
+                    Some(50),
+                    None,
+                )
+                .await?;
+            self.all_paging_items(first_page, &payload).await
+        }?;
+        albums.append(&mut singles);
+
+        // converts `rspotify_model::SimplifiedAlbum` into `state::Album`
         let albums = albums
             .into_iter()
             .filter_map(Album::try_from_simplified_album)
@@ -789,11 +1500,252 @@ impl Client {
                 )
                 .await?
             }
+            Playback::Episode(id, position) => {
+                self.start_uris_playback([PlayableId::Episode(id)], device_id, None, position)
+                    .await?
+            }
         }
 
         Ok(())
     }
 
+    /// Get a random selection of the tracks in the user's library, which is limited by
+    /// the `tracks_playback_limit` config option
+    async fn shuffled_library_track_ids(
+        &self,
+        state: &SharedState,
+        source: LibraryShuffleSource,
+    ) -> Result<Vec<TrackId<'static>>> {
+        let limit = config::get_config().app_config.tracks_playback_limit;
+
+        let mut track_ids = match source {
+            LibraryShuffleSource::LikedTracks => {
+                let saved_tracks = state
+                    .data
+                    .read()
+                    .user_data
+                    .saved_tracks
+                    .values()
+                    .filter(|t| t.is_playable())
+                    .map(|t| t.id.clone())
+                    .collect::<Vec<_>>();
+                if saved_tracks.is_empty() {
+                    self.current_user_saved_tracks()
+                        .await?
+                        .into_iter()
+                        .filter(|t| t.is_playable())
+                        .map(|t| t.id)
+                        .collect()
+                } else {
+                    saved_tracks
+                }
+            }
+            LibraryShuffleSource::SavedAlbums => {
+                let mut albums = state.data.read().user_data.saved_albums.clone();
+                albums.shuffle(&mut rand::thread_rng());
+
+                // fetch the tracks of randomly chosen albums until there are enough tracks
+                let mut track_ids = vec![];
+                for album in albums {
+                    if track_ids.len() >= limit {
+                        break;
+                    }
+                    if let Context::Album { tracks, .. } = self.album_context(album.id).await? {
+                        track_ids
+                            .extend(tracks.into_iter().filter(|t| t.is_playable()).map(|t| t.id));
+                    }
+                }
+                track_ids
+            }
+        };
+
+        track_ids.shuffle(&mut rand::thread_rng());
+        track_ids.truncate(limit);
+        Ok(track_ids)
+    }
+
+    /// Play a track right after the current track.
+    ///
+    /// The Spotify API only supports adding an item to the end of the queue, so the queue
+    /// is rebuilt with the track followed by the queue's upcoming tracks. The queue API returns
+    /// only the first upcoming tracks, so the remaining tracks of the playing context are
+    /// taken from the context's cached tracks (if any).
+    async fn play_track_next(&self, state: &SharedState, track_id: TrackId<'static>) -> Result<()> {
+        let queue = self.current_user_queue().await?;
+        let current_track_id = match queue.currently_playing {
+            Some(rspotify::model::PlayableItem::Track(track)) => track.id,
+            _ => None,
+        };
+        let Some(current_track_id) = current_track_id else {
+            // without a playing track, playing the track next is the same as queueing it
+            self.add_item_to_queue(PlayableId::Track(track_id), None)
+                .await?;
+            return Ok(());
+        };
+
+        let mut upcoming_track_ids = queue
+            .queue
+            .into_iter()
+            .filter_map(|item| match item {
+                rspotify::model::PlayableItem::Track(track) => track.id,
+                rspotify::model::PlayableItem::Episode(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        // append the context's tracks after the current track that aren't in the returned queue
+        let context_id = state.player.read().playing_context_id();
+        if let Some(context_id) = context_id {
+            if let Some(context) = state.data.read().caches.context.get(&context_id.uri()) {
+                let tracks = context.tracks();
+                if let Some(pos) = tracks.iter().position(|t| t.id == current_track_id) {
+                    let queued_ids = upcoming_track_ids.iter().cloned().collect::<HashSet<_>>();
+                    upcoming_track_ids.extend(
+                        tracks[pos + 1..]
+                            .iter()
+                            .filter(|t| !queued_ids.contains(&t.id))
+                            .map(|t| t.id.clone()),
+                    );
+                }
+            }
+        }
+
+        self.rebuild_queue(
+            state,
+            PlayableId::Track(current_track_id),
+            std::iter::once(track_id)
+                .chain(upcoming_track_ids)
+                .map(PlayableId::from)
+                .collect(),
+        )
+        .await
+    }
+
+    /// Replace the queue's upcoming items with the given items
+    async fn replace_queue(
+        &self,
+        state: &SharedState,
+        items: Vec<rspotify::model::PlayableItem>,
+    ) -> Result<()> {
+        let queue = self.current_user_queue().await?;
+        let current_id = queue
+            .currently_playing
+            .as_ref()
+            .and_then(|item| item.id())
+            .map(|id| id.into_static());
+        match current_id {
+            Some(current_id) => {
+                let item_ids = items
+                    .iter()
+                    .filter_map(|item| item.id().map(|id| id.into_static()))
+                    .collect();
+                self.rebuild_queue(state, current_id, item_ids).await
+            }
+            // there is no queue to replace without a playing item
+            None => Ok(()),
+        }
+    }
+
+    /// Rebuild the queue by starting a new playback of the current item followed by
+    /// the given upcoming items, resuming the current item from its current progress.
+    ///
+    /// The new playback replaces the playing context (if any).
+    async fn rebuild_queue(
+        &self,
+        state: &SharedState,
+        current_id: PlayableId<'static>,
+        upcoming_ids: Vec<PlayableId<'static>>,
+    ) -> Result<()> {
+        let device_id = state
+            .player
+            .read()
+            .buffered_playback
+            .as_ref()
+            .and_then(|p| p.device_id.clone());
+        // re-read the progress right before restarting, as the buffered progress may be stale
+        let progress = self
+            .current_playback(None, None::<Vec<_>>)
+            .await?
+            .and_then(|p| p.progress);
+        self.start_uris_playback(
+            std::iter::once(current_id).chain(upcoming_ids),
+            device_id.as_deref(),
+            Some(rspotify::model::Offset::Position(chrono::Duration::zero())),
+            progress,
+        )
+        .await?;
+        self.update_playback(state);
+
+        Ok(())
+    }
+
+    /// Start the auto-mix mode on the integrated device, which re-orders the queue's upcoming
+    /// tracks so that consecutive tracks have close tempos and keys, and fades between tracks
+    #[cfg(feature = "streaming")]
+    async fn start_auto_mix(&self, state: &SharedState) -> Result<()> {
+        let device_id = self.session().await.device_id().to_string();
+        let (is_integrated_device, progress) = {
+            let player = state.player.read();
+            (
+                player
+                    .buffered_playback
+                    .as_ref()
+                    .is_some_and(|p| p.device_id.as_deref() == Some(device_id.as_str())),
+                player.playback_progress(),
+            )
+        };
+        if !is_integrated_device {
+            state
+                .ui
+                .lock()
+                .show_toast(tr("Auto-mix is only available on the integrated device").to_string());
+            return Ok(());
+        }
+
+        let queue = self.current_user_queue().await?;
+        let current_track_id = match queue.currently_playing {
+            Some(rspotify::model::PlayableItem::Track(track)) => track.id,
+            _ => None,
+        };
+        let Some(current_track_id) = current_track_id else {
+            state
+                .ui
+                .lock()
+                .show_toast(tr("No playing track to mix from").to_string());
+            return Ok(());
+        };
+        let track_ids = std::iter::once(current_track_id)
+            .chain(queue.queue.into_iter().filter_map(|item| match item {
+                rspotify::model::PlayableItem::Track(track) => track.id,
+                rspotify::model::PlayableItem::Episode(_) => None,
+            }))
+            .collect::<Vec<_>>();
+
+        let mut features = vec![];
+        // the audio features API accepts at most 100 tracks per request
+        for ids in track_ids.chunks(100) {
+            if let Some(f) = self.tracks_features(ids.iter().cloned()).await? {
+                features.extend(f);
+            }
+        }
+        let track_ids = crate::streaming::order_tracks_for_auto_mix(&track_ids, &features);
+
+        self.start_uris_playback(
+            track_ids.into_iter().map(PlayableId::from),
+            Some(&device_id),
+            Some(rspotify::model::Offset::Position(chrono::Duration::zero())),
+            progress,
+        )
+        .await?;
+        state.player.write().is_auto_mix_enabled = true;
+        state
+            .ui
+            .lock()
+            .show_toast(tr("Auto-mix enabled").to_string());
+        self.update_playback(state);
+
+        Ok(())
+    }
+
     /// Get recommendation (radio) tracks based on a seed
     pub async fn radio_tracks(&self, seed_uri: String) -> Result<Vec<Track>> {
         let session = self.session().await;
@@ -841,7 +1793,67 @@ impl Client {
             .filter_map(|t| TrackId::from_id(t.original_gid).ok());
 
         // Retrieve tracks based on IDs
-        let tracks = self.tracks(track_ids, Some(Market::FromToken)).await?;
+        let tracks = self.tracks(track_ids, Some(market())).await?;
+        let tracks = tracks
+            .into_iter()
+            .filter_map(Track::try_from_full_track)
+            .collect();
+
+        Ok(tracks)
+    }
+
+    /// Get the genres available as recommendation seeds
+    pub async fn recommendation_genre_seeds(&self) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct GenreSeeds {
+            genres: Vec<String>,
+        }
+
+        let seeds = self
+            .http_get::<GenreSeeds>(
+                &format!("{SPOTIFY_API_ENDPOINT}/recommendations/available-genre-seeds"),
+                &Query::new(),
+            )
+            .await?;
+        Ok(seeds.genres)
+    }
+
+    /// Get recommended tracks seeded by a genre, which is mapped onto
+    /// one of the available recommendation genre seeds
+    pub async fn genre_tracks(&self, state: &SharedState, genre: &str) -> Result<Vec<Track>> {
+        if state.data.read().browse.genre_seeds.is_none() {
+            let seeds = self.recommendation_genre_seeds().await?;
+            state.data.write().browse.genre_seeds = Some(seeds);
+        }
+        let seed = state
+            .data
+            .read()
+            .browse
+            .genre_seed(genre)
+            .map(String::from)
+            .with_context(|| format!("no recommendation genre seed matches {genre}"))?;
+
+        let recommendations = self
+            .recommendations(
+                std::iter::empty(),
+                None::<Vec<ArtistId>>,
+                Some([seed.as_str()]),
+                None::<Vec<TrackId>>,
+                Some(market()),
+                Some(50),
+            )
+            .await?;
+        let track_ids = recommendations
+            .tracks
+            .into_iter()
+            .filter_map(|t| t.id)
+            .collect::<Vec<_>>();
+        if track_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Retrieve tracks based on IDs
+        let tracks = self.tracks(track_ids, Some(market())).await?;
         let tracks = tracks
             .into_iter()
             .filter_map(Track::try_from_full_track)
@@ -906,67 +1918,292 @@ impl Client {
     ) -> Result<rspotify_model::SearchResult> {
         Ok(self
             .spotify
-            .search(query, _type, None, None, None, None)
+            .search(query, _type, Some(market()), None, None, None)
             .await?)
     }
 
-    /// Add a track to a playlist
-    pub async fn add_track_to_playlist(
+    /// Add tracks to a playlist in batched API requests, appending them in the given order.
+    ///
+    /// The existing occurrences of the tracks are removed to ensure no duplication in the playlist.
+    pub async fn add_tracks_to_playlist(
         &self,
         state: &SharedState,
         playlist_id: PlaylistId<'_>,
-        track_id: TrackId<'_>,
+        mut tracks: Vec<Track>,
     ) -> Result<()> {
-        // remove all the occurrences of the track to ensure no duplication in the playlist
-        self.playlist_remove_all_occurrences_of_items(
-            playlist_id.as_ref(),
-            [PlayableId::Track(track_id.as_ref())],
-            None,
-        )
-        .await?;
+        let mut track_ids = HashSet::new();
+        tracks.retain(|t| track_ids.insert(t.id.clone()));
+        if tracks.is_empty() {
+            return Ok(());
+        }
 
-        self.playlist_add_items(
-            playlist_id.as_ref(),
-            [PlayableId::Track(track_id.as_ref())],
-            None,
+        let request = async {
+            // the API removes at most 100 items per request
+            for chunk in tracks.chunks(100) {
+                self.playlist_remove_all_occurrences_of_items(
+                    playlist_id.as_ref(),
+                    chunk.iter().map(|t| PlayableId::Track(t.id.as_ref())),
+                    None,
+                )
+                .await?;
+            }
+            self.append_tracks_to_playlist(playlist_id.as_ref(), &tracks)
+                .await
+        };
+        self.edit_playlist_optimistically(
+            state,
+            &playlist_id,
+            |playlist_tracks| {
+                playlist_tracks.retain(|t| !track_ids.contains(&t.id));
+                playlist_tracks.extend(tracks.iter().cloned());
+            },
+            request,
         )
         .await?;
 
-        // After adding a new track to a playlist, remove the cache of that playlist to force refetching new data
-        state.data.write().caches.context.remove(&playlist_id.uri());
+        // the tracks are now in a playlist, so they're no longer unsorted liked tracks
+        if let Some(context) = state
+            .data
+            .write()
+            .caches
+            .context
+            .get_mut(&USER_UNSORTED_LIKED_TRACKS_ID.uri)
+        {
+            context.tracks_mut().retain(|t| !track_ids.contains(&t.id));
+        }
 
+        if tracks.len() > 1 {
+            state.ui.lock().show_toast(format!(
+                "{} {} {}",
+                tr("Added"),
+                tracks.len(),
+                tr("tracks to the playlist")
+            ));
+        }
         Ok(())
     }
 
-    /// Remove a track from a playlist
-    pub async fn delete_track_from_playlist(
+    /// Get a playlist's tracks, using the playlist's cached context (if any)
+    /// to avoid re-fetching the playlist
+    async fn playlist_tracks(
         &self,
         state: &SharedState,
+        playlist_id: PlaylistId<'static>,
+    ) -> Result<Vec<Track>> {
+        let cached_tracks = state
+            .data
+            .read()
+            .caches
+            .context
+            .get(&playlist_id.uri())
+            .map(|c| c.tracks().to_vec());
+        match cached_tracks {
+            Some(tracks) => Ok(tracks),
+            None => Ok(self.playlist_context(playlist_id).await?.tracks().to_vec()),
+        }
+    }
+
+    /// Append (non-empty) tracks to a playlist, returning the result of the last API request
+    async fn append_tracks_to_playlist(
+        &self,
         playlist_id: PlaylistId<'_>,
-        track_id: TrackId<'_>,
+        tracks: &[Track],
+    ) -> Result<rspotify::model::PlaylistResult> {
+        let mut result = None;
+        // the API adds at most 100 items per request
+        for chunk in tracks.chunks(100) {
+            result = Some(
+                self.playlist_add_items(
+                    playlist_id.as_ref(),
+                    chunk.iter().map(|t| PlayableId::Track(t.id.as_ref())),
+                    None,
+                )
+                .await?,
+            );
+        }
+        result.context("no tracks to append")
+    }
+
+    /// Copy a playlist's current tracks into a new private playlist with the given name.
+    ///
+    /// If `skip_existing` is set, the user's playlists are re-fetched right before creating
+    /// the new playlist, which isn't created if a playlist with the same name already exists
+    /// (e.g. created by another running instance in the meantime).
+    pub async fn archive_playlist(
+        &self,
+        state: &SharedState,
+        playlist_id: PlaylistId<'static>,
+        archive_name: String,
+        skip_existing: bool,
     ) -> Result<()> {
-        // remove all the occurrences of the track to ensure no duplication in the playlist
-        self.playlist_remove_all_occurrences_of_items(
-            playlist_id.as_ref(),
-            [PlayableId::Track(track_id.as_ref())],
-            None,
+        let user_id = state
+            .data
+            .read()
+            .user_data
+            .user
+            .as_ref()
+            .map(|u| u.id.clone());
+        // the current user may not be loaded yet on startup
+        let user_id = match user_id {
+            Some(id) => id,
+            None => self.current_user().await?.id,
+        };
+        let tracks = self.playlist_tracks(state, playlist_id.clone()).await?;
+
+        if skip_existing
+            && self
+                .current_user_playlists()
+                .await?
+                .iter()
+                .any(|p| p.name == archive_name)
+        {
+            tracing::info!("Playlist {archive_name} already exists, skip archiving playlist (id={playlist_id})");
+            return Ok(());
+        }
+
+        let archive: Playlist = self
+            .user_playlist_create(
+                user_id,
+                &archive_name,
+                Some(false),
+                Some(false),
+                Some(&format!(
+                    "Archived on {}",
+                    chrono::Local::now().format("%Y-%m-%d")
+                )),
+            )
+            .await?
+            .into();
+        if !tracks.is_empty() {
+            self.append_tracks_to_playlist(archive.id.as_ref(), &tracks)
+                .await?;
+        }
+        tracing::info!(
+            "Archived playlist (id={playlist_id}) into a new playlist (id={})",
+            archive.id
+        );
+
+        let toast = format!("{}: {}", tr("Archived the playlist"), archive.name);
+        state.data.write().user_data.playlists.insert(0, archive);
+        state.ui.lock().show_toast(toast);
+        Ok(())
+    }
+
+    /// Archive the configured `auto_archived_playlists` into new playlists named after
+    /// the playlists and the current week (e.g. "Discover Weekly — 2024-W23"),
+    /// skipping the playlists already archived this week (possibly by another running instance)
+    pub async fn auto_archive_playlists(&self, state: &SharedState) -> Result<()> {
+        let names = &config::get_config().app_config.auto_archived_playlists;
+        let week = chrono::Local::now().format("%G-W%V").to_string();
+
+        // fetch the playlists instead of using the in-memory playlists,
+        // which may not be loaded yet on startup
+        let playlists = self.current_user_playlists().await?;
+        for playlist in &playlists {
+            if !names.contains(&playlist.name) {
+                continue;
+            }
+            let archive_name = format!("{} — {week}", playlist.name);
+            if playlists.iter().any(|p| p.name == archive_name) {
+                continue;
+            }
+            if let Err(err) = self
+                .archive_playlist(state, playlist.id.clone(), archive_name, true)
+                .await
+            {
+                tracing::error!("Failed to archive playlist {}: {err:#}", playlist.name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Append the tracks of a playlist to another playlist.
+    ///
+    /// If `deduplicate_merged_playlist_tracks` is enabled, the tracks already in the target playlist
+    /// and the repeated tracks of the source playlist are skipped.
+    pub async fn merge_playlists(
+        &self,
+        state: &SharedState,
+        source_id: PlaylistId<'static>,
+        target_id: PlaylistId<'static>,
+    ) -> Result<()> {
+        let mut tracks = self.playlist_tracks(state, source_id).await?;
+        if config::get_config()
+            .app_config
+            .deduplicate_merged_playlist_tracks
+        {
+            let mut track_ids = self
+                .playlist_tracks(state, target_id.clone())
+                .await?
+                .into_iter()
+                .map(|t| t.id)
+                .collect::<HashSet<_>>();
+            tracks.retain(|t| track_ids.insert(t.id.clone()));
+        }
+        if tracks.is_empty() {
+            state.ui.lock().show_toast(tr("No tracks to merge"));
+            return Ok(());
+        }
+
+        let request = self.append_tracks_to_playlist(target_id.as_ref(), &tracks);
+        self.edit_playlist_optimistically(
+            state,
+            &target_id,
+            |target_tracks| target_tracks.extend(tracks.iter().cloned()),
+            request,
         )
         .await?;
 
-        // After making a delete request, update the playlist in-memory data stored inside the app caches.
-        if let Some(Context::Playlist { tracks, .. }) = state
+        // the merged tracks are now in a playlist, so they're no longer unsorted liked tracks
+        if let Some(context) = state
             .data
             .write()
             .caches
             .context
-            .get_mut(&playlist_id.uri())
+            .get_mut(&USER_UNSORTED_LIKED_TRACKS_ID.uri)
         {
-            tracks.retain(|t| t.id != track_id);
+            context
+                .tracks_mut()
+                .retain(|t| !tracks.iter().any(|m| m.id == t.id));
         }
 
+        state.ui.lock().show_toast(format!(
+            "{} {} {}",
+            tr("Added"),
+            tracks.len(),
+            tr("tracks to the playlist")
+        ));
         Ok(())
     }
 
+    /// Remove a track from a playlist
+    pub async fn delete_track_from_playlist(
+        &self,
+        state: &SharedState,
+        playlist_id: PlaylistId<'_>,
+        track_id: TrackId<'_>,
+    ) -> Result<()> {
+        // remove all the occurrences of the track to ensure no duplication in the playlist
+        let request = async {
+            let result = self
+                .playlist_remove_all_occurrences_of_items(
+                    playlist_id.as_ref(),
+                    [PlayableId::Track(track_id.as_ref())],
+                    None,
+                )
+                .await?;
+            Ok(result)
+        };
+
+        self.edit_playlist_optimistically(
+            state,
+            &playlist_id,
+            |tracks| tracks.retain(|t| t.id != track_id),
+            request,
+        )
+        .await
+    }
+
     /// Reorder items in a playlist
     async fn reorder_playlist_items(
         &self,
@@ -982,27 +2219,122 @@ impl Client {
             false => insert_index,
         };
 
-        self.playlist_reorder_items(
-            playlist_id.clone(),
-            Some(range_start as i32),
-            Some(insert_before as i32),
-            range_length.map(|range_length| range_length as u32),
-            snapshot_id,
+        // reorder relative to the cached playlist's snapshot, so that the positions
+        // refer to the playlist's version shown in the app
+        let cached_snapshot_id = match state.data.read().caches.context.get(&playlist_id.uri()) {
+            Some(Context::Playlist { playlist, .. }) if !playlist.snapshot_id.is_empty() => {
+                Some(playlist.snapshot_id.clone())
+            }
+            _ => None,
+        };
+        let snapshot_id = snapshot_id.or(cached_snapshot_id.as_deref());
+
+        let request = async {
+            let result = self
+                .playlist_reorder_items(
+                    playlist_id.clone(),
+                    Some(range_start as i32),
+                    Some(insert_before as i32),
+                    range_length.map(|range_length| range_length as u32),
+                    snapshot_id,
+                )
+                .await?;
+            Ok(result)
+        };
+
+        self.edit_playlist_optimistically(
+            state,
+            &playlist_id,
+            |tracks| {
+                if range_start < tracks.len() && insert_index < tracks.len() {
+                    let track = tracks.remove(range_start);
+                    tracks.insert(insert_index, track);
+                }
+            },
+            request,
         )
-        .await?;
+        .await
+    }
 
-        // After making a reorder request, update the playlist in-memory data stored inside the app caches.
-        if let Some(Context::Playlist { tracks, .. }) = state
-            .data
-            .write()
-            .caches
-            .context
-            .get_mut(&playlist_id.uri())
+    /// Apply an edit to a playlist's in-memory tracks before the edit's API request completes.
+    ///
+    /// On success, the in-memory playlist is updated with the snapshot ID returned by the API.
+    /// On failure, a toast is shown and the playlist is re-fetched, as restoring the tracks
+    /// from before the edit would discard the edits of concurrent requests.
+    async fn edit_playlist_optimistically(
+        &self,
+        state: &SharedState,
+        playlist_id: &PlaylistId<'_>,
+        edit: impl FnOnce(&mut Vec<Track>),
+        request: impl std::future::Future<Output = Result<rspotify::model::PlaylistResult>>,
+    ) -> Result<()> {
+        let uri = playlist_id.uri();
+        if let Some(Context::Playlist { tracks, .. }) =
+            state.data.write().caches.context.get_mut(&uri)
         {
-            let track = tracks.remove(range_start);
-            tracks.insert(insert_index, track);
+            edit(tracks);
         }
 
+        match request.await {
+            Ok(result) => {
+                if let Some(Context::Playlist { playlist, .. }) =
+                    state.data.write().caches.context.get_mut(&uri)
+                {
+                    playlist.snapshot_id = result.snapshot_id;
+                }
+                Ok(())
+            }
+            Err(err) => {
+                state
+                    .ui
+                    .lock()
+                    .show_toast(tr("Failed to update the playlist"));
+                match self.playlist_context(playlist_id.as_ref()).await {
+                    Ok(mut context) => {
+                        let mut data = state.data.write();
+                        // restore the context's remembered track order
+                        if let Some(view) = data.context_views.get(&uri) {
+                            view.sort_tracks(context.tracks_mut());
+                        }
+                        data.caches
+                            .context
+                            .insert(uri, context, *TTL_CACHE_DURATION);
+                    }
+                    Err(fetch_err) => {
+                        // drop the edited playlist, so it's re-fetched the next time it's shown
+                        tracing::warn!("Failed to re-fetch playlist {uri}: {fetch_err:#}");
+                        state.data.write().caches.context.remove(&uri);
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Add albums to the user's library, skipping the albums that are already saved
+    pub async fn add_albums_to_library(
+        &self,
+        state: &SharedState,
+        albums: Vec<Album>,
+    ) -> Result<()> {
+        let albums = {
+            let data = state.data.read();
+            albums
+                .into_iter()
+                .filter(|a| !data.user_data.saved_albums.iter().any(|s| s.id == a.id))
+                .collect::<Vec<_>>()
+        };
+
+        // the saved albums API accepts at most 20 albums per request
+        for albums in albums.chunks(20) {
+            self.current_user_saved_albums_add(albums.iter().map(|a| a.id.as_ref()))
+                .await?;
+            // update the in-memory `user_data`
+            let mut data = state.data.write();
+            for album in albums.iter().rev() {
+                data.user_data.saved_albums.insert(0, album.clone());
+            }
+        }
         Ok(())
     }
 
@@ -1074,6 +2406,34 @@ impl Client {
         Ok(())
     }
 
+    /// Remove items from the user's library one by one, showing the removal's progress
+    async fn delete_items_from_library(&self, state: &SharedState, ids: Vec<ItemId>) {
+        let n_items = ids.len();
+        let mut n_failed = 0;
+        for (i, id) in ids.into_iter().enumerate() {
+            if let Err(err) = self.delete_from_library(state, id).await {
+                tracing::error!("Failed to remove an item from the library: {err:#}");
+                n_failed += 1;
+            }
+            state.ui.lock().show_toast(format!(
+                "{} {}/{n_items}",
+                tr("Removing items from the library"),
+                i + 1
+            ));
+        }
+
+        let mut toast = format!(
+            "{} {} {}",
+            tr("Removed"),
+            n_items - n_failed,
+            tr("items from the library")
+        );
+        if n_failed > 0 {
+            toast += &format!(" ({n_failed} {})", tr("failed"));
+        }
+        state.ui.lock().show_toast(toast);
+    }
+
     // Delete a Spotify item from user's library
     async fn delete_from_library(&self, state: &SharedState, id: ItemId) -> Result<()> {
         match id {
@@ -1115,12 +2475,8 @@ impl Client {
 
     /// Get a track data
     pub async fn track(&self, track_id: TrackId<'_>) -> Result<Track> {
-        Track::try_from_full_track(
-            self.spotify
-                .track(track_id, Some(Market::FromToken))
-                .await?,
-        )
-        .context("convert FullTrack into Track")
+        Track::try_from_full_track(self.spotify.track(track_id, Some(market())).await?)
+            .context("convert FullTrack into Track")
     }
 
     /// Get a playlist context data
@@ -1148,7 +2504,7 @@ impl Client {
             .into_iter()
             .filter_map(|item| match item.track {
                 Some(rspotify_model::PlayableItem::Track(track)) => {
-                    Track::try_from_full_track(track)
+                    Track::try_from_listed_full_track(track)
                 }
                 _ => None,
             })
@@ -1160,12 +2516,115 @@ impl Client {
         })
     }
 
+    /// Re-fetch a cached playlist context if the playlist's snapshot has changed,
+    /// keeping the selected track selected if the playlist is shown in the current page
+    async fn refresh_playlist_context(
+        &self,
+        state: &SharedState,
+        playlist_id: PlaylistId<'static>,
+    ) -> Result<()> {
+        #[derive(Deserialize)]
+        struct PlaylistSnapshot {
+            snapshot_id: String,
+        }
+
+        let uri = playlist_id.uri();
+        let cached_snapshot_id = match state.data.read().caches.context.get(&uri) {
+            Some(Context::Playlist { playlist, .. }) => playlist.snapshot_id.clone(),
+            _ => return Ok(()),
+        };
+
+        // only fetch the playlist's snapshot ID to avoid re-fetching an unchanged playlist
+        let snapshot = self
+            .http_get::<PlaylistSnapshot>(
+                &format!("{SPOTIFY_API_ENDPOINT}/playlists/{}", playlist_id.id()),
+                &Query::from([("fields", "snapshot_id")]),
+            )
+            .await?;
+        if snapshot.snapshot_id == cached_snapshot_id {
+            return Ok(());
+        }
+
+        tracing::info!("Playlist {uri} has a new snapshot, refreshing its context");
+        let mut context = self.playlist_context(playlist_id.clone()).await?;
+
+        let mut ui = state.ui.lock();
+        let mut data = state.data.write();
+        if let Some(view) = data.context_views.get(&uri) {
+            view.sort_tracks(context.tracks_mut());
+        }
+
+        let is_current_page = matches!(
+            ui.current_page(),
+            PageState::Context { id: Some(ContextId::Playlist(id)), .. } if *id == playlist_id
+        );
+        let selected = if is_current_page {
+            ui.current_page_mut().selected()
+        } else {
+            None
+        };
+        // the selected track's position after the refresh, which falls back to the nearest position
+        // if the selected track is removed from the playlist
+        let selected = selected.map(|selected| {
+            let new_tracks = ui.track_table_items(context.tracks());
+            let selected_id = data.caches.context.get(&uri).and_then(|c| {
+                ui.track_table_items(c.tracks())
+                    .get(selected)
+                    .map(|t| t.id.clone())
+            });
+            selected_id
+                .and_then(|id| new_tracks.iter().position(|t| t.id == id))
+                .unwrap_or(selected.min(new_tracks.len().saturating_sub(1)))
+        });
+
+        data.caches
+            .context
+            .insert(uri, context, *TTL_CACHE_DURATION);
+        if let Some(selected) = selected {
+            ui.current_page_mut().select(selected);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch the tempos of a cached context's tracks whose tempos are not known yet
+    async fn context_track_tempos(&self, state: &SharedState, context_id: ContextId) -> Result<()> {
+        let uri = context_id.uri();
+        let track_ids = match state.data.read().caches.context.get(&uri) {
+            Some(context) => context
+                .tracks()
+                .iter()
+                .filter(|t| t.tempo.is_none())
+                .map(|t| t.id.clone())
+                .collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        let mut tempos = HashMap::new();
+        // the audio features API accepts at most 100 tracks per request
+        for ids in track_ids.chunks(100) {
+            if let Some(features) = self.tracks_features(ids.iter().cloned()).await? {
+                tempos.extend(features.into_iter().map(|f| (f.id, f.tempo)));
+            }
+        }
+
+        if let Some(context) = state.data.write().caches.context.get_mut(&uri) {
+            for track in context.tracks_mut() {
+                if let Some(tempo) = tempos.get(&track.id) {
+                    track.tempo = Some(*tempo);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get an album context data
     pub async fn album_context(&self, album_id: AlbumId<'_>) -> Result<Context> {
         let album_uri = album_id.uri();
         tracing::info!("Get album context: {}", album_uri);
 
-        let album = self.album(album_id, Some(Market::FromToken)).await?;
+        let album = self.album(album_id, Some(market())).await?;
         let first_page = album.tracks.clone();
 
         // converts `rspotify_model::FullAlbum` into `state::Album`
@@ -1180,7 +2639,7 @@ impl Client {
                 // simplified track doesn't have album so
                 // we need to manually include one during
                 // converting into `state::Track`
-                Track::try_from_simplified_track(t).map(|mut t| {
+                Track::try_from_listed_simplified_track(t).map(|mut t| {
                     t.album = Some(album.clone());
                     t
                 })
@@ -1200,11 +2659,11 @@ impl Client {
         let artist = self.artist(artist_id.as_ref()).await?.into();
 
         let top_tracks = self
-            .artist_top_tracks(artist_id.as_ref(), Some(Market::FromToken))
+            .artist_top_tracks(artist_id.as_ref(), Some(market()))
             .await?;
         let top_tracks = top_tracks
             .into_iter()
-            .filter_map(Track::try_from_full_track)
+            .filter_map(Track::try_from_listed_full_track)
             .collect::<Vec<_>>();
 
         let related_artists = self.artist_related_artists(artist_id.as_ref()).await?;
@@ -1308,7 +2767,10 @@ impl Client {
     ) -> Result<()> {
         let new_track = {
             // update the playback state
-            let playback = self.current_playback(None, None::<Vec<_>>).await?;
+            let request_time = std::time::Instant::now();
+            let mut playback = self.current_playback(None, None::<Vec<_>>).await?;
+            // the returned progress is estimated to be measured in the middle of the request
+            let updated_time = request_time + request_time.elapsed() / 2;
             let mut player = state.player.write();
 
             let prev_track_name = player
@@ -1316,8 +2778,28 @@ impl Client {
                 .map(|t| t.name.to_owned())
                 .unwrap_or_default();
 
+            // keep advancing the interpolated progress if the polled progress only differs
+            // slightly from it, so that the progress bar doesn't jump back and forth between polls
+            let item_uri = |p: &rspotify_model::CurrentPlaybackContext| {
+                p.item.as_ref().and_then(|i| i.id()).map(|id| id.uri())
+            };
+            if let (Some(prev), Some(curr)) = (player.playback.as_ref(), playback.as_mut()) {
+                let interpolated_progress = player.playback_progress().map(|p| {
+                    p - chrono::TimeDelta::from_std(updated_time.elapsed()).unwrap_or_default()
+                });
+                if let (Some(interpolated), Some(polled)) = (interpolated_progress, curr.progress) {
+                    if prev.is_playing
+                        && curr.is_playing
+                        && item_uri(prev) == item_uri(curr)
+                        && (polled - interpolated).abs() < PLAYBACK_PROGRESS_JITTER_TOLERANCE
+                    {
+                        curr.progress = Some(interpolated);
+                    }
+                }
+            }
+
             player.playback = playback;
-            player.playback_last_updated_time = Some(std::time::Instant::now());
+            player.playback_last_updated_time = Some(updated_time);
 
             let curr_track_name = player
                 .current_playing_track()
@@ -1364,6 +2846,7 @@ impl Client {
     // Handle new track event
     #[cfg(any(feature = "image", feature = "notify"))]
     async fn handle_new_track_event(&self, state: &SharedState) -> Result<()> {
+        #[cfg(feature = "notify")]
         let configs = config::get_config();
 
         let track = match state.player.read().current_playing_track() {
@@ -1376,18 +2859,69 @@ impl Client {
             None => return Ok(()),
         };
 
+        let path = Self::track_cover_image_path(&track);
+
+        #[cfg(feature = "image")]
+        self.cache_cover_image(state, url, &path).await?;
+
+        // notify user about the playback's change if any
+        #[cfg(feature = "notify")]
+        if configs.app_config.enable_notify {
+            // for Linux, ensure that the cached cover image is available to render the notification's thumbnail
+            #[cfg(all(unix, not(target_os = "macos")))]
+            self.retrieve_image(url, &path, true).await?;
+
+            if !configs.app_config.notify_streaming_only || self.stream_conn.lock().is_some() {
+                Self::notify_new_track(track, &path)?;
+            }
+            #[cfg(not(feature = "streaming"))]
+            Self::notify_new_track(track, &path, state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the path to the cached cover image of a track
+    #[cfg(any(feature = "image", feature = "notify"))]
+    fn track_cover_image_path(track: &rspotify::model::FullTrack) -> std::path::PathBuf {
         let path = (format!(
             "{}-{}-cover.jpg",
             track.album.name,
             crate::utils::map_join(&track.album.artists, |a| &a.name, ", ")
         ))
         .replace('/', ""); // remove invalid characters from the file's name
-        let path = configs.cache_folder.join("image").join(path);
+        config::get_config().cache_folder.join("image").join(path)
+    }
 
-        #[cfg(feature = "image")]
-        if !state.data.read().caches.images.contains_key(url) {
+    /// Get the path to the cached album cover image whose URL is `url`
+    #[cfg(feature = "image")]
+    fn album_cover_image_path(url: &str) -> std::path::PathBuf {
+        // the last segment of a Spotify image's URL is the image's unique ID
+        let id = url.rsplit('/').next().unwrap_or(url);
+        config::get_config()
+            .cache_folder
+            .join("image")
+            .join(format!("{id}-album-cover.jpg"))
+    }
+
+    /// Load a cover image from its `url` into the application's image cache (if not yet)
+    #[cfg(feature = "image")]
+    async fn cache_cover_image(
+        &self,
+        state: &SharedState,
+        url: &str,
+        path: &std::path::Path,
+    ) -> Result<()> {
+        if !crate::metrics::record_cache_lookup(
+            "images",
+            state.data.read().caches.images.contains_key(url),
+        ) {
             let bytes = self
-                .retrieve_image(url, &path, configs.app_config.enable_cover_image_cache)
+                .retrieve_image(
+                    url,
+                    path,
+                    config::get_config().app_config.enable_cover_image_cache,
+                )
                 .await?;
             let image =
                 image::load_from_memory(&bytes).context("Failed to load image from memory")?;
@@ -1398,21 +2932,6 @@ impl Client {
                 .images
                 .insert(url.to_owned(), image, *TTL_CACHE_DURATION);
         }
-
-        // notify user about the playback's change if any
-        #[cfg(feature = "notify")]
-        if configs.app_config.enable_notify {
-            // for Linux, ensure that the cached cover image is available to render the notification's thumbnail
-            #[cfg(all(unix, not(target_os = "macos")))]
-            self.retrieve_image(url, &path, true).await?;
-
-            if !configs.app_config.notify_streaming_only || self.stream_conn.lock().is_some() {
-                Self::notify_new_track(track, &path)?;
-            }
-            #[cfg(not(feature = "streaming"))]
-            Self::notify_new_track(track, &path, state)?;
-        }
-
         Ok(())
     }
 
@@ -17,6 +17,52 @@ pub enum PlayerRequest {
     StartPlayback(Playback, Option<bool>),
 }
 
+#[derive(Clone)]
+/// A secret (e.g. a password), which is redacted in the logs
+pub struct Secret(pub String);
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// The tracks of the user's library to start a shuffled playback from
+pub enum LibraryShuffleSource {
+    LikedTracks,
+    SavedAlbums,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+/// A player event sent to the player event webhook
+pub enum PlayerWebhookEvent {
+    TrackChanged {
+        track: WebhookTrack,
+    },
+    Paused {
+        track: WebhookTrack,
+    },
+    Resumed {
+        track: WebhookTrack,
+    },
+    /// the upcoming items (identified by URIs) of the queue have changed
+    QueueChanged {
+        queue: Vec<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+/// A track's data sent to the player event webhook
+pub struct WebhookTrack {
+    pub uri: String,
+    pub name: String,
+    pub artists: Vec<String>,
+    pub album: String,
+    pub duration_ms: i64,
+}
+
 #[derive(Clone, Debug)]
 /// A request to the client
 pub enum ClientRequest {
@@ -24,23 +70,64 @@ pub enum ClientRequest {
     GetDevices,
     GetBrowseCategories,
     GetBrowseCategoryPlaylists(Category),
+    GetNewReleases,
+    GetFeaturedPlaylists,
     GetUserPlaylists,
     GetUserSavedAlbums,
     GetUserFollowedArtists,
     GetUserSavedTracks,
     GetUserTopTracks,
     GetUserRecentlyPlayedTracks,
+    GetUserRecentlyAddedTracks,
+    GetUserUnsortedLikedTracks,
+    GetUserSavedEpisodes,
+    GetAudiobookChapters(String),
+    GetShowEpisodes(ShowId<'static>),
+    /// load the album cover images (identified by URLs) into the image cache
+    #[cfg(feature = "image")]
+    GetAlbumCoverImages(Vec<String>),
+    GetTrackCredits(TrackId<'static>),
     GetContext(ContextId),
+    /// re-fetch a cached playlist context if the playlist has been modified since it was fetched
+    RefreshPlaylistContext(PlaylistId<'static>),
+    /// fetch the tempos of a cached context's tracks from their audio features
+    GetContextTrackTempos(ContextId),
     GetCurrentPlayback,
     GetRadioTracks {
         seed_uri: String,
         seed_name: String,
     },
+    GetGenreTracks(String),
     Search(String),
     AddTrackToQueue(TrackId<'static>),
+    /// play a track right after the current track
+    PlayTrackNext(TrackId<'static>),
+    /// replace the upcoming items of the queue, e.g. to reorder or remove queued items
+    ReplaceQueue(Vec<rspotify_model::PlayableItem>),
+    /// start a shuffled playback of the tracks in the user's library
+    StartShuffledLibraryPlayback(LibraryShuffleSource),
     AddAlbumToQueue(AlbumId<'static>),
-    AddTrackToPlaylist(PlaylistId<'static>, TrackId<'static>),
+    AddEpisodeToQueue(EpisodeId<'static>),
+    /// add tracks to a playlist in batched requests, keeping the tracks' order
+    AddTracksToPlaylist(PlaylistId<'static>, Vec<Track>),
     DeleteTrackFromPlaylist(PlaylistId<'static>, TrackId<'static>),
+    /// copy a playlist's tracks into a new dated playlist
+    ArchivePlaylist(PlaylistId<'static>),
+    /// archive the configured auto-archived playlists that aren't archived yet this week
+    AutoArchivePlaylists,
+    /// refresh the access token if it's about to expire
+    RefreshToken,
+    /// create a new session by authenticating with Spotify's username and password,
+    /// which replaces the current session whose cached credentials are invalid
+    Authenticate {
+        username: String,
+        password: Secret,
+    },
+    /// append the tracks of a playlist to another playlist
+    MergePlaylists {
+        source_id: PlaylistId<'static>,
+        target_id: PlaylistId<'static>,
+    },
     ReorderPlaylistItems {
         playlist_id: PlaylistId<'static>,
         insert_index: usize,
@@ -49,10 +136,20 @@ pub enum ClientRequest {
         snapshot_id: Option<String>,
     },
     AddToLibrary(Item),
+    AddAlbumsToLibrary(Vec<Album>),
     DeleteFromLibrary(ItemId),
+    DeleteItemsFromLibrary(Vec<ItemId>),
+    DeleteSavedEpisode(EpisodeId<'static>),
     ConnectDevice,
     Player(PlayerRequest),
     GetCurrentUserQueue,
+    /// start the auto-mix mode on the integrated device
+    #[cfg(feature = "streaming")]
+    StartAutoMix,
+    /// skip to the next chapter of the currently playing audiobook
+    PlayNextChapter,
+    /// prefetch the next track's metadata and cover image before the current track ends
+    PrefetchNextTrack,
     #[cfg(feature = "lyric-finder")]
     GetLyric {
         track: String,
@@ -60,6 +157,8 @@ pub enum ClientRequest {
     },
     #[cfg(feature = "streaming")]
     RestartIntegratedClient,
+    #[cfg(feature = "streaming")]
+    TakeOverPlayback,
     CreatePlaylist {
         playlist_name: String,
         public: bool,
@@ -67,3 +166,16 @@ pub enum ClientRequest {
         desc: String,
     },
 }
+
+impl ClientRequest {
+    /// Get the request fetching a context's data (if the context can be fetched), e.g. when the
+    /// context is shown but its data isn't in the cache yet or has expired from the cache
+    pub fn get_context(id: &ContextId) -> Option<Self> {
+        match id {
+            ContextId::Tracks(TracksId { uri, .. }) => uri
+                .strip_prefix("genre:")
+                .map(|genre| Self::GetGenreTracks(genre.to_string())),
+            _ => Some(Self::GetContext(id.clone())),
+        }
+    }
+}
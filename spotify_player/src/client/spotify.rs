@@ -83,6 +83,24 @@ impl Spotify {
     }
 }
 
+impl Spotify {
+    /// refreshes the access token stored inside the client if it expires within `margin`,
+    /// so that requests don't wait for the token to be refreshed after its expiry
+    pub async fn refresh_token_if_expiring(&self, margin: chrono::Duration) -> Result<()> {
+        let is_expiring = match self.token.lock().await.unwrap().as_ref() {
+            Some(token) => token
+                .expires_at
+                .is_some_and(|expires_at| expires_at - margin <= chrono::Utc::now()),
+            None => false,
+        };
+        if is_expiring {
+            tracing::info!("Refreshing the access token before its expiry");
+            self.refresh_token().await?;
+        }
+        Ok(())
+    }
+}
+
 // TODO: remove the below uses of `maybe_async` crate once
 // async trait is fully supported in stable Rust.
 
@@ -1,4 +1,4 @@
-use crate::state::{Album, Artist, DataReadGuard, Playlist, Track};
+use crate::state::{Album, Artist, DataReadGuard, Episode, Playlist, Track};
 use serde::Deserialize;
 
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -8,8 +8,12 @@ pub enum Command {
 
     NextTrack,
     PreviousTrack,
+    NextChapter,
+    AddBookmark,
+    JumpToNextBookmark,
     ResumePause,
     PlayRandom,
+    ShuffleLibrary,
     Repeat,
     ToggleFakeTrackRepeatMode,
     Shuffle,
@@ -18,8 +22,14 @@ pub enum Command {
     Mute,
     SeekForward,
     SeekBackward,
+    SeekToPosition,
+    LikeCurrentTrack,
+    UnlikeCurrentTrack,
+    DislikeAndSkipCurrentTrack,
 
     Quit,
+    #[cfg(unix)]
+    Suspend,
     OpenCommandHelp,
     ClosePopup,
 
@@ -36,18 +46,39 @@ pub enum Command {
 
     #[cfg(feature = "streaming")]
     RestartIntegratedClient,
+    #[cfg(feature = "streaming")]
+    TakeOverPlayback,
+    #[cfg(feature = "streaming")]
+    ToggleAutoMix,
 
     FocusNextWindow,
     FocusPreviousWindow,
+    GrowFocusedWindow,
+    ShrinkFocusedWindow,
+    GrowPlaybackWindow,
+    ShrinkPlaybackWindow,
+    ToggleAlbumGridView,
+    ToggleDetailPanel,
+    ToggleTrackGrouping,
+    ToggleTrackGroupCollapse,
 
     SwitchTheme,
     SwitchDevice,
+    SetDefaultDevice,
     Search,
+    SearchArtistGenre,
+    BrowseGenre,
     Queue,
 
     ShowActionsOnSelectedItem,
     ShowActionsOnCurrentTrack,
+    GoToSelectedItemRadio,
     AddSelectedItemToQueue,
+    PlaySelectedTrackNext,
+    ToggleLikeSelectedTrack,
+    ToggleVisualSelection,
+    CopySelectedTrackUris,
+    AddSelectedTracksToPlaylist,
 
     BrowseUserPlaylists,
     BrowseUserFollowedArtists,
@@ -56,26 +87,52 @@ pub enum Command {
     CurrentlyPlayingContextPage,
     TopTrackPage,
     RecentlyPlayedTrackPage,
+    RecentlyAddedTrackPage,
+    UnsortedLikedTrackPage,
     LikedTrackPage,
     #[cfg(feature = "lyric-finder")]
     LyricPage,
     LibraryPage,
+    SavedEpisodePage,
+    AudiobookChapterPage,
+    ShowPage,
     SearchPage,
     BrowsePage,
+    NewReleasesPage,
+    FeaturedPlaylistsPage,
+    SaveAllNewReleases,
     PreviousPage,
+    SwapContext,
     OpenSpotifyLinkFromClipboard,
 
+    GoToTrackNumber,
     SortTrackByTitle,
     SortTrackByArtists,
     SortTrackByAlbum,
     SortTrackByDuration,
     SortTrackByAddedDate,
+    SortTrackByPopularity,
     ReverseTrackOrder,
+    FilterTracksByYear,
+    FilterTracksByTempo,
+    ToggleAlbumColumn,
+    ToggleYearColumn,
+    TogglePopularityColumn,
 
     MovePlaylistItemUp,
     MovePlaylistItemDown,
+    RemoveFromQueue,
 
     CreatePlaylist,
+
+    TogglePartyMode,
+    ModerateGuestRequests,
+    CleanUpLibrary,
+    ShowMostSkippedTracks,
+    ComparePlaylists,
+    CopyMissingTracks,
+    MergePlaylists,
+    ArchivePlaylist,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -86,11 +143,13 @@ pub enum TrackAction {
     ShowActionsOnAlbum,
     ShowActionsOnArtist,
     AddToQueue,
+    PlayNext,
     AddToPlaylist,
     DeleteFromCurrentPlaylist,
     AddToLikedTracks,
     DeleteFromLikedTracks,
     CopyTrackLink,
+    ShowCredits,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -112,6 +171,14 @@ pub enum ArtistAction {
     CopyArtistLink,
 }
 
+#[derive(Debug, Copy, Clone)]
+pub enum EpisodeAction {
+    AddToQueue,
+    DeleteFromSavedEpisodes,
+    CopyEpisodeLink,
+    GoToShow,
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum PlaylistAction {
     GoToPlaylistRadio,
@@ -131,6 +198,8 @@ pub fn construct_track_actions(track: &Track, data: &DataReadGuard) -> Vec<Track
         TrackAction::CopyTrackLink,
         TrackAction::AddToPlaylist,
         TrackAction::AddToQueue,
+        TrackAction::PlayNext,
+        TrackAction::ShowCredits,
     ];
 
     // check if the track is a liked track
@@ -198,21 +267,41 @@ impl Command {
         match self {
             Self::None => "do nothing",
             Self::NextTrack => "next track",
-            Self::PreviousTrack => "previous track",
+            Self::PreviousTrack => "previous track (or the start of the current track)",
+            Self::NextChapter => "skip to the next chapter of the playing audiobook",
+            Self::AddBookmark => "add a named bookmark at the current playback position",
+            Self::JumpToNextBookmark => "jump to the next bookmark of the current track/episode",
             Self::ResumePause => "resume/pause based on the current playback",
             Self::PlayRandom => "play a random track in the current context",
+            Self::ShuffleLibrary => {
+                "shuffle-play liked tracks (or saved albums if focused) in the library"
+            }
             Self::Repeat => "cycle the repeat mode",
             Self::ToggleFakeTrackRepeatMode => "toggle fake track repeat mode",
             Self::Shuffle => "toggle the shuffle mode",
-            Self::VolumeUp => "increase playback volume by 5%",
-            Self::VolumeDown => "decrease playback volume by 5%",
+            Self::VolumeUp => "increase playback volume by 5% (configurable)",
+            Self::VolumeDown => "decrease playback volume by 5% (configurable)",
             Self::Mute => "toggle playback volume between 0% and previous level",
-            Self::SeekForward => "seek forward by 5s",
-            Self::SeekBackward => "seek backward by 5s",
+            Self::SeekForward => "seek forward by 5s (configurable)",
+            Self::SeekBackward => "seek backward by 5s (configurable)",
+            Self::SeekToPosition => "seek to a position (mm:ss) of the current playback",
+            Self::LikeCurrentTrack => "like the current track and continue playing",
+            Self::UnlikeCurrentTrack => "remove the current track from the liked tracks",
+            Self::DislikeAndSkipCurrentTrack => {
+                "unlike, add to the blocklist and skip the current track"
+            }
             Self::Quit => "quit the application",
+            #[cfg(unix)]
+            Self::Suspend => "suspend the application",
             Self::ClosePopup => "close a popup",
             #[cfg(feature = "streaming")]
             Self::RestartIntegratedClient => "restart the integrated librespot client",
+            #[cfg(feature = "streaming")]
+            Self::TakeOverPlayback => "take over the playback on the integrated device",
+            #[cfg(feature = "streaming")]
+            Self::ToggleAutoMix => {
+                "toggle the (experimental) auto-mix mode on the integrated device"
+            }
             Self::SelectNextOrScrollDown => "select the next item in a list/table or scroll down",
             Self::SelectPreviousOrScrollUp => {
                 "select the previous item in a list/table or scroll up"
@@ -233,37 +322,172 @@ impl Command {
             Self::RefreshPlayback => "manually refresh the current playback",
             Self::ShowActionsOnSelectedItem => "open a popup showing actions on a selected item",
             Self::ShowActionsOnCurrentTrack => "open a popup showing actions on the current track",
+            Self::GoToSelectedItemRadio => {
+                "go to the radio (recommendations) page of the selected track or artist"
+            }
             Self::AddSelectedItemToQueue => "add the selected item to queue",
+            Self::PlaySelectedTrackNext => "play the selected track right after the current track",
+            Self::ToggleLikeSelectedTrack => {
+                "add the selected track to (or remove it from) the liked tracks"
+            }
+            Self::ToggleVisualSelection => {
+                "start/stop selecting a range of tracks in a track table"
+            }
+            Self::CopySelectedTrackUris => {
+                "copy the selected tracks' URIs (newline-separated) to the clipboard"
+            }
+            Self::AddSelectedTracksToPlaylist => "add the selected tracks to a playlist",
             Self::FocusNextWindow => "focus the next focusable window (if any)",
             Self::FocusPreviousWindow => "focus the previous focusable window (if any)",
+            Self::GrowFocusedWindow => "grow the focused window (if resizable)",
+            Self::ShrinkFocusedWindow => "shrink the focused window (if resizable)",
+            Self::GrowPlaybackWindow => "grow the playback window",
+            Self::ShrinkPlaybackWindow => "shrink the playback window",
+            Self::ToggleAlbumGridView => "toggle between the list and grid views of album lists",
+            Self::ToggleDetailPanel => "toggle a panel showing details of the selected item",
+            Self::ToggleTrackGrouping => {
+                "toggle grouping context track tables by albums (or discs)"
+            }
+            Self::ToggleTrackGroupCollapse => "collapse (or expand) the selected track's group",
             Self::SwitchTheme => "open a popup for switching theme",
             Self::SwitchDevice => "open a popup for switching device",
+            Self::SetDefaultDevice => {
+                "set the selected device in the device popup as the default device"
+            }
             Self::Search => "open a popup for searching in the current page",
+            Self::SearchArtistGenre => {
+                "open a popup for searching by a genre of the current artist"
+            }
+            Self::BrowseGenre => {
+                "browse recommendations of the artist's or selected category's genre"
+            }
             Self::BrowseUserPlaylists => "open a popup for browsing user's playlists",
             Self::BrowseUserFollowedArtists => "open a popup for browsing user's followed artists",
             Self::BrowseUserSavedAlbums => "open a popup for browsing user's saved albums",
             Self::CurrentlyPlayingContextPage => "go to the currently playing context page",
             Self::TopTrackPage => "go to the user top track page",
             Self::RecentlyPlayedTrackPage => "go to the user recently played track page",
+            Self::RecentlyAddedTrackPage => "go to the user recently added track page",
+            Self::UnsortedLikedTrackPage => "go to the page of liked tracks not in any playlist",
             Self::LikedTrackPage => "go to the user liked track page",
             #[cfg(feature = "lyric-finder")]
             Self::LyricPage => "go to the lyric page of the current track",
             Self::LibraryPage => "go to the user library page",
+            Self::SavedEpisodePage => "go to the user saved episodes of the library page",
+            Self::AudiobookChapterPage => "go to the chapter page of the playing audiobook",
+            Self::ShowPage => "go to the episode page of the playing podcast show",
             Self::SearchPage => "go to the search page",
             Self::BrowsePage => "go to the browse page",
+            Self::NewReleasesPage => "go to the new releases page",
+            Self::FeaturedPlaylistsPage => "go to the featured playlists page",
+            Self::SaveAllNewReleases => "add all albums of the new releases page to the library",
             Self::Queue => "go to the queue page",
             Self::OpenCommandHelp => "go to the command help page",
             Self::PreviousPage => "go to the previous page",
+            Self::SwapContext => "swap between the current and the previously shown context pages",
             Self::OpenSpotifyLinkFromClipboard => "open a Spotify link from clipboard",
+            Self::GoToTrackNumber => "select the track of a number in the context's track table",
             Self::SortTrackByTitle => "sort the track table (if any) by track's title",
             Self::SortTrackByArtists => "sort the track table (if any) by track's artists",
             Self::SortTrackByAlbum => "sort the track table (if any) by track's album",
             Self::SortTrackByDuration => "sort the track table (if any) by track's duration",
             Self::SortTrackByAddedDate => "sort the track table (if any) by track's added date",
+            Self::SortTrackByPopularity => "sort the track table (if any) by track's popularity",
             Self::ReverseTrackOrder => "reverse the order of the track table (if any)",
-            Self::MovePlaylistItemUp => "move playlist item up one position",
-            Self::MovePlaylistItemDown => "move playlist item down one position",
+            Self::FilterTracksByYear => "filter the track tables by a range of release years",
+            Self::FilterTracksByTempo => "filter the track tables by a range of tempos (BPM)",
+            Self::ToggleAlbumColumn => "toggle the album column of the context's track table",
+            Self::ToggleYearColumn => "toggle the release year column of the context's track table",
+            Self::TogglePopularityColumn => {
+                "toggle the popularity column of the context's track table"
+            }
+            Self::MovePlaylistItemUp => "move playlist (or queue) item up one position",
+            Self::MovePlaylistItemDown => "move playlist (or queue) item down one position",
+            Self::RemoveFromQueue => "remove the selected item from the queue",
             Self::CreatePlaylist => "create a new playlist",
+            Self::TogglePartyMode => "toggle the party mode (protected by a passphrase)",
+            Self::ModerateGuestRequests => "accept or reject the guests' song requests",
+            Self::CleanUpLibrary => {
+                "remove multiple playlists, albums and artists from the library at once"
+            }
+            Self::ShowMostSkippedTracks => "show the most skipped tracks of the current context",
+            Self::ComparePlaylists => "compare the current playlist with another playlist",
+            Self::MergePlaylists => "append the tracks of a playlist to another playlist",
+            Self::ArchivePlaylist => "copy the current playlist's tracks into a new dated playlist",
+            Self::CopyMissingTracks => {
+                "copy the focused comparison window's tracks to the other playlist"
+            }
+        }
+    }
+
+    /// checks if the command is allowed in the kiosk mode, which only allows browsing
+    /// the playback, the queue, and search results, and adding items to the queue
+    pub fn is_allowed_in_kiosk_mode(&self) -> bool {
+        matches!(
+            self,
+            Self::None
+                | Self::OpenCommandHelp
+                | Self::ClosePopup
+                | Self::SelectNextOrScrollDown
+                | Self::SelectPreviousOrScrollUp
+                | Self::PageSelectNextOrScrollDown
+                | Self::PageSelectPreviousOrScrollUp
+                | Self::SelectFirstOrScrollToTop
+                | Self::SelectLastOrScrollToBottom
+                | Self::GoToTrackNumber
+                | Self::ChooseSelected
+                | Self::RefreshPlayback
+                | Self::FocusNextWindow
+                | Self::FocusPreviousWindow
+                | Self::ToggleDetailPanel
+                | Self::Search
+                | Self::Queue
+                | Self::AddSelectedItemToQueue
+                | Self::SearchPage
+                | Self::PreviousPage
+        )
+    }
+
+    /// checks if the command is disabled in the party mode, which prevents
+    /// editing playlists, modifying the user's account, and quitting the application
+    pub fn is_disabled_in_party_mode(&self) -> bool {
+        match self {
+            Self::Quit
+            | Self::LikeCurrentTrack
+            | Self::UnlikeCurrentTrack
+            | Self::ToggleLikeSelectedTrack
+            | Self::DislikeAndSkipCurrentTrack
+            | Self::SetDefaultDevice
+            | Self::MovePlaylistItemUp
+            | Self::MovePlaylistItemDown
+            | Self::CreatePlaylist
+            | Self::SaveAllNewReleases
+            | Self::CleanUpLibrary
+            | Self::AddSelectedTracksToPlaylist
+            | Self::CopyMissingTracks
+            | Self::MergePlaylists
+            | Self::ArchivePlaylist
+            | Self::ModerateGuestRequests => true,
+            #[cfg(unix)]
+            Self::Suspend => true,
+            _ => false,
         }
     }
 }
+
+/// constructs a list of actions on an episode
+pub fn construct_episode_actions(episode: &Episode, data: &DataReadGuard) -> Vec<EpisodeAction> {
+    let mut actions = vec![EpisodeAction::AddToQueue, EpisodeAction::CopyEpisodeLink];
+    if episode.show_id.is_some() {
+        actions.push(EpisodeAction::GoToShow);
+    }
+    if data
+        .user_data
+        .saved_episodes
+        .iter()
+        .any(|e| e.id == episode.id)
+    {
+        actions.push(EpisodeAction::DeleteFromSavedEpisodes);
+    }
+    actions
+}
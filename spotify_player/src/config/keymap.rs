@@ -2,7 +2,7 @@ use crate::{
     command::Command,
     key::{Key, KeySequence},
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -31,10 +31,26 @@ impl Default for KeymapConfig {
                     key_sequence: "p".into(),
                     command: Command::PreviousTrack,
                 },
+                Keymap {
+                    key_sequence: "M-n".into(),
+                    command: Command::NextChapter,
+                },
+                Keymap {
+                    key_sequence: "B".into(),
+                    command: Command::AddBookmark,
+                },
+                Keymap {
+                    key_sequence: "b".into(),
+                    command: Command::JumpToNextBookmark,
+                },
                 Keymap {
                     key_sequence: ".".into(),
                     command: Command::PlayRandom,
                 },
+                Keymap {
+                    key_sequence: "M-s".into(),
+                    command: Command::ShuffleLibrary,
+                },
                 Keymap {
                     key_sequence: "space".into(),
                     command: Command::ResumePause,
@@ -71,6 +87,22 @@ impl Default for KeymapConfig {
                     key_sequence: "<".into(),
                     command: Command::SeekBackward,
                 },
+                Keymap {
+                    key_sequence: "M-j".into(),
+                    command: Command::SeekToPosition,
+                },
+                Keymap {
+                    key_sequence: "L".into(),
+                    command: Command::LikeCurrentTrack,
+                },
+                Keymap {
+                    key_sequence: "C-u".into(),
+                    command: Command::UnlikeCurrentTrack,
+                },
+                Keymap {
+                    key_sequence: "X".into(),
+                    command: Command::DislikeAndSkipCurrentTrack,
+                },
                 Keymap {
                     key_sequence: "enter".into(),
                     command: Command::ChooseSelected,
@@ -95,6 +127,26 @@ impl Default for KeymapConfig {
                     key_sequence: "Z".into(),
                     command: Command::AddSelectedItemToQueue,
                 },
+                Keymap {
+                    key_sequence: "A".into(),
+                    command: Command::PlaySelectedTrackNext,
+                },
+                Keymap {
+                    key_sequence: "C-l".into(),
+                    command: Command::ToggleLikeSelectedTrack,
+                },
+                Keymap {
+                    key_sequence: "V".into(),
+                    command: Command::ToggleVisualSelection,
+                },
+                Keymap {
+                    key_sequence: "Y".into(),
+                    command: Command::CopySelectedTrackUris,
+                },
+                Keymap {
+                    key_sequence: "P".into(),
+                    command: Command::AddSelectedTracksToPlaylist,
+                },
                 Keymap {
                     key_sequence: "C-space".into(),
                     command: Command::ShowActionsOnSelectedItem,
@@ -107,11 +159,25 @@ impl Default for KeymapConfig {
                     key_sequence: "a".into(),
                     command: Command::ShowActionsOnCurrentTrack,
                 },
+                Keymap {
+                    key_sequence: "g i".into(),
+                    command: Command::GoToSelectedItemRadio,
+                },
                 #[cfg(feature = "streaming")]
                 Keymap {
                     key_sequence: "R".into(),
                     command: Command::RestartIntegratedClient,
                 },
+                #[cfg(feature = "streaming")]
+                Keymap {
+                    key_sequence: "g D".into(),
+                    command: Command::TakeOverPlayback,
+                },
+                #[cfg(feature = "streaming")]
+                Keymap {
+                    key_sequence: "M-x".into(),
+                    command: Command::ToggleAutoMix,
+                },
                 Keymap {
                     key_sequence: "tab".into(),
                     command: Command::FocusNextWindow,
@@ -120,6 +186,38 @@ impl Default for KeymapConfig {
                     key_sequence: "backtab".into(),
                     command: Command::FocusPreviousWindow,
                 },
+                Keymap {
+                    key_sequence: "M-=".into(),
+                    command: Command::GrowFocusedWindow,
+                },
+                Keymap {
+                    key_sequence: "M--".into(),
+                    command: Command::ShrinkFocusedWindow,
+                },
+                Keymap {
+                    key_sequence: "M-+".into(),
+                    command: Command::GrowPlaybackWindow,
+                },
+                Keymap {
+                    key_sequence: "M-_".into(),
+                    command: Command::ShrinkPlaybackWindow,
+                },
+                Keymap {
+                    key_sequence: "M-g".into(),
+                    command: Command::ToggleAlbumGridView,
+                },
+                Keymap {
+                    key_sequence: "M-i".into(),
+                    command: Command::ToggleDetailPanel,
+                },
+                Keymap {
+                    key_sequence: "M-t".into(),
+                    command: Command::ToggleTrackGrouping,
+                },
+                Keymap {
+                    key_sequence: "M-c".into(),
+                    command: Command::ToggleTrackGroupCollapse,
+                },
                 Keymap {
                     key_sequence: "T".into(),
                     command: Command::SwitchTheme,
@@ -128,6 +226,18 @@ impl Default for KeymapConfig {
                     key_sequence: "D".into(),
                     command: Command::SwitchDevice,
                 },
+                Keymap {
+                    key_sequence: "d".into(),
+                    command: Command::SetDefaultDevice,
+                },
+                Keymap {
+                    key_sequence: "g G".into(),
+                    command: Command::SearchArtistGenre,
+                },
+                Keymap {
+                    key_sequence: "g R".into(),
+                    command: Command::BrowseGenre,
+                },
                 Keymap {
                     key_sequence: "u p".into(),
                     command: Command::BrowseUserPlaylists,
@@ -152,6 +262,14 @@ impl Default for KeymapConfig {
                     key_sequence: "g r".into(),
                     command: Command::RecentlyPlayedTrackPage,
                 },
+                Keymap {
+                    key_sequence: "g n".into(),
+                    command: Command::RecentlyAddedTrackPage,
+                },
+                Keymap {
+                    key_sequence: "g u".into(),
+                    command: Command::UnsortedLikedTrackPage,
+                },
                 Keymap {
                     key_sequence: "g y".into(),
                     command: Command::LikedTrackPage,
@@ -170,6 +288,18 @@ impl Default for KeymapConfig {
                     key_sequence: "g l".into(),
                     command: Command::LibraryPage,
                 },
+                Keymap {
+                    key_sequence: "g e".into(),
+                    command: Command::SavedEpisodePage,
+                },
+                Keymap {
+                    key_sequence: "g c".into(),
+                    command: Command::AudiobookChapterPage,
+                },
+                Keymap {
+                    key_sequence: "g o".into(),
+                    command: Command::ShowPage,
+                },
                 Keymap {
                     key_sequence: "g s".into(),
                     command: Command::SearchPage,
@@ -178,6 +308,18 @@ impl Default for KeymapConfig {
                     key_sequence: "g b".into(),
                     command: Command::BrowsePage,
                 },
+                Keymap {
+                    key_sequence: "g N".into(),
+                    command: Command::NewReleasesPage,
+                },
+                Keymap {
+                    key_sequence: "g F".into(),
+                    command: Command::FeaturedPlaylistsPage,
+                },
+                Keymap {
+                    key_sequence: "M-a".into(),
+                    command: Command::SaveAllNewReleases,
+                },
                 Keymap {
                     key_sequence: "backspace".into(),
                     command: Command::PreviousPage,
@@ -186,6 +328,10 @@ impl Default for KeymapConfig {
                     key_sequence: "C-q".into(),
                     command: Command::PreviousPage,
                 },
+                Keymap {
+                    key_sequence: "g tab".into(),
+                    command: Command::SwapContext,
+                },
                 Keymap {
                     key_sequence: "O".into(),
                     command: Command::OpenSpotifyLinkFromClipboard,
@@ -206,6 +352,11 @@ impl Default for KeymapConfig {
                     key_sequence: "C-c".into(),
                     command: Command::Quit,
                 },
+                #[cfg(unix)]
+                Keymap {
+                    key_sequence: "C-x C-z".into(),
+                    command: Command::Suspend,
+                },
                 Keymap {
                     key_sequence: "esc".into(),
                     command: Command::ClosePopup,
@@ -266,6 +417,10 @@ impl Default for KeymapConfig {
                     key_sequence: "end".into(),
                     command: Command::SelectLastOrScrollToBottom,
                 },
+                Keymap {
+                    key_sequence: ":".into(),
+                    command: Command::GoToTrackNumber,
+                },
                 Keymap {
                     key_sequence: "s t".into(),
                     command: Command::SortTrackByTitle,
@@ -286,10 +441,66 @@ impl Default for KeymapConfig {
                     key_sequence: "s D".into(),
                     command: Command::SortTrackByAddedDate,
                 },
+                Keymap {
+                    key_sequence: "s p".into(),
+                    command: Command::SortTrackByPopularity,
+                },
                 Keymap {
                     key_sequence: "s r".into(),
                     command: Command::ReverseTrackOrder,
                 },
+                Keymap {
+                    key_sequence: "F".into(),
+                    command: Command::FilterTracksByYear,
+                },
+                Keymap {
+                    key_sequence: "M-b".into(),
+                    command: Command::FilterTracksByTempo,
+                },
+                Keymap {
+                    key_sequence: "c a".into(),
+                    command: Command::ToggleAlbumColumn,
+                },
+                Keymap {
+                    key_sequence: "c y".into(),
+                    command: Command::ToggleYearColumn,
+                },
+                Keymap {
+                    key_sequence: "c p".into(),
+                    command: Command::TogglePopularityColumn,
+                },
+                Keymap {
+                    key_sequence: "M-p".into(),
+                    command: Command::TogglePartyMode,
+                },
+                Keymap {
+                    key_sequence: "M-q".into(),
+                    command: Command::ModerateGuestRequests,
+                },
+                Keymap {
+                    key_sequence: "M-l".into(),
+                    command: Command::CleanUpLibrary,
+                },
+                Keymap {
+                    key_sequence: "M-k".into(),
+                    command: Command::ShowMostSkippedTracks,
+                },
+                Keymap {
+                    key_sequence: "g d".into(),
+                    command: Command::ComparePlaylists,
+                },
+                Keymap {
+                    key_sequence: "M-m".into(),
+                    command: Command::CopyMissingTracks,
+                },
+                Keymap {
+                    key_sequence: "M-e".into(),
+                    command: Command::MergePlaylists,
+                },
+                Keymap {
+                    key_sequence: "M-v".into(),
+                    command: Command::ArchivePlaylist,
+                },
                 Keymap {
                     key_sequence: "C-k".into(),
                     command: Command::MovePlaylistItemUp,
@@ -298,6 +509,10 @@ impl Default for KeymapConfig {
                     key_sequence: "C-j".into(),
                     command: Command::MovePlaylistItemDown,
                 },
+                Keymap {
+                    key_sequence: "x".into(),
+                    command: Command::RemoveFromQueue,
+                },
                 Keymap {
                     key_sequence: "N".into(),
                     command: Command::CreatePlaylist,
@@ -325,25 +540,39 @@ impl KeymapConfig {
                 );
             }
             Ok(content) => {
-                let mut keymaps = toml::from_str::<Self>(&content)?.keymaps;
-                std::mem::swap(&mut self.keymaps, &mut keymaps);
-                // a dumb approach (with quadratic complexity) to merge two different keymap arrays
-                // while keeping the invariant:
-                // - each `KeySequence` is mapped to only one `Command`.
-                keymaps.into_iter().for_each(|keymap| {
-                    if !self
-                        .keymaps
-                        .iter()
-                        .any(|k| k.key_sequence == keymap.key_sequence)
-                    {
-                        self.keymaps.push(keymap);
-                    }
-                });
+                let keymaps = toml::from_str::<Self>(&content)?.keymaps;
+                validate_keymaps(&keymaps)
+                    .with_context(|| format!("invalid keymap config file (path={file_path:?})"))?;
+                self.merge_keymaps(keymaps);
             }
         }
         Ok(())
     }
 
+    /// merges user-defined keymaps into the current keymaps while keeping the invariant:
+    /// - no key sequence is a prefix of another key sequence (including itself),
+    ///   so each key sequence is mapped to only one command and every keymap is reachable.
+    ///
+    /// A current keymap conflicting with a user-defined keymap is replaced by the latter.
+    fn merge_keymaps(&mut self, mut keymaps: Vec<Keymap>) {
+        self.keymaps.retain(|keymap| {
+            match keymaps.iter().find(|k| k.conflicts_with(keymap)) {
+                None => true,
+                Some(k) => {
+                    if k.key_sequence != keymap.key_sequence {
+                        tracing::warn!(
+                            "The keymap \"{keymap}\" is overridden by the conflicting keymap \"{k}\""
+                        );
+                    }
+                    false
+                }
+            }
+        });
+        // user-defined keymaps come first, so they are preferred when finding a command's key sequence
+        keymaps.append(&mut self.keymaps);
+        self.keymaps = keymaps;
+    }
+
     /// finds all keymaps whose mapped key sequence has a given `prefix` key sequence as its prefix
     pub fn find_matched_prefix_keymaps(&self, prefix: &KeySequence) -> Vec<&Keymap> {
         self.keymaps
@@ -352,6 +581,14 @@ impl KeymapConfig {
             .collect()
     }
 
+    /// finds the first key sequence mapped to a given command
+    pub fn find_key_sequence_from_command(&self, command: Command) -> Option<&KeySequence> {
+        self.keymaps
+            .iter()
+            .find(|&keymap| keymap.command == command)
+            .map(|keymap| &keymap.key_sequence)
+    }
+
     /// finds a command from a mapped key sequence
     pub fn find_command_from_key_sequence(&self, key_sequence: &KeySequence) -> Option<Command> {
         self.keymaps
@@ -361,10 +598,27 @@ impl KeymapConfig {
     }
 }
 
+/// validates a list of user-defined keymaps, which must not conflict with each other
+fn validate_keymaps(keymaps: &[Keymap]) -> Result<()> {
+    for (i, keymap) in keymaps.iter().enumerate() {
+        if let Some(k) = keymaps[i + 1..].iter().find(|k| k.conflicts_with(keymap)) {
+            anyhow::bail!("the keymap \"{keymap}\" conflicts with the keymap \"{k}\"");
+        }
+    }
+    Ok(())
+}
+
 impl Keymap {
     pub fn include_in_help_screen(&self) -> bool {
         !matches!(&self.command, Command::None)
     }
+
+    /// checks if two keymaps conflict, i.e. they map the same key sequence,
+    /// or one's key sequence is a prefix of the other's, which makes the latter unreachable
+    pub fn conflicts_with(&self, other: &Keymap) -> bool {
+        self.key_sequence.is_prefix(&other.key_sequence)
+            || other.key_sequence.is_prefix(&self.key_sequence)
+    }
 }
 
 impl std::fmt::Display for Keymap {
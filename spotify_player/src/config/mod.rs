@@ -14,7 +14,7 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use std::{
     path::{Path, PathBuf},
-    sync::OnceLock,
+    sync::{OnceLock, RwLock},
 };
 
 use keymap::*;
@@ -24,11 +24,15 @@ pub use theme::Theme;
 
 static CONFIGS: OnceLock<Configs> = OnceLock::new();
 
+/// the default device set while the application is running, overriding the configured one
+static DEFAULT_DEVICE: RwLock<Option<String>> = RwLock::new(None);
+
 #[derive(Debug)]
 pub struct Configs {
     pub app_config: AppConfig,
     pub keymap_config: KeymapConfig,
     pub theme_config: ThemeConfig,
+    pub config_folder: std::path::PathBuf,
     pub cache_folder: std::path::PathBuf,
 }
 
@@ -38,6 +42,7 @@ impl Configs {
             app_config: AppConfig::new(config_folder)?,
             keymap_config: KeymapConfig::new(config_folder)?,
             theme_config: ThemeConfig::new(config_folder)?,
+            config_folder: config_folder.to_path_buf(),
             cache_folder: cache_folder.to_path_buf(),
         })
     }
@@ -47,11 +52,22 @@ impl Configs {
 /// Application configurations
 pub struct AppConfig {
     pub theme: String,
+    pub locale: Locale,
     pub client_id: String,
 
     pub client_port: u16,
+    /// the port of the HTTP endpoint for guests' song requests, which is disabled if unset
+    pub guest_request_port: Option<u16>,
+    /// the address the guests' song requests endpoint is bound to
+    pub guest_request_bind_address: String,
+    /// the port of the HTTP endpoint exposing the application's metrics, which is disabled if unset
+    pub metrics_port: Option<u16>,
+    /// the address the metrics endpoint is bound to
+    pub metrics_bind_address: String,
 
     pub player_event_hook_command: Option<Command>,
+    /// the URL receiving a JSON `POST` request on every player event
+    pub player_event_webhook_url: Option<String>,
 
     pub playback_format: String,
     #[cfg(feature = "notify")]
@@ -61,6 +77,8 @@ pub struct AppConfig {
 
     pub tracks_playback_limit: usize,
 
+    pub market: Option<String>,
+
     // session configs
     pub proxy: Option<String>,
     pub ap_port: Option<u16>,
@@ -68,13 +86,50 @@ pub struct AppConfig {
     // duration configs
     pub app_refresh_duration_in_ms: u64,
     pub playback_refresh_duration_in_ms: u64,
+    pub playlist_refresh_interval_in_secs: u64,
 
     pub page_size_in_rows: usize,
 
+    pub pause_on_suspend: bool,
+    pub pause_on_audio_sink_change: bool,
+    pub inhibit_sleep_while_playing: bool,
+
+    pub previous_track_seek_threshold_in_secs: u64,
+    /// the duration (in seconds) to seek the playback by with `SeekForward` and `SeekBackward`
+    pub seek_duration_in_secs: u64,
+    /// the step (in percentage) to change the volume by with `VolumeUp` and `VolumeDown`
+    pub volume_step: u32,
+    pub album_auto_skip_threshold_in_secs: u64,
+
+    /// whether merging a playlist into another playlist skips the tracks already in the other playlist
+    pub deduplicate_merged_playlist_tracks: bool,
+    /// the names of the playlists (e.g. `Discover Weekly`) archived into new playlists every week
+    pub auto_archived_playlists: Vec<String>,
+
+    #[cfg(feature = "listening-log")]
+    pub listening_log_retention_in_days: u64,
+
+    /// the folder storing the user data shared between machines (bookmarks, search history
+    /// and context view states), which is stored in the cache folder if unset
+    pub sync_folder: Option<PathBuf>,
+
+    /// whether the track table's selection follows the playing track when it changes
+    pub follow_playing_track: bool,
+
+    /// whether the search popup fuzzy matches the items and ranks them by their match scores,
+    /// instead of matching the items containing any of the query's words
+    pub fuzzy_search: bool,
+
+    pub screen_reader_mode: bool,
+    /// whether the application runs in the read-only kiosk mode, which only allows
+    /// browsing the playback, the queue, and search results, and queueing tracks
+    pub kiosk_mode: bool,
+
     // icon configs
     pub play_icon: String,
     pub pause_icon: String,
     pub liked_icon: String,
+    pub queued_icon: String,
 
     // layout configs
     pub border_type: BorderType,
@@ -90,6 +145,8 @@ pub struct AppConfig {
     pub cover_img_scale: f32,
 
     pub playback_window_width: usize,
+    pub playback_next_up_count: usize,
+    pub window_resize_step: usize,
 
     #[cfg(feature = "media-control")]
     pub enable_media_control: bool,
@@ -103,13 +160,27 @@ pub struct AppConfig {
     pub enable_cover_image_cache: bool,
 
     pub default_device: String,
+    pub transfer_to_default_device_on_startup: bool,
 
     pub device: DeviceConfig,
 
+    pub window_sizes: WindowSizes,
+
+    pub playlist_shuffle: Vec<PlaylistShuffleConfig>,
+
     #[cfg(all(feature = "streaming", feature = "notify"))]
     pub notify_streaming_only: bool,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+/// A locale of the application's UI labels
+pub enum Locale {
+    En,
+    Es,
+    Fr,
+}
+config_parser_impl!(Locale);
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum Position {
     Top,
@@ -150,6 +221,54 @@ pub struct DeviceConfig {
     pub bitrate: u16,
     pub audio_cache: bool,
     pub normalization: bool,
+    pub gapless: bool,
+    /// the duration of the auto-mix mode's fade transition, in which the volume fades out at
+    /// the end of a track and back in at the start of the next one (the tracks don't overlap)
+    pub fade_duration_in_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+/// The sizes of the application's resizable windows, which are updated by the resize commands
+pub struct WindowSizes {
+    /// the playback window's height adjusted by the resize commands, which overrides
+    /// the `playback_window_width` config option and is stored as that option
+    #[serde(skip)]
+    pub playback_window: Option<usize>,
+    /// the width percentages of the library page's playlists, saved albums, and followed artists windows
+    pub library_windows: [u16; 3],
+    /// the height of the artist page's top tracks window
+    pub artist_top_tracks_window: u16,
+    /// the width percentage of the artist page's albums window.
+    /// The related artists window takes the remaining width.
+    pub artist_albums_window: u16,
+    /// the width percentage of the search page's left (tracks and artists) windows
+    pub search_left_windows: u16,
+    /// the height percentage of the search page's top (tracks and albums) windows
+    pub search_top_windows: u16,
+}
+
+impl Default for WindowSizes {
+    fn default() -> Self {
+        Self {
+            playback_window: None,
+            library_windows: [40, 40, 20],
+            artist_top_tracks_window: 12,
+            artist_albums_window: 50,
+            search_left_windows: 50,
+            search_top_windows: 50,
+        }
+    }
+}
+
+config_parser_impl!(WindowSizes);
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+/// The default shuffle state of a playlist, applied when starting a playback from the playlist
+pub struct PlaylistShuffleConfig {
+    /// the playlist's ID or URI
+    pub playlist: String,
+    pub shuffle: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, ConfigParse, Clone)]
@@ -212,13 +331,20 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             theme: "dracula".to_owned(),
+            locale: Locale::En,
             // official Spotify web app's client id
             client_id: "65b708073fc0480ea92a077233ca87bd".to_string(),
 
             client_port: 8080,
+            guest_request_port: None,
+            guest_request_bind_address: "127.0.0.1".to_string(),
+            metrics_port: None,
+            metrics_bind_address: "127.0.0.1".to_string(),
 
             tracks_playback_limit: 50,
 
+            market: None,
+
             playback_format: String::from("{track} • {artists}\n{album}\n{metadata}"),
             #[cfg(feature = "notify")]
             notify_format: NotifyFormat {
@@ -229,17 +355,42 @@ impl Default for AppConfig {
             notify_timeout_in_secs: 0,
 
             player_event_hook_command: None,
+            player_event_webhook_url: None,
 
             proxy: None,
             ap_port: None,
             app_refresh_duration_in_ms: 32,
             playback_refresh_duration_in_ms: 0,
+            playlist_refresh_interval_in_secs: 60,
 
             page_size_in_rows: 20,
 
+            pause_on_suspend: false,
+            pause_on_audio_sink_change: false,
+            inhibit_sleep_while_playing: false,
+
+            previous_track_seek_threshold_in_secs: 3,
+            seek_duration_in_secs: 5,
+            volume_step: 5,
+            album_auto_skip_threshold_in_secs: 0,
+
+            deduplicate_merged_playlist_tracks: true,
+            auto_archived_playlists: vec![],
+
+            #[cfg(feature = "listening-log")]
+            listening_log_retention_in_days: 90,
+
+            sync_folder: None,
+
+            follow_playing_track: false,
+            fuzzy_search: false,
+            screen_reader_mode: false,
+            kiosk_mode: false,
+
             pause_icon: "▌▌".to_string(),
             play_icon: "▶".to_string(),
             liked_icon: "♥".to_string(),
+            queued_icon: "+".to_string(),
 
             border_type: BorderType::Plain,
             progress_bar_type: ProgressBarType::Rectangle,
@@ -254,6 +405,8 @@ impl Default for AppConfig {
             cover_img_scale: 1.0,
 
             playback_window_width: 6,
+            playback_next_up_count: 2,
+            window_resize_step: 2,
 
             // Because of the "creating new window and stealing focus" behaviour
             // when running the media control event loop on startup,
@@ -275,9 +428,14 @@ impl Default for AppConfig {
             enable_cover_image_cache: true,
 
             default_device: "spotify-player".to_string(),
+            transfer_to_default_device_on_startup: false,
 
             device: DeviceConfig::default(),
 
+            window_sizes: WindowSizes::default(),
+
+            playlist_shuffle: vec![],
+
             #[cfg(all(feature = "streaming", feature = "notify"))]
             notify_streaming_only: false,
         }
@@ -293,6 +451,8 @@ impl Default for DeviceConfig {
             bitrate: 320,
             audio_cache: false,
             normalization: false,
+            gapless: true,
+            fade_duration_in_secs: 5,
         }
     }
 }
@@ -304,6 +464,15 @@ impl AppConfig {
             config.write_config_file(path)?
         }
 
+        if config.screen_reader_mode {
+            // replace decorative glyphs with plain words that can be read by screen readers
+            config.play_icon = "playing".to_string();
+            config.pause_icon = "paused".to_string();
+            config.liked_icon = "liked".to_string();
+            config.queued_icon = "queued".to_string();
+            config.border_type = BorderType::Hidden;
+        }
+
         Ok(config)
     }
 
@@ -329,6 +498,71 @@ impl AppConfig {
             })
     }
 
+    /// updates an option in the application config file in `path` folder,
+    /// other options, comments and formatting of the file are kept unchanged.
+    pub fn update_config_file_option(
+        path: &Path,
+        key: &str,
+        value: impl Into<toml_edit::Item>,
+    ) -> Result<()> {
+        let file_path = path.join(APP_CONFIG_FILE);
+        let mut doc: toml_edit::DocumentMut = match std::fs::read_to_string(&file_path) {
+            Ok(content) => std::str::FromStr::from_str(&content)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                toml_edit::DocumentMut::new()
+            }
+            Err(error) => return Err(error.into()),
+        };
+        doc[key] = value.into();
+        std::fs::write(file_path, doc.to_string())?;
+        Ok(())
+    }
+
+    /// gets the default device, which is either the configured `default_device`
+    /// or the device set by `set_default_device` while the application is running
+    pub fn default_device(&self) -> String {
+        DEFAULT_DEVICE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .unwrap_or_else(|| self.default_device.clone())
+    }
+
+    /// sets the default device of the running application and in the config file in `path` folder
+    pub fn set_default_device(path: &Path, name: String) -> Result<()> {
+        Self::update_config_file_option(path, "default_device", toml_edit::value(name.as_str()))?;
+        *DEFAULT_DEVICE.write().unwrap_or_else(|e| e.into_inner()) = Some(name);
+        Ok(())
+    }
+
+    /// gets the market (country) configured for Spotify API requests
+    pub fn market(&self) -> Option<rspotify::model::Country> {
+        parse_market(self.market.as_ref()?).ok()
+    }
+
+    /// validates the configured market, which is unset if it's invalid
+    /// so that the market associated with the user's access token is used instead
+    pub fn validate_market(&mut self) -> Result<()> {
+        if let Some(market) = self.market.as_ref() {
+            if let Err(err) = parse_market(market) {
+                let err = anyhow!("invalid market {market}: {err:#}");
+                self.market = None;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the configured default shuffle state of a playlist (if any)
+    pub fn playlist_shuffle(&self, id: &rspotify::model::PlaylistId) -> Option<bool> {
+        use rspotify::prelude::Id;
+
+        self.playlist_shuffle
+            .iter()
+            .find(|c| c.playlist == id.id() || c.playlist == id.uri())
+            .map(|c| c.shuffle)
+    }
+
     pub fn session_config(&self) -> SessionConfig {
         let proxy = self
             .proxy
@@ -356,6 +590,13 @@ pub fn get_config_folder_path() -> Result<PathBuf> {
     }
 }
 
+/// parses a market (country code), e.g. `US`
+fn parse_market(market: &str) -> Result<rspotify::model::Country> {
+    Ok(serde_json::from_value(serde_json::Value::String(
+        market.to_uppercase(),
+    ))?)
+}
+
 /// gets the application's cache folder path
 pub fn get_cache_folder_path() -> Result<PathBuf> {
     match dirs_next::home_dir() {
@@ -74,6 +74,7 @@ pub struct ComponentStyle {
     pub playlist_desc: Option<Style>,
     pub table_header: Option<Style>,
     pub selection: Option<Style>,
+    pub search_match: Option<Style>,
 }
 
 #[derive(Default, Clone, Debug, Deserialize)]
@@ -284,6 +285,16 @@ impl Theme {
             Some(s) => s.style(&self.palette),
         }
     }
+
+    pub fn search_match(&self) -> tui::style::Style {
+        match &self.component_style.search_match {
+            None => Style::default()
+                .fg(StyleColor::Yellow)
+                .modifiers(vec![StyleModifier::Bold])
+                .style(&self.palette),
+            Some(s) => s.style(&self.palette),
+        }
+    }
 }
 
 impl Style {
@@ -482,7 +493,11 @@ impl From<style::Color> for Color {
 impl Default for ThemeConfig {
     fn default() -> Self {
         Self {
-            themes: vec![Theme::default()],
+            themes: vec![
+                Theme::default(),
+                Theme::high_contrast(),
+                Theme::colorblind(),
+            ],
         }
     }
 }
@@ -497,6 +512,127 @@ impl Default for Theme {
     }
 }
 
+impl Theme {
+    /// a built-in theme with pure black and white colors and non-color cues
+    /// (bold, underlined) for low-vision users
+    fn high_contrast() -> Self {
+        let bold = || vec![StyleModifier::Bold];
+        let bold_underlined = || vec![StyleModifier::Bold, StyleModifier::Underlined];
+
+        Self {
+            name: "high_contrast".to_owned(),
+            palette: Palette {
+                background: Some(style::Color::Rgb(0, 0, 0).into()),
+                foreground: Some(style::Color::Rgb(255, 255, 255).into()),
+                black: style::Color::Rgb(0, 0, 0).into(),
+                white: style::Color::Rgb(255, 255, 255).into(),
+                bright_white: style::Color::Rgb(255, 255, 255).into(),
+                bright_yellow: style::Color::Rgb(255, 255, 0).into(),
+                bright_cyan: style::Color::Rgb(0, 255, 255).into(),
+                bright_green: style::Color::Rgb(0, 255, 0).into(),
+                ..Palette::default()
+            },
+            component_style: ComponentStyle {
+                block_title: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightWhite)
+                        .modifiers(bold()),
+                ),
+                border: Some(Style::default().fg(StyleColor::White)),
+                playback_track: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightYellow)
+                        .modifiers(bold()),
+                ),
+                playback_artists: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightCyan)
+                        .modifiers(bold()),
+                ),
+                playback_album: Some(Style::default().fg(StyleColor::BrightYellow)),
+                playback_metadata: Some(Style::default().fg(StyleColor::BrightWhite)),
+                playback_progress_bar: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightYellow)
+                        .bg(StyleColor::Black),
+                ),
+                current_playing: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightGreen)
+                        .modifiers(bold_underlined()),
+                ),
+                page_desc: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightCyan)
+                        .modifiers(bold()),
+                ),
+                playlist_desc: Some(Style::default().fg(StyleColor::BrightWhite)),
+                table_header: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightWhite)
+                        .modifiers(bold_underlined()),
+                ),
+                selection: Some(
+                    Style::default()
+                        .fg(StyleColor::Black)
+                        .bg(StyleColor::BrightYellow)
+                        .modifiers(bold()),
+                ),
+                search_match: Some(
+                    Style::default()
+                        .fg(StyleColor::BrightCyan)
+                        .modifiers(bold_underlined()),
+                ),
+            },
+        }
+    }
+
+    /// a built-in theme using the colorblind-safe Okabe-Ito palette, which
+    /// never relies on a red/green distinction
+    fn colorblind() -> Self {
+        let bold = || vec![StyleModifier::Bold];
+
+        Self {
+            name: "colorblind".to_owned(),
+            palette: Palette {
+                black: style::Color::Rgb(0, 0, 0).into(),
+                // vermillion
+                red: style::Color::Rgb(213, 94, 0).into(),
+                // bluish green
+                green: style::Color::Rgb(0, 158, 115).into(),
+                yellow: style::Color::Rgb(240, 228, 66).into(),
+                blue: style::Color::Rgb(0, 114, 178).into(),
+                // reddish purple
+                magenta: style::Color::Rgb(204, 121, 167).into(),
+                // sky blue
+                cyan: style::Color::Rgb(86, 180, 233).into(),
+                // orange
+                bright_yellow: style::Color::Rgb(230, 159, 0).into(),
+                ..Palette::default()
+            },
+            component_style: ComponentStyle {
+                block_title: Some(Style::default().fg(StyleColor::Cyan).modifiers(bold())),
+                playback_track: Some(Style::default().fg(StyleColor::Cyan).modifiers(bold())),
+                playback_artists: Some(Style::default().fg(StyleColor::Cyan).modifiers(bold())),
+                playback_album: Some(Style::default().fg(StyleColor::BrightYellow)),
+                playback_progress_bar: Some(
+                    Style::default()
+                        .fg(StyleColor::Blue)
+                        .bg(StyleColor::BrightBlack),
+                ),
+                current_playing: Some(
+                    Style::default()
+                        .fg(StyleColor::Yellow)
+                        .modifiers(vec![StyleModifier::Bold, StyleModifier::Underlined]),
+                ),
+                page_desc: Some(Style::default().fg(StyleColor::Cyan).modifiers(bold())),
+                table_header: Some(Style::default().fg(StyleColor::Blue).modifiers(bold())),
+                ..ComponentStyle::default()
+            },
+        }
+    }
+}
+
 impl Default for Palette {
     fn default() -> Self {
         Self {
@@ -1,11 +1,12 @@
 use crate::{
-    client::{ClientRequest, PlayerRequest},
+    client::{ClientRequest, LibraryShuffleSource, PlayerRequest, Secret},
     command::{self, Command},
     config,
+    i18n::tr,
     key::{Key, KeySequence},
     state::*,
-    ui::single_line_input::LineInput,
-    utils::new_list_state,
+    ui::single_line_input::{InputEffect, LineInput},
+    utils::{new_list_state, new_table_state},
 };
 
 #[cfg(feature = "lyric-finder")]
@@ -19,12 +20,16 @@ mod page;
 mod popup;
 mod window;
 
+/// the maximum number of tracks listed in the most skipped tracks popup
+const MOST_SKIPPED_TRACKS_LIMIT: usize = 20;
+
 /// Start a terminal event handler (key pressed, mouse clicked, etc)
 pub fn start_event_handler(state: SharedState, client_pub: flume::Sender<ClientRequest>) {
     while let Ok(event) = crossterm::event::read() {
         let _enter = tracing::info_span!("terminal_event", event = ?event).entered();
         if let Err(err) = match event {
             crossterm::event::Event::Mouse(event) => handle_mouse_event(event, &client_pub, &state),
+            crossterm::event::Event::Paste(text) => handle_paste_event(&text, &state),
             crossterm::event::Event::Key(event) => {
                 if event.kind == crossterm::event::KeyEventKind::Press {
                     // only handle key press event to avoid handling a key event multiple times
@@ -49,25 +54,166 @@ fn handle_mouse_event(
     client_pub: &flume::Sender<ClientRequest>,
     state: &SharedState,
 ) -> Result<()> {
-    // a left click event
-    if let crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left) = event.kind
+    use crossterm::event::{MouseButton, MouseEventKind};
+
+    let command = match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => None,
+        MouseEventKind::ScrollDown => Some(Command::SelectNextOrScrollDown),
+        MouseEventKind::ScrollUp => Some(Command::SelectPreviousOrScrollUp),
+        _ => return Ok(()),
+    };
+    tracing::debug!("Handling mouse event: {event:?}");
+    let mut ui = state.ui.lock();
+
+    let rect = ui.playback_progress_bar_rect;
+    // seeking the playback is disabled in the kiosk mode, and clicks to the right of
+    // the progress bar (e.g. on the volume gauge) are ignored
+    if command.is_none()
+        && event.row == rect.y
+        && event.column < rect.x + rect.width
+        && !config::get_config().app_config.kiosk_mode
     {
-        tracing::debug!("Handling mouse event: {event:?}");
-        let rect = state.ui.lock().playback_progress_bar_rect;
-        if event.row == rect.y {
-            // calculate the seek position (in ms) based on the mouse click position,
-            // the progress bar's width and the track's duration (in ms)
-            let duration = state
-                .player
-                .read()
-                .current_playing_track()
-                .map(|t| t.duration);
-            if let Some(duration) = duration {
-                let position_ms =
-                    (duration.num_milliseconds()) * (event.column as i64) / (rect.width as i64);
-                client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
-                    chrono::Duration::try_milliseconds(position_ms).unwrap(),
-                )))?;
+        // calculate the seek position (in ms) based on the mouse click position,
+        // the progress bar's width and the track's duration (in ms)
+        let duration = state
+            .player
+            .read()
+            .current_playing_track()
+            .map(|t| t.duration);
+        if let Some(duration) = duration {
+            let position_ms =
+                (duration.num_milliseconds()) * (event.column as i64) / (rect.width as i64);
+            client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
+                chrono::Duration::try_milliseconds(position_ms).unwrap(),
+            )))?;
+        }
+        return Ok(());
+    }
+
+    // other mouse events are only handled on the items of the focused window
+    let area = match ui.focused_window_area {
+        Some(ref area)
+            if (area.rect.x..area.rect.x + area.rect.width).contains(&event.column)
+                && (area.rect.y..area.rect.y + area.rect.height).contains(&event.row) =>
+        {
+            area.clone()
+        }
+        _ => return Ok(()),
+    };
+
+    let command = match command {
+        // scrolling moves the focused window's selection
+        Some(command) => command,
+        // clicking an item selects the item, and double clicking the item chooses it
+        None => {
+            let row = usize::from(event.row - area.rect.y);
+            if !select_focused_window_row(&mut ui, row, &area) {
+                return Ok(());
+            }
+            let now = std::time::Instant::now();
+            let is_double_click = ui.last_mouse_click.is_some_and(|(time, column, row)| {
+                now.duration_since(time) < DOUBLE_CLICK_DURATION
+                    && (column, row) == (event.column, event.row)
+            });
+            if !is_double_click {
+                ui.last_mouse_click = Some((now, event.column, event.row));
+                return Ok(());
+            }
+            ui.last_mouse_click = None;
+            Command::ChooseSelected
+        }
+    };
+
+    // a mouse command is handled like the key sequence of the command's shortcut
+    if let Some(key_sequence) = config::get_config()
+        .keymap_config
+        .find_key_sequence_from_command(command)
+    {
+        handle_key_sequence(key_sequence.clone(), client_pub, state, &mut ui)?;
+    }
+    Ok(())
+}
+
+/// Get the (mutable) state of the focused window, which is either the list popup's window
+/// (if a popup is shown) or the current page's focused window
+fn focused_window_state<'a>(ui: &'a mut UIStateGuard) -> Option<MutableWindowState<'a>> {
+    if ui.popup.is_some() {
+        ui.popup
+            .as_mut()
+            .and_then(|p| p.list_state_mut())
+            .map(MutableWindowState::List)
+    } else {
+        ui.current_page_mut().focus_window_state_mut()
+    }
+}
+
+/// Select the item at the given row of the focused window's area,
+/// returning whether an item is selected
+fn select_focused_window_row(ui: &mut UIStateGuard, row: usize, area: &FocusedWindowArea) -> bool {
+    let row = row
+        + match focused_window_state(ui) {
+            Some(MutableWindowState::List(state)) => state.offset(),
+            Some(MutableWindowState::Table(state)) => state.offset(),
+            _ => return false,
+        };
+    let id = match area.item_rows {
+        Some(ref item_rows) => match item_rows.iter().position(|r| *r == row) {
+            Some(id) => id,
+            None => return false,
+        },
+        None => row,
+    };
+    if id >= area.n_items {
+        return false;
+    }
+    match focused_window_state(ui) {
+        Some(MutableWindowState::List(state)) => state.select(Some(id)),
+        Some(MutableWindowState::Table(state)) => state.select(Some(id)),
+        _ => return false,
+    }
+    ui.track_selection_moved_at = Some(std::time::Instant::now());
+    true
+}
+
+// Handle a terminal paste event by inserting the pasted text into the focused input (if any)
+fn handle_paste_event(text: &str, state: &SharedState) -> Result<()> {
+    let mut ui = state.ui.lock();
+    match ui.popup {
+        Some(PopupState::Search { ref mut query }) => {
+            query.insert_str(text);
+            ui.current_page_mut().select(0);
+        }
+        Some(PopupState::PlaylistCreate {
+            ref mut name,
+            ref mut desc,
+            current_field,
+        }) => match current_field {
+            PlaylistCreateCurrentField::Name => name.insert_str(text),
+            PlaylistCreateCurrentField::Desc => desc.insert_str(text),
+        },
+        Some(PopupState::BookmarkCreate { ref mut name, .. }) => name.insert_str(text),
+        Some(PopupState::TrackYearFilter { ref mut years }) => years.insert_str(text),
+        Some(PopupState::TrackTempoFilter { ref mut tempos }) => tempos.insert_str(text),
+        Some(PopupState::SeekPosition { ref mut position }) => position.insert_str(text),
+        Some(PopupState::TrackNumber { ref mut number }) => number.insert_str(text),
+        Some(PopupState::Authenticate {
+            ref mut username,
+            ref mut password,
+            current_field,
+        }) => match current_field {
+            AuthenticateCurrentField::Username => username.insert_str(text),
+            AuthenticateCurrentField::Password => password.insert_str(text),
+        },
+        Some(PopupState::PartyModePassphrase { ref mut passphrase }) => passphrase.insert_str(text),
+        Some(_) => {}
+        None => {
+            if let PageState::Search {
+                state, line_input, ..
+            } = ui.current_page_mut()
+            {
+                if state.focus == SearchFocusState::Input {
+                    line_input.insert_str(text);
+                }
             }
         }
     }
@@ -97,18 +243,32 @@ fn handle_key_event(
     }
 
     tracing::debug!("Handling key event: {event:?}, current key sequence: {key_sequence:?}");
+    handle_key_sequence(key_sequence, client_pub, state, &mut ui)
+}
+
+/// Handle a key sequence, which is cleared if handled. Otherwise, the key sequence
+/// is kept as it can be a prefix of a command's shortcut.
+fn handle_key_sequence(
+    key_sequence: KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    let keymap_config = &config::get_config().keymap_config;
     let handled = {
-        if ui.popup.is_none() {
-            page::handle_key_sequence_for_page(&key_sequence, client_pub, state, &mut ui)?
+        if ui.popup.is_none() && ui.is_playback_window_focused {
+            handle_key_sequence_for_playback_window(&key_sequence, client_pub, state, ui)?
+        } else if ui.popup.is_none() {
+            page::handle_key_sequence_for_page(&key_sequence, client_pub, state, ui)?
         } else {
-            popup::handle_key_sequence_for_popup(&key_sequence, client_pub, state, &mut ui)?
+            popup::handle_key_sequence_for_popup(&key_sequence, client_pub, state, ui)?
         }
     };
 
     // if the key sequence is not handled, let the global command handler handle it
     let handled = if !handled {
         match keymap_config.find_command_from_key_sequence(&key_sequence) {
-            Some(command) => handle_global_command(command, client_pub, state, &mut ui)?,
+            Some(command) => handle_global_command(command, client_pub, state, ui)?,
             None => false,
         }
     } else {
@@ -125,6 +285,51 @@ fn handle_key_event(
     Ok(())
 }
 
+/// Open a new search page with results of the given query
+fn new_search_page_with_query(
+    ui: &mut UIStateGuard,
+    client_pub: &flume::Sender<ClientRequest>,
+    query: String,
+) -> Result<()> {
+    let mut line_input = LineInput::with_history(ui.search_history.clone());
+    line_input.insert_str(&query);
+    line_input.submit();
+    ui.set_search_history(line_input.history());
+
+    client_pub.send(ClientRequest::Search(query.clone()))?;
+    ui.new_page(PageState::Search {
+        line_input,
+        current_query: query,
+        state: SearchPageUIState {
+            focus: SearchFocusState::Tracks,
+            ..SearchPageUIState::new()
+        },
+    });
+    Ok(())
+}
+
+/// Handle a key sequence when the playback window is focused.
+///
+/// The list/table navigation commands are mapped to playback commands:
+/// choosing toggles the playback, selecting up/down changes the volume.
+fn handle_key_sequence_for_playback_window(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let command = match config::get_config()
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(Command::ChooseSelected) => Command::ResumePause,
+        Some(Command::SelectPreviousOrScrollUp) => Command::VolumeUp,
+        Some(Command::SelectNextOrScrollDown) => Command::VolumeDown,
+        _ => return Ok(false),
+    };
+    handle_global_command(command, client_pub, state, ui)
+}
+
 /// Handle a global command that is not specific to any page/popup
 fn handle_global_command(
     command: Command,
@@ -132,15 +337,48 @@ fn handle_global_command(
     state: &SharedState,
     ui: &mut UIStateGuard,
 ) -> Result<bool> {
+    if command.is_disabled_in_party_mode() && ui.reject_in_party_mode() {
+        return Ok(true);
+    }
+    if !command.is_allowed_in_kiosk_mode() && ui.reject_in_kiosk_mode() {
+        return Ok(true);
+    }
+
     match command {
         Command::Quit => {
             ui.is_running = false;
         }
+        #[cfg(unix)]
+        Command::Suspend => {
+            suspend_application(client_pub, state, ui)?;
+        }
         Command::NextTrack => {
             client_pub.send(ClientRequest::Player(PlayerRequest::NextTrack))?;
         }
+        Command::NextChapter => {
+            client_pub.send(ClientRequest::PlayNextChapter)?;
+        }
         Command::PreviousTrack => {
-            client_pub.send(ClientRequest::Player(PlayerRequest::PreviousTrack))?;
+            let threshold = chrono::Duration::try_seconds(
+                config::get_config()
+                    .app_config
+                    .previous_track_seek_threshold_in_secs as i64,
+            )
+            .unwrap_or_default();
+            let progress = state.player.read().playback_progress();
+
+            // restart the current track instead of going to the previous track
+            // if the track has been played for long enough
+            match progress {
+                Some(progress) if threshold > chrono::Duration::zero() && progress > threshold => {
+                    client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
+                        chrono::Duration::zero(),
+                    )))?;
+                }
+                _ => {
+                    client_pub.send(ClientRequest::Player(PlayerRequest::PreviousTrack))?;
+                }
+            }
         }
         Command::ResumePause => {
             client_pub.send(ClientRequest::Player(PlayerRequest::ResumePause))?;
@@ -160,39 +398,104 @@ fn handle_global_command(
         Command::VolumeUp => {
             if let Some(ref playback) = state.player.read().buffered_playback {
                 if let Some(volume) = playback.volume {
-                    let volume = std::cmp::min(volume + 5, 100_u32);
+                    let volume = std::cmp::min(
+                        volume + config::get_config().app_config.volume_step,
+                        100_u32,
+                    );
                     client_pub.send(ClientRequest::Player(PlayerRequest::Volume(volume as u8)))?;
                 }
             }
+            ui.show_volume_overlay();
         }
         Command::VolumeDown => {
             if let Some(ref playback) = state.player.read().buffered_playback {
                 if let Some(volume) = playback.volume {
-                    let volume = volume.saturating_sub(5_u32);
+                    let volume = volume.saturating_sub(config::get_config().app_config.volume_step);
                     client_pub.send(ClientRequest::Player(PlayerRequest::Volume(volume as u8)))?;
                 }
             }
+            ui.show_volume_overlay();
         }
         Command::Mute => {
             client_pub.send(ClientRequest::Player(PlayerRequest::ToggleMute))?;
+            ui.show_volume_overlay();
         }
         Command::SeekForward => {
             if let Some(progress) = state.player.read().playback_progress() {
                 client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
-                    progress + chrono::Duration::try_seconds(5).unwrap(),
+                    progress + seek_duration(),
                 )))?;
             }
         }
         Command::SeekBackward => {
             if let Some(progress) = state.player.read().playback_progress() {
                 client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
-                    std::cmp::max(
-                        chrono::Duration::zero(),
-                        progress - chrono::Duration::try_seconds(5).unwrap(),
-                    ),
+                    std::cmp::max(chrono::Duration::zero(), progress - seek_duration()),
                 )))?;
             }
         }
+        Command::SeekToPosition => {
+            ui.popup = Some(PopupState::SeekPosition {
+                position: LineInput::default(),
+            });
+        }
+        Command::LikeCurrentTrack => {
+            let track = state
+                .player
+                .read()
+                .current_playing_track()
+                .and_then(|track| Track::try_from_full_track(track.clone()));
+            if let Some(track) = track {
+                let mut data = state.data.write();
+                data.user_data.set_blocked_track(&track.id, false);
+                if !data.user_data.is_liked_track(&track) {
+                    ui.show_toast(format!("{} {}", tr("Liked"), track.name));
+                    client_pub.send(ClientRequest::AddToLibrary(Item::Track(track)))?;
+                }
+            }
+        }
+        Command::UnlikeCurrentTrack => {
+            let track = state
+                .player
+                .read()
+                .current_playing_track()
+                .and_then(|track| Track::try_from_full_track(track.clone()));
+            if let Some(track) = track {
+                if state.data.read().user_data.is_liked_track(&track) {
+                    ui.show_toast(format!("{} {}", tr("Unliked"), track.name));
+                    client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Track(track.id)))?;
+                }
+            }
+        }
+        Command::DislikeAndSkipCurrentTrack => {
+            let (track, is_playing) = {
+                let player = state.player.read();
+                (
+                    player
+                        .current_playing_track()
+                        .and_then(|track| Track::try_from_full_track(track.clone())),
+                    player
+                        .buffered_playback
+                        .as_ref()
+                        .is_some_and(|p| p.is_playing),
+                )
+            };
+            if let Some(track) = track {
+                let mut data = state.data.write();
+                data.user_data.set_blocked_track(&track.id, true);
+                if data.user_data.is_liked_track(&track) {
+                    client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Track(
+                        track.id.clone(),
+                    )))?;
+                }
+                // a playing blocked track is skipped by the playback watcher,
+                // so the track only needs to be skipped here if the playback is paused
+                if !is_playing {
+                    client_pub.send(ClientRequest::Player(PlayerRequest::NextTrack))?;
+                }
+                ui.show_toast(format!("{} {}", tr("Disliked"), track.name));
+            }
+        }
         Command::OpenCommandHelp => {
             ui.new_page(PageState::CommandHelp { scroll_offset: 0 });
         }
@@ -244,14 +547,30 @@ fn handle_global_command(
             client_pub.send(ClientRequest::GetUserTopTracks)?;
         }
         Command::RecentlyPlayedTrackPage => {
+            ui.new_page(PageState::RecentlyPlayed {
+                track_table: new_table_state(),
+            });
+            client_pub.send(ClientRequest::GetUserRecentlyPlayedTracks)?;
+        }
+        Command::RecentlyAddedTrackPage => {
             ui.new_page(PageState::Context {
                 id: None,
                 context_page_type: ContextPageType::Browsing(ContextId::Tracks(
-                    USER_RECENTLY_PLAYED_TRACKS_ID.to_owned(),
+                    USER_RECENTLY_ADDED_TRACKS_ID.to_owned(),
                 )),
                 state: None,
             });
-            client_pub.send(ClientRequest::GetUserRecentlyPlayedTracks)?;
+            client_pub.send(ClientRequest::GetUserRecentlyAddedTracks)?;
+        }
+        Command::UnsortedLikedTrackPage => {
+            ui.new_page(PageState::Context {
+                id: None,
+                context_page_type: ContextPageType::Browsing(ContextId::Tracks(
+                    USER_UNSORTED_LIKED_TRACKS_ID.to_owned(),
+                )),
+                state: None,
+            });
+            client_pub.send(ClientRequest::GetUserUnsortedLikedTracks)?;
         }
         Command::LikedTrackPage => {
             ui.new_page(PageState::Context {
@@ -268,9 +587,39 @@ fn handle_global_command(
                 state: LibraryPageUIState::new(),
             });
         }
+        Command::SavedEpisodePage => {
+            let mut state = LibraryPageUIState::new();
+            state.focus = LibraryFocusState::SavedEpisodes;
+            ui.new_page(PageState::Library { state });
+            client_pub.send(ClientRequest::GetUserSavedEpisodes)?;
+        }
+        Command::AudiobookChapterPage => match state.player.read().current_playing_episode() {
+            Some(episode) => {
+                let audiobook_id = episode.show.id.id().to_string();
+                ui.new_page(PageState::Chapters {
+                    audiobook_id: audiobook_id.clone(),
+                    audiobook_name: episode.show.name.clone(),
+                    chapter_table: new_table_state(),
+                });
+                client_pub.send(ClientRequest::GetAudiobookChapters(audiobook_id))?;
+            }
+            None => ui.show_toast(tr("No audiobook is playing")),
+        },
+        Command::ShowPage => match state.player.read().current_playing_episode() {
+            Some(episode) => {
+                ui.new_page(PageState::Show {
+                    show_id: episode.show.id.clone(),
+                    show_name: episode.show.name.clone(),
+                    episode_table: new_table_state(),
+                });
+                client_pub.send(ClientRequest::GetShowEpisodes(episode.show.id.clone()))?;
+            }
+            None => ui.show_toast(tr("No episode is playing")),
+        },
         Command::SearchPage => {
+            let line_input = LineInput::with_history(ui.search_history.clone());
             ui.new_page(PageState::Search {
-                line_input: LineInput::default(),
+                line_input,
                 current_query: String::new(),
                 state: SearchPageUIState::new(),
             });
@@ -283,10 +632,60 @@ fn handle_global_command(
             });
             client_pub.send(ClientRequest::GetBrowseCategories)?;
         }
+        Command::NewReleasesPage => {
+            ui.new_page(PageState::Browse {
+                state: BrowsePageUIState::NewReleases {
+                    state: new_list_state(),
+                },
+            });
+            client_pub.send(ClientRequest::GetNewReleases)?;
+        }
+        Command::FeaturedPlaylistsPage => {
+            ui.new_page(PageState::Browse {
+                state: BrowsePageUIState::FeaturedPlaylists {
+                    state: new_list_state(),
+                },
+            });
+            client_pub.send(ClientRequest::GetFeaturedPlaylists)?;
+        }
         Command::PreviousPage => {
             if ui.history.len() > 1 {
                 ui.history.pop();
                 ui.popup = None;
+                ui.is_playback_window_focused = false;
+                ui.visual_selection_anchor = None;
+            }
+        }
+        Command::SwapContext => {
+            let current_id = match ui.current_page() {
+                PageState::Context { id, .. } => id.clone(),
+                _ => None,
+            };
+            // the most recently shown context page other than the current one
+            let index = ui.history.iter().rposition(|p| {
+                matches!(p, PageState::Context { id: Some(id), .. } if Some(id) != current_id.as_ref())
+            });
+            match index {
+                Some(index) => {
+                    // keep both contexts loaded, so swapping between them doesn't re-fetch either
+                    let mut data = state.data.write();
+                    if let PageState::Context { id: Some(id), .. } = &ui.history[index] {
+                        data.keep_context_loaded(&id.uri());
+                    }
+                    match current_id {
+                        // swap the context pages in place, so swapping back and forth
+                        // doesn't grow the history
+                        Some(id) => {
+                            data.keep_context_loaded(&id.uri());
+                            ui.swap_page(index);
+                        }
+                        None => {
+                            let page = ui.history[index].clone();
+                            ui.new_page(page);
+                        }
+                    }
+                }
+                None => ui.show_toast(tr("No other context to swap to")),
             }
         }
         Command::OpenSpotifyLinkFromClipboard => {
@@ -354,9 +753,13 @@ fn handle_global_command(
             }
         }
         Command::SwitchDevice => {
-            ui.popup = Some(PopupState::DeviceList(new_list_state()));
+            // the device list popup is opened once the devices are refreshed
             client_pub.send(ClientRequest::GetDevices)?;
         }
+        // the command is handled by the device list popup
+        Command::SetDefaultDevice => {
+            ui.show_toast(tr("Open the device list to set the default device"));
+        }
         Command::SwitchTheme => {
             // get the available themes with the current theme moved to the first position
             let mut themes = config::get_config().theme_config.themes.clone();
@@ -372,18 +775,61 @@ fn handle_global_command(
         Command::RestartIntegratedClient => {
             client_pub.send(ClientRequest::RestartIntegratedClient)?;
         }
+        #[cfg(feature = "streaming")]
+        Command::TakeOverPlayback => {
+            client_pub.send(ClientRequest::TakeOverPlayback)?;
+        }
+        #[cfg(feature = "streaming")]
+        Command::ToggleAutoMix => {
+            let mut player = state.player.write();
+            if player.is_auto_mix_enabled {
+                player.is_auto_mix_enabled = false;
+                ui.show_toast(tr("Auto-mix disabled").to_string());
+            } else {
+                client_pub.send(ClientRequest::StartAutoMix)?;
+            }
+        }
         Command::FocusNextWindow => {
             if !ui.has_focused_popup() {
-                ui.current_page_mut().next()
+                ui.focus_next_window()
             }
         }
         Command::FocusPreviousWindow => {
             if !ui.has_focused_popup() {
-                ui.current_page_mut().previous()
+                ui.focus_previous_window()
             }
         }
+        Command::GrowFocusedWindow | Command::ShrinkFocusedWindow => {
+            let step = config::get_config().app_config.window_resize_step as i32;
+            ui.resize_focused_window(if command == Command::GrowFocusedWindow {
+                step
+            } else {
+                -step
+            });
+        }
+        Command::GrowPlaybackWindow | Command::ShrinkPlaybackWindow => {
+            let step = config::get_config().app_config.window_resize_step as i32;
+            ui.resize_playback_window(if command == Command::GrowPlaybackWindow {
+                step
+            } else {
+                -step
+            });
+        }
+        Command::ToggleAlbumGridView => {
+            ui.is_album_grid_view = !ui.is_album_grid_view;
+        }
+        Command::ToggleDetailPanel => {
+            ui.is_detail_panel_shown = !ui.is_detail_panel_shown;
+        }
+        Command::ToggleTrackGrouping => {
+            ui.is_track_table_grouped = !ui.is_track_table_grouped;
+            ui.collapsed_track_groups.clear();
+            ui.visual_selection_anchor = None;
+        }
         Command::Queue => {
-            ui.new_page(PageState::Queue { scroll_offset: 0 });
+            ui.new_page(PageState::Queue {
+                queue_table: new_table_state(),
+            });
             client_pub.send(ClientRequest::GetCurrentUserQueue)?;
         }
         Command::CreatePlaylist => {
@@ -393,6 +839,157 @@ fn handle_global_command(
                 current_field: PlaylistCreateCurrentField::Name,
             });
         }
+        Command::FilterTracksByYear => {
+            let mut years = LineInput::default();
+            if let Some((from, to)) = ui.track_year_filter {
+                years.insert_str(&format!("{from}-{to}"));
+            }
+            ui.popup = Some(PopupState::TrackYearFilter { years });
+        }
+        Command::FilterTracksByTempo => {
+            let mut tempos = LineInput::default();
+            if let Some((from, to)) = ui.track_tempo_filter {
+                tempos.insert_str(&format!("{from}-{to}"));
+            }
+            ui.popup = Some(PopupState::TrackTempoFilter { tempos });
+        }
+        Command::TogglePartyMode => {
+            ui.popup = Some(PopupState::PartyModePassphrase {
+                passphrase: LineInput::default(),
+            });
+        }
+        Command::ModerateGuestRequests => {
+            ui.popup = Some(PopupState::GuestRequestList(new_list_state()));
+        }
+        Command::CleanUpLibrary => {
+            let data = state.data.read();
+            let items = data
+                .user_data
+                .playlists
+                .iter()
+                .cloned()
+                .map(Item::Playlist)
+                .chain(data.user_data.saved_albums.iter().cloned().map(Item::Album))
+                .chain(
+                    data.user_data
+                        .followed_artists
+                        .iter()
+                        .cloned()
+                        .map(Item::Artist),
+                )
+                .collect::<Vec<_>>();
+            ui.popup = Some(PopupState::LibraryCleanup {
+                marked: vec![false; items.len()],
+                items,
+                confirming: false,
+                list_state: new_list_state(),
+            });
+        }
+        Command::ShowMostSkippedTracks => {
+            let id = match ui.current_page() {
+                PageState::Context { id: Some(id), .. } => id.clone(),
+                _ => return Ok(false),
+            };
+            let data = state.data.read();
+            let mut tracks = data
+                .caches
+                .context
+                .get(&id.uri())
+                .map(|c| {
+                    c.tracks()
+                        .iter()
+                        .map(|t| (t.clone(), data.user_data.skip_count(t)))
+                        .filter(|(_, n)| *n > 0)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            if tracks.is_empty() {
+                ui.show_toast(tr("No skipped tracks"));
+                return Ok(true);
+            }
+            tracks.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+            tracks.truncate(MOST_SKIPPED_TRACKS_LIMIT);
+
+            let mut actions = vec![];
+            if let ContextId::Playlist(playlist_id) = id {
+                actions.push(SkippedTracksAction::RemoveFromPlaylist(playlist_id));
+            }
+            actions.push(SkippedTracksAction::AddToBlocklist);
+            ui.popup = Some(PopupState::SkippedTrackList {
+                actions,
+                tracks,
+                list_state: new_list_state(),
+            });
+        }
+        Command::MergePlaylists => {
+            ui.popup = Some(PopupState::UserPlaylistList(
+                PlaylistPopupAction::MergeFrom,
+                new_list_state(),
+            ));
+        }
+        Command::ArchivePlaylist => match ui.current_page() {
+            PageState::Context {
+                id: Some(ContextId::Playlist(id)),
+                ..
+            } => {
+                client_pub.send(ClientRequest::ArchivePlaylist(id.clone()))?;
+            }
+            _ => ui.show_toast(tr("Open a playlist to archive it")),
+        },
+        Command::ComparePlaylists => {
+            let playlist = match ui.current_page() {
+                PageState::Context {
+                    id: Some(ContextId::Playlist(id)),
+                    ..
+                } => match state.data.read().caches.context.get(&id.uri()) {
+                    Some(Context::Playlist { playlist, .. }) => playlist.clone(),
+                    _ => return Ok(false),
+                },
+                _ => {
+                    ui.show_toast(tr("Open a playlist to compare it with another playlist"));
+                    return Ok(true);
+                }
+            };
+            ui.popup = Some(PopupState::UserPlaylistList(
+                PlaylistPopupAction::Compare(Box::new(playlist)),
+                new_list_state(),
+            ));
+        }
+        Command::AddBookmark => {
+            let player = state.player.read();
+            let position = player.playback_progress().and_then(|p| p.to_std().ok());
+            if let (Some(uri), Some(position)) = (player.current_playing_item_uri(), position) {
+                ui.popup = Some(PopupState::BookmarkCreate {
+                    uri,
+                    position,
+                    name: LineInput::default(),
+                });
+            }
+        }
+        Command::JumpToNextBookmark => {
+            let (uri, progress) = {
+                let player = state.player.read();
+                (
+                    player.current_playing_item_uri(),
+                    player.playback_progress(),
+                )
+            };
+            if let (Some(uri), Some(progress)) = (uri, progress.and_then(|p| p.to_std().ok())) {
+                // skip bookmarks right after the current position, so that repeatedly
+                // jumping doesn't get stuck at the bookmark that was just jumped to
+                let position = progress + std::time::Duration::from_secs(1);
+                let data = state.data.read();
+                match data.user_data.next_bookmark(&uri, position) {
+                    Some(bookmark) => {
+                        client_pub.send(ClientRequest::Player(PlayerRequest::SeekTrack(
+                            chrono::Duration::from_std(bookmark.position)?,
+                        )))?;
+                        ui.show_toast(format!("{}: {}", tr("Bookmark"), bookmark.name));
+                    }
+                    None => ui.show_toast(tr("No next bookmark")),
+                }
+            }
+        }
         Command::ClosePopup => {
             ui.popup = None;
         }
@@ -401,8 +998,66 @@ fn handle_global_command(
     Ok(true)
 }
 
+/// Get the duration to seek the playback by with `SeekForward` and `SeekBackward`
+fn seek_duration() -> chrono::Duration {
+    chrono::Duration::try_seconds(config::get_config().app_config.seek_duration_in_secs as i64)
+        .unwrap_or_default()
+}
+
+/// Suspend the application until it's resumed.
+///
+/// If `pause_on_suspend` is enabled, the current playback is paused before suspending
+/// and resumed afterward. Upon resuming, the playback state is re-synced with Spotify.
+#[cfg(unix)]
+pub fn suspend_application(
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    let is_playing = || {
+        state
+            .player
+            .read()
+            .buffered_playback
+            .as_ref()
+            .map(|p| p.is_playing)
+            .unwrap_or_default()
+    };
+
+    let should_pause = config::get_config().app_config.pause_on_suspend && is_playing();
+    if should_pause {
+        client_pub.send(ClientRequest::Player(PlayerRequest::Pause))?;
+        // wait for the pause request to be handled before stopping the process,
+        // during which the UI state's lock is released to not block the UI
+        parking_lot::MutexGuard::unlocked(ui, || {
+            for _ in 0..20 {
+                if !is_playing() {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        });
+    }
+
+    tracing::info!("Suspending the application...");
+    crate::ui::suspend(ui).context("suspend the application's UI")?;
+    tracing::info!("Resumed the application");
+
+    if should_pause {
+        client_pub.send(ClientRequest::Player(PlayerRequest::Resume))?;
+    }
+    client_pub.send(ClientRequest::GetCurrentPlayback)?;
+    Ok(())
+}
+
 fn get_clipboard_content() -> Result<String> {
     CLIPBOARD_PROVIDER
         .get_or_init(|| get_clipboard_provider())
         .get_contents()
 }
+
+fn execute_copy_command(text: String) -> Result<()> {
+    CLIPBOARD_PROVIDER
+        .get_or_init(|| get_clipboard_provider())
+        .set_contents(text)
+}
@@ -1,5 +1,7 @@
 use anyhow::Context as _;
 
+use crate::command::{construct_episode_actions, EpisodeAction};
+
 use super::*;
 
 pub fn handle_key_sequence_for_page(
@@ -20,8 +22,15 @@ pub fn handle_key_sequence_for_page(
         .find_command_from_key_sequence(key_sequence)
     {
         Some(command) => command,
-        None => return Ok(false),
+        None => {
+            return Ok(page_type == PageType::Context
+                && open_track_number_popup_with_digit(key_sequence, ui))
+        }
     };
+    // a command disallowed in the kiosk mode is rejected by the global command handler
+    if !command.is_allowed_in_kiosk_mode() && config::get_config().app_config.kiosk_mode {
+        return Ok(false);
+    }
 
     match page_type {
         PageType::Search => anyhow::bail!("page search type should already be handled!"),
@@ -30,8 +39,16 @@ pub fn handle_key_sequence_for_page(
         PageType::Browse => handle_command_for_browse_page(command, client_pub, ui, state),
         #[cfg(feature = "lyric-finder")]
         PageType::Lyric => handle_command_for_lyric_page(command, ui),
-        PageType::Queue => handle_command_for_queue_page(command, ui),
+        PageType::Queue => handle_command_for_queue_page(command, client_pub, ui, state),
+        PageType::RecentlyPlayed => {
+            handle_command_for_recently_played_page(command, client_pub, ui, state)
+        }
+        PageType::Chapters => handle_command_for_chapters_page(command, client_pub, ui, state),
+        PageType::Show => handle_command_for_show_page(command, client_pub, ui, state),
         PageType::CommandHelp => handle_command_for_command_help_page(command, ui),
+        PageType::PlaylistComparison => {
+            handle_command_for_playlist_comparison_page(command, client_pub, ui, state)
+        }
     }
 }
 
@@ -46,6 +63,20 @@ fn handle_command_for_library_page(
             ui.new_search_popup();
             Ok(true)
         }
+        Command::ShuffleLibrary => {
+            let source = match ui.current_page() {
+                PageState::Library {
+                    state:
+                        LibraryPageUIState {
+                            focus: LibraryFocusState::SavedAlbums,
+                            ..
+                        },
+                } => LibraryShuffleSource::SavedAlbums,
+                _ => LibraryShuffleSource::LikedTracks,
+            };
+            client_pub.send(ClientRequest::StartShuffledLibraryPlayback(source))?;
+            Ok(true)
+        }
         _ => {
             let data = state.data.read();
             let focus_state = match ui.current_page() {
@@ -61,19 +92,23 @@ fn handle_command_for_library_page(
                 ),
                 LibraryFocusState::SavedAlbums => window::handle_command_for_album_list_window(
                     command,
+                    client_pub,
                     ui.search_filtered_items(&data.user_data.saved_albums),
                     &data,
                     ui,
-                    client_pub,
                 ),
                 LibraryFocusState::FollowedArtists => {
                     window::handle_command_for_artist_list_window(
                         command,
+                        client_pub,
                         ui.search_filtered_items(&data.user_data.followed_artists),
                         &data,
                         ui,
                     )
                 }
+                LibraryFocusState::SavedEpisodes => {
+                    handle_command_for_saved_episode_list(command, client_pub, &data, ui)
+                }
             }
         }
     }
@@ -100,8 +135,10 @@ fn handle_key_sequence_for_search_page(
             return match &key_sequence.keys[0] {
                 Key::None(crossterm::event::KeyCode::Enter) => {
                     if !line_input.is_empty() {
-                        *current_query = line_input.get_text();
-                        client_pub.send(ClientRequest::Search(line_input.get_text()))?;
+                        *current_query = line_input.submit();
+                        client_pub.send(ClientRequest::Search(current_query.clone()))?;
+                        let history = line_input.history().to_vec();
+                        ui.set_search_history(&history);
                     }
                     Ok(true)
                 }
@@ -120,6 +157,10 @@ fn handle_key_sequence_for_search_page(
         Some(command) => command,
         None => return Ok(false),
     };
+    // a command disallowed in the kiosk mode is rejected by the global command handler
+    if !command.is_allowed_in_kiosk_mode() && config::get_config().app_config.kiosk_mode {
+        return Ok(false);
+    }
 
     let data = state.data.read();
     let search_results = data.caches.search.get(current_query);
@@ -136,13 +177,13 @@ fn handle_key_sequence_for_search_page(
             let artists = search_results
                 .map(|s| s.artists.iter().collect())
                 .unwrap_or_default();
-            window::handle_command_for_artist_list_window(command, artists, &data, ui)
+            window::handle_command_for_artist_list_window(command, client_pub, artists, &data, ui)
         }
         SearchFocusState::Albums => {
             let albums = search_results
                 .map(|s| s.albums.iter().collect())
                 .unwrap_or_default();
-            window::handle_command_for_album_list_window(command, albums, &data, ui, client_pub)
+            window::handle_command_for_album_list_window(command, client_pub, albums, &data, ui)
         }
         SearchFocusState::Playlists => {
             let playlists = search_results
@@ -153,6 +194,27 @@ fn handle_key_sequence_for_search_page(
     }
 }
 
+/// Open the track number popup prefilled with a typed digit (if not bound to any shortcut),
+/// so that typing a number followed by `Enter` goes to the track of the number
+fn open_track_number_popup_with_digit(key_sequence: &KeySequence, ui: &mut UIStateGuard) -> bool {
+    let [Key::None(crossterm::event::KeyCode::Char(c))] = key_sequence.keys[..] else {
+        return false;
+    };
+    if !c.is_ascii_digit()
+        || !config::get_config()
+            .keymap_config
+            .find_matched_prefix_keymaps(key_sequence)
+            .is_empty()
+    {
+        return false;
+    }
+
+    let mut number = LineInput::default();
+    number.insert_str(&c.to_string());
+    ui.popup = Some(PopupState::TrackNumber { number });
+    true
+}
+
 fn handle_command_for_context_page(
     command: Command,
     client_pub: &flume::Sender<ClientRequest>,
@@ -164,6 +226,47 @@ fn handle_command_for_context_page(
             ui.new_search_popup();
             Ok(true)
         }
+        Command::SearchArtistGenre | Command::BrowseGenre => {
+            let genres = match ui.current_page() {
+                PageState::Context { id: Some(id), .. } => {
+                    match state.data.read().caches.context.get(&id.uri()) {
+                        Some(Context::Artist { artist, .. }) => artist.genres.clone(),
+                        _ => return Ok(false),
+                    }
+                }
+                _ => return Ok(false),
+            };
+            if !genres.is_empty() {
+                let action = if command == Command::BrowseGenre {
+                    GenrePopupAction::Browse
+                } else {
+                    GenrePopupAction::Search
+                };
+                ui.popup = Some(PopupState::GenreList(action, genres, new_list_state()));
+            }
+            Ok(true)
+        }
+        Command::GoToTrackNumber => {
+            ui.popup = Some(PopupState::TrackNumber {
+                number: LineInput::default(),
+            });
+            Ok(true)
+        }
+        Command::RefreshPlayback => {
+            // retry loading the context if it failed to load previously.
+            // The command is not marked as handled, so the playback is still refreshed.
+            if let PageState::Context { id: Some(id), .. } = ui.current_page() {
+                let uri = id.uri();
+                let mut data = state.data.write();
+                if data.context_loading_status.get(&uri) == Some(&LoadingStatus::Failed) {
+                    data.context_loading_status.remove(&uri);
+                    if let Some(request) = ClientRequest::get_context(id) {
+                        client_pub.send(request)?;
+                    }
+                }
+            }
+            Ok(false)
+        }
         _ => window::handle_command_for_focused_context_window(command, client_pub, ui, state),
     }
 }
@@ -187,6 +290,18 @@ fn handle_command_for_browse_page(
                 .get(&category.id)
                 .map(|v| ui.search_filtered_items(v).len())
                 .unwrap_or_default(),
+            BrowsePageUIState::NewReleases { .. } => data
+                .browse
+                .new_releases
+                .as_ref()
+                .map(|v| ui.search_filtered_items(v).len())
+                .unwrap_or_default(),
+            BrowsePageUIState::FeaturedPlaylists { .. } => data
+                .browse
+                .featured_playlists
+                .as_ref()
+                .map(|v| ui.search_filtered_items(v).len())
+                .unwrap_or_default(),
         },
         _ => anyhow::bail!("expect a browse page state"),
     };
@@ -233,9 +348,102 @@ fn handle_command_for_browse_page(
                             state: None,
                         });
                     }
+                    BrowsePageUIState::FeaturedPlaylists { .. } => {
+                        let playlists = data
+                            .browse
+                            .featured_playlists
+                            .as_ref()
+                            .context("expect to have featured playlists data")?;
+                        let context_id = ContextId::Playlist(
+                            ui.search_filtered_items(playlists)[selected].id.clone(),
+                        );
+                        ui.new_page(PageState::Context {
+                            id: None,
+                            context_page_type: ContextPageType::Browsing(context_id),
+                            state: None,
+                        });
+                    }
+                    BrowsePageUIState::NewReleases { .. } => {
+                        let albums = data
+                            .browse
+                            .new_releases
+                            .as_ref()
+                            .context("expect to have new releases data")?;
+                        let context_id =
+                            ContextId::Album(ui.search_filtered_items(albums)[selected].id.clone());
+                        ui.new_page(PageState::Context {
+                            id: None,
+                            context_page_type: ContextPageType::Browsing(context_id),
+                            state: None,
+                        });
+                    }
+                },
+                _ => anyhow::bail!("expect a browse page state"),
+            };
+        }
+        Command::ShowActionsOnSelectedItem => {
+            let PageState::Browse {
+                state: BrowsePageUIState::NewReleases { .. },
+            } = page_state
+            else {
+                return Ok(false);
+            };
+            let album = ui
+                .search_filtered_items(data.browse.new_releases.as_deref().unwrap_or_default())
+                [selected]
+                .clone();
+            let actions = command::construct_album_actions(&album, &data);
+            ui.popup = Some(PopupState::ActionList(
+                ActionListItem::Album(album, actions),
+                new_list_state(),
+            ));
+        }
+        Command::SaveAllNewReleases => {
+            let PageState::Browse {
+                state: BrowsePageUIState::NewReleases { .. },
+            } = page_state
+            else {
+                return Ok(false);
+            };
+            if !ui.reject_in_party_mode() {
+                // only save the albums shown in the (search filtered) page
+                let albums = ui
+                    .search_filtered_items(data.browse.new_releases.as_deref().unwrap_or_default())
+                    .into_iter()
+                    .filter(|a| !data.user_data.saved_albums.iter().any(|s| s.id == a.id))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                ui.show_toast(format!(
+                    "{} {} {}",
+                    tr("Saving"),
+                    albums.len(),
+                    tr("albums to the library")
+                ));
+                client_pub.send(ClientRequest::AddAlbumsToLibrary(albums))?;
+            }
+        }
+        Command::BrowseGenre => {
+            // browse categories are mostly genres, so the category's name is mapped onto a genre seed
+            let genre = match page_state {
+                PageState::Browse { state } => match state {
+                    BrowsePageUIState::CategoryList { .. } => ui
+                        .search_filtered_items(&data.browse.categories)[selected]
+                        .name
+                        .clone(),
+                    BrowsePageUIState::CategoryPlaylistList { category, .. } => {
+                        category.name.clone()
+                    }
+                    BrowsePageUIState::NewReleases { .. }
+                    | BrowsePageUIState::FeaturedPlaylists { .. } => return Ok(false),
                 },
                 _ => anyhow::bail!("expect a browse page state"),
             };
+            if data.browse.has_no_genre_seed(&genre) {
+                ui.show_toast(tr("No recommendations for the genre"));
+                return Ok(true);
+            }
+            ui.new_genre_page(&genre);
+            client_pub.send(ClientRequest::GetGenreTracks(genre))?;
         }
         Command::Search => {
             ui.new_search_popup();
@@ -251,28 +459,296 @@ fn handle_command_for_lyric_page(command: Command, ui: &mut UIStateGuard) -> Res
         PageState::Lyric { scroll_offset, .. } => *scroll_offset,
         _ => return Ok(false),
     };
-    Ok(handle_navigation_command(
-        command,
-        ui.current_page_mut(),
-        scroll_offset,
-        10000,
-    ))
+    let handled = handle_navigation_command(command, ui.current_page_mut(), scroll_offset, 10000);
+    if handled {
+        // pause the auto-scroll, so the lyric can be scrolled manually
+        ui.lyric_scrolled_at = Some(std::time::Instant::now());
+    }
+    Ok(handled)
 }
 
 fn handle_command_for_queue_page(
     command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
     ui: &mut UIStateGuard,
-) -> Result<bool, anyhow::Error> {
-    let scroll_offset = match ui.current_page() {
-        PageState::Queue { scroll_offset } => *scroll_offset,
+    state: &SharedState,
+) -> Result<bool> {
+    let mut items = match state.player.read().queue {
+        Some(ref queue) => queue.queue.clone(),
+        None => return Ok(false),
+    };
+
+    let page_state = ui.current_page_mut();
+    let selected = page_state.selected().unwrap_or_default();
+    if selected >= items.len() {
+        return Ok(false);
+    }
+
+    if handle_navigation_command(command, page_state, selected, items.len()) {
+        return Ok(true);
+    }
+    let new_selected = match command {
+        Command::MovePlaylistItemUp => {
+            if selected == 0 {
+                return Ok(true);
+            }
+            items.swap(selected, selected - 1);
+            selected - 1
+        }
+        Command::MovePlaylistItemDown => {
+            if selected + 1 >= items.len() {
+                return Ok(true);
+            }
+            items.swap(selected, selected + 1);
+            selected + 1
+        }
+        Command::RemoveFromQueue => {
+            items.remove(selected);
+            selected.min(items.len().saturating_sub(1))
+        }
         _ => return Ok(false),
     };
-    Ok(handle_navigation_command(
-        command,
-        ui.current_page_mut(),
-        scroll_offset,
-        10000,
-    ))
+
+    client_pub.send(ClientRequest::ReplaceQueue(items.clone()))?;
+    // update the queue before the request completes for an immediate feedback
+    if let Some(queue) = state.player.write().queue.as_mut() {
+        queue.queue = items;
+    }
+    ui.current_page_mut().select(new_selected);
+    Ok(true)
+}
+
+/// Handle a command for the saved episode list of the library page
+fn handle_command_for_saved_episode_list(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    data: &DataReadGuard,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let len = ui
+        .search_filtered_items(&data.user_data.saved_episodes)
+        .len();
+
+    let page_state = ui.current_page_mut();
+    let selected = page_state.selected().unwrap_or_default();
+    if selected >= len {
+        return Ok(false);
+    }
+
+    if handle_navigation_command(command, page_state, selected, len) {
+        return Ok(true);
+    }
+    let episode = ui.search_filtered_items(&data.user_data.saved_episodes)[selected].clone();
+    match command {
+        Command::ChooseSelected => {
+            // start the episode from where the user left off
+            let position = episode
+                .resume_position
+                .and_then(|p| chrono::Duration::from_std(p).ok());
+            client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
+                Playback::Episode(episode.id, position),
+                None,
+            )))?;
+        }
+        Command::AddSelectedItemToQueue => {
+            client_pub.send(ClientRequest::AddEpisodeToQueue(episode.id))?;
+        }
+        Command::ShowActionsOnSelectedItem => {
+            let actions = construct_episode_actions(&episode, data);
+            ui.popup = Some(PopupState::ActionList(
+                ActionListItem::Episode(episode, actions),
+                new_list_state(),
+            ));
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn handle_command_for_recently_played_page(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    state: &SharedState,
+) -> Result<bool> {
+    if command == Command::Search {
+        ui.new_search_popup();
+        return Ok(true);
+    }
+
+    let data = state.data.read();
+    let tracks = ui
+        .search_filtered_items(&data.user_data.recently_played_tracks)
+        .into_iter()
+        .map(|t| &t.track)
+        .collect();
+    window::handle_command_for_track_list_window(command, client_pub, tracks, &data, ui)
+}
+
+fn handle_command_for_chapters_page(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    state: &SharedState,
+) -> Result<bool> {
+    let data = state.data.read();
+    let chapters = match ui.current_page() {
+        PageState::Chapters { audiobook_id, .. } => match data.caches.chapters.get(audiobook_id) {
+            Some(chapters) => chapters,
+            None => return Ok(false),
+        },
+        _ => anyhow::bail!("expect a chapters page state"),
+    };
+    let len = ui.search_filtered_items(chapters).len();
+
+    let page_state = ui.current_page_mut();
+    let selected = page_state.selected().unwrap_or_default();
+    if selected >= len {
+        return Ok(false);
+    }
+
+    if handle_navigation_command(command, page_state, selected, len) {
+        return Ok(true);
+    }
+    let chapter = ui.search_filtered_items(chapters)[selected].clone();
+    match command {
+        Command::ChooseSelected => {
+            // start the chapter from where the user left off
+            let position = chapter
+                .resume_position
+                .and_then(|p| chrono::Duration::from_std(p).ok());
+            client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
+                Playback::Episode(chapter.id, position),
+                None,
+            )))?;
+        }
+        Command::AddSelectedItemToQueue => {
+            client_pub.send(ClientRequest::AddEpisodeToQueue(chapter.id))?;
+        }
+        Command::Search => {
+            ui.new_search_popup();
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn handle_command_for_show_page(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    state: &SharedState,
+) -> Result<bool> {
+    let data = state.data.read();
+    let episodes = match ui.current_page() {
+        PageState::Show { show_id, .. } => match data.caches.show_episodes.get(show_id.id()) {
+            Some(episodes) => episodes,
+            None => return Ok(false),
+        },
+        _ => anyhow::bail!("expect a show page state"),
+    };
+    let len = ui.search_filtered_items(episodes).len();
+
+    let page_state = ui.current_page_mut();
+    let selected = page_state.selected().unwrap_or_default();
+    if selected >= len {
+        return Ok(false);
+    }
+
+    if handle_navigation_command(command, page_state, selected, len) {
+        return Ok(true);
+    }
+    let episode = ui.search_filtered_items(episodes)[selected].clone();
+    match command {
+        Command::ChooseSelected => {
+            // start the episode from where the user left off
+            let position = episode
+                .resume_position
+                .and_then(|p| chrono::Duration::from_std(p).ok());
+            client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
+                Playback::Episode(episode.id, position),
+                None,
+            )))?;
+        }
+        Command::AddSelectedItemToQueue => {
+            client_pub.send(ClientRequest::AddEpisodeToQueue(episode.id))?;
+        }
+        Command::ShowActionsOnSelectedItem => {
+            let mut actions = construct_episode_actions(&episode, &data);
+            // the show of the episode is already shown
+            actions.retain(|a| !matches!(a, EpisodeAction::GoToShow));
+            ui.popup = Some(PopupState::ActionList(
+                ActionListItem::Episode(episode, actions),
+                new_list_state(),
+            ));
+        }
+        Command::Search => {
+            ui.new_search_popup();
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn handle_command_for_playlist_comparison_page(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    state: &SharedState,
+) -> Result<bool> {
+    let (playlists, focus) = match ui.current_page() {
+        PageState::PlaylistComparison { playlists, state } => (playlists.clone(), state.focus),
+        _ => anyhow::bail!("expect a playlist comparison page state"),
+    };
+    let data = state.data.read();
+    let [first_only, second_only, common] = match data.compare_playlists(&playlists.0, &playlists.1)
+    {
+        Some(comparison) => comparison,
+        None => return Ok(false),
+    };
+    // the tracks only in one playlist are missing from the other playlist
+    let (tracks, target) = match focus {
+        PlaylistComparisonFocusState::FirstOnly => (first_only, Some(&playlists.1)),
+        PlaylistComparisonFocusState::SecondOnly => (second_only, Some(&playlists.0)),
+        PlaylistComparisonFocusState::Common => (common, None),
+    };
+
+    if command != Command::CopyMissingTracks {
+        return window::handle_command_for_track_list_window(
+            command, client_pub, tracks, &data, ui,
+        );
+    }
+    if ui.reject_in_party_mode() {
+        return Ok(true);
+    }
+    let Some(target) = target else {
+        ui.show_toast(tr("The tracks are already in both playlists"));
+        return Ok(true);
+    };
+    if tracks.is_empty() {
+        ui.show_toast(tr("No missing tracks"));
+        return Ok(true);
+    }
+    if !data
+        .user_data
+        .modifiable_playlists()
+        .iter()
+        .any(|p| p.id == target.id)
+    {
+        ui.show_toast(format!(
+            "{}: {}",
+            tr("Cannot modify the playlist"),
+            target.name
+        ));
+        return Ok(true);
+    }
+
+    // the client shows a toast once the tracks are added
+    client_pub.send(ClientRequest::AddTracksToPlaylist(
+        target.id.clone(),
+        tracks.into_iter().cloned().collect(),
+    ))?;
+    Ok(true)
 }
 
 fn handle_command_for_command_help_page(command: Command, ui: &mut UIStateGuard) -> Result<bool> {
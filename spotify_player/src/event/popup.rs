@@ -1,7 +1,7 @@
 use super::*;
 use crate::command::{
-    construct_album_actions, construct_artist_actions, AlbumAction, ArtistAction, PlaylistAction,
-    TrackAction,
+    construct_album_actions, construct_artist_actions, AlbumAction, ArtistAction, EpisodeAction,
+    PlaylistAction, TrackAction,
 };
 use anyhow::Context;
 
@@ -21,6 +21,27 @@ pub fn handle_key_sequence_for_popup(
         PopupState::PlaylistCreate { .. } => {
             return handle_key_sequence_for_create_playlist_popup(key_sequence, client_pub, ui);
         }
+        PopupState::BookmarkCreate { .. } => {
+            return handle_key_sequence_for_create_bookmark_popup(key_sequence, state, ui);
+        }
+        PopupState::TrackYearFilter { .. } => {
+            return handle_key_sequence_for_track_year_filter_popup(key_sequence, ui);
+        }
+        PopupState::TrackTempoFilter { .. } => {
+            return handle_key_sequence_for_track_tempo_filter_popup(key_sequence, ui);
+        }
+        PopupState::SeekPosition { .. } => {
+            return handle_key_sequence_for_seek_position_popup(key_sequence, client_pub, ui);
+        }
+        PopupState::TrackNumber { .. } => {
+            return handle_key_sequence_for_track_number_popup(key_sequence, state, ui);
+        }
+        PopupState::Authenticate { .. } => {
+            return handle_key_sequence_for_authenticate_popup(key_sequence, client_pub, ui);
+        }
+        PopupState::PartyModePassphrase { .. } => {
+            return handle_key_sequence_for_party_mode_passphrase_popup(key_sequence, ui);
+        }
         PopupState::ActionList(item, ..) => {
             return handle_key_sequence_for_action_list_popup(
                 item.n_actions(),
@@ -46,9 +67,37 @@ pub fn handle_key_sequence_for_popup(
         PopupState::PlaylistCreate { .. } => {
             anyhow::bail!("create playlist popup should be handled before")
         }
+        PopupState::BookmarkCreate { .. } => {
+            anyhow::bail!("create bookmark popup should be handled before")
+        }
+        PopupState::TrackYearFilter { .. } => {
+            anyhow::bail!("track year filter popup should be handled before")
+        }
+        PopupState::TrackTempoFilter { .. } => {
+            anyhow::bail!("track tempo filter popup should be handled before")
+        }
+        PopupState::SeekPosition { .. } => {
+            anyhow::bail!("seek position popup should be handled before")
+        }
+        PopupState::TrackNumber { .. } => {
+            anyhow::bail!("track number popup should be handled before")
+        }
+        PopupState::Authenticate { .. } => {
+            anyhow::bail!("authenticate popup should be handled before")
+        }
+        PopupState::PartyModePassphrase { .. } => {
+            anyhow::bail!("party mode passphrase popup should be handled before")
+        }
         PopupState::ActionList(..) => {
             anyhow::bail!("action list popup should be handled before")
         }
+        PopupState::TrackCredits(_) => {
+            if command == Command::ClosePopup {
+                ui.popup = None;
+                return Ok(true);
+            }
+            Ok(false)
+        }
         PopupState::ArtistList(_, artists, _) => {
             let n_items = artists.len();
 
@@ -109,8 +158,12 @@ pub fn handle_key_sequence_for_popup(
                     rspotify_model::Type::Playlist,
                 )
             }
-            PlaylistPopupAction::AddTrack(track_id) => {
-                let track_id = track_id.clone();
+            PlaylistPopupAction::AddTrack(_) | PlaylistPopupAction::AddTracks(_) => {
+                let tracks = match action {
+                    PlaylistPopupAction::AddTracks(tracks) => tracks.clone(),
+                    PlaylistPopupAction::AddTrack(track) => vec![*track.clone()],
+                    _ => unreachable!(),
+                };
                 let playlist_ids = state
                     .data
                     .read()
@@ -126,9 +179,9 @@ pub fn handle_key_sequence_for_popup(
                     playlist_ids.len(),
                     |_, _| {},
                     |ui: &mut UIStateGuard, id: usize| -> Result<()> {
-                        client_pub.send(ClientRequest::AddTrackToPlaylist(
+                        client_pub.send(ClientRequest::AddTracksToPlaylist(
                             playlist_ids[id].clone(),
-                            track_id.clone(),
+                            tracks.clone(),
                         ))?;
                         ui.popup = None;
                         Ok(())
@@ -138,6 +191,91 @@ pub fn handle_key_sequence_for_popup(
                     },
                 )
             }
+            PlaylistPopupAction::Compare(playlist) => {
+                let first = *playlist.clone();
+                let playlists = state
+                    .data
+                    .read()
+                    .user_data
+                    .playlists
+                    .iter()
+                    .filter(|p| p.id != first.id)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                handle_command_for_list_popup(
+                    command,
+                    ui,
+                    playlists.len(),
+                    |_, _| {},
+                    |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                        let second = playlists[id].clone();
+                        for p in [&first, &second] {
+                            client_pub.send(ClientRequest::GetContext(ContextId::Playlist(
+                                p.id.clone(),
+                            )))?;
+                        }
+                        ui.new_page(PageState::PlaylistComparison {
+                            playlists: Box::new((first.clone(), second)),
+                            state: PlaylistComparisonPageUIState::new(),
+                        });
+                        Ok(())
+                    },
+                    |ui: &mut UIStateGuard| {
+                        ui.popup = None;
+                    },
+                )
+            }
+            PlaylistPopupAction::MergeFrom => {
+                let playlists = state.data.read().user_data.playlists.clone();
+
+                handle_command_for_list_popup(
+                    command,
+                    ui,
+                    playlists.len(),
+                    |_, _| {},
+                    |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                        ui.popup = Some(PopupState::UserPlaylistList(
+                            PlaylistPopupAction::MergeInto(Box::new(playlists[id].clone())),
+                            new_list_state(),
+                        ));
+                        Ok(())
+                    },
+                    |ui: &mut UIStateGuard| {
+                        ui.popup = None;
+                    },
+                )
+            }
+            PlaylistPopupAction::MergeInto(source) => {
+                let source_id = source.id.clone();
+                let target_ids = state
+                    .data
+                    .read()
+                    .user_data
+                    .modifiable_playlists()
+                    .into_iter()
+                    .filter(|p| p.id != source_id)
+                    .map(|p| p.id.clone())
+                    .collect::<Vec<_>>();
+
+                handle_command_for_list_popup(
+                    command,
+                    ui,
+                    target_ids.len(),
+                    |_, _| {},
+                    |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                        client_pub.send(ClientRequest::MergePlaylists {
+                            source_id: source_id.clone(),
+                            target_id: target_ids[id].clone(),
+                        })?;
+                        ui.popup = None;
+                        Ok(())
+                    },
+                    |ui: &mut UIStateGuard| {
+                        ui.popup = None;
+                    },
+                )
+            }
         },
         PopupState::UserFollowedArtistList(_) => {
             let artist_uris = state
@@ -199,17 +337,213 @@ pub fn handle_key_sequence_for_popup(
                 },
             )
         }
+        PopupState::GenreList(_, genres, _) => {
+            let n_items = genres.len();
+
+            handle_command_for_list_popup(
+                command,
+                ui,
+                n_items,
+                |_, _| {},
+                |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                    let (action, genre) = match ui.popup {
+                        Some(PopupState::GenreList(action, ref genres, _)) => {
+                            (action, genres[id].clone())
+                        }
+                        _ => return Ok(()),
+                    };
+                    match action {
+                        GenrePopupAction::Search => {
+                            new_search_page_with_query(ui, client_pub, format!("genre:\"{genre}\""))
+                        }
+                        GenrePopupAction::Browse => {
+                            if state.data.read().browse.has_no_genre_seed(&genre) {
+                                ui.show_toast(tr("No recommendations for the genre"));
+                                return Ok(());
+                            }
+                            ui.new_genre_page(&genre);
+                            client_pub.send(ClientRequest::GetGenreTracks(genre))?;
+                            Ok(())
+                        }
+                    }
+                },
+                |ui: &mut UIStateGuard| {
+                    ui.popup = None;
+                },
+            )
+        }
+        PopupState::SkippedTrackList {
+            actions, tracks, ..
+        } => {
+            let (actions, tracks) = (actions.clone(), tracks.clone());
+            handle_command_for_list_popup(
+                command,
+                ui,
+                actions.len() + tracks.len(),
+                |_, _| {},
+                |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                    let Some(action) = actions.get(id) else {
+                        // show the actions on the selected track
+                        let track = tracks[id - actions.len()].0.clone();
+                        let actions = command::construct_track_actions(&track, &state.data.read());
+                        ui.popup = Some(PopupState::ActionList(
+                            ActionListItem::Track(track, actions),
+                            new_list_state(),
+                        ));
+                        return Ok(());
+                    };
+                    if ui.reject_in_party_mode() {
+                        return Ok(());
+                    }
+                    match action {
+                        SkippedTracksAction::RemoveFromPlaylist(playlist_id) => {
+                            for (track, _) in &tracks {
+                                client_pub.send(ClientRequest::DeleteTrackFromPlaylist(
+                                    playlist_id.clone(),
+                                    track.id.clone(),
+                                ))?;
+                            }
+                            ui.show_toast(format!(
+                                "{} {} {}",
+                                tr("Removed"),
+                                tracks.len(),
+                                tr("tracks from the playlist")
+                            ));
+                        }
+                        SkippedTracksAction::AddToBlocklist => {
+                            let mut data = state.data.write();
+                            for (track, _) in &tracks {
+                                data.user_data.set_blocked_track(&track.id, true);
+                            }
+                            ui.show_toast(format!(
+                                "{} {} {}",
+                                tr("Blocked"),
+                                tracks.len(),
+                                tr("tracks")
+                            ));
+                        }
+                    }
+                    ui.popup = None;
+                    Ok(())
+                },
+                |ui: &mut UIStateGuard| {
+                    ui.popup = None;
+                },
+            )
+        }
+        PopupState::LibraryCleanup { items, .. } => {
+            let n_items = items.len() + 1;
+            handle_command_for_list_popup(
+                command,
+                ui,
+                n_items,
+                |ui: &mut UIStateGuard, _| {
+                    // moving the selection cancels a pending confirmation
+                    if let Some(PopupState::LibraryCleanup { confirming, .. }) = ui.popup.as_mut() {
+                        *confirming = false;
+                    }
+                },
+                |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                    let Some(PopupState::LibraryCleanup {
+                        items,
+                        marked,
+                        confirming,
+                        ..
+                    }) = ui.popup.as_mut()
+                    else {
+                        return Ok(());
+                    };
+                    if id > 0 {
+                        // toggle the selected item's mark
+                        marked[id - 1] = !marked[id - 1];
+                        *confirming = false;
+                        return Ok(());
+                    }
+
+                    let ids = items
+                        .iter()
+                        .zip(marked.iter())
+                        .filter(|(_, &m)| m)
+                        .map(|(item, _)| match item {
+                            Item::Playlist(p) => ItemId::Playlist(p.id.clone()),
+                            Item::Album(a) => ItemId::Album(a.id.clone()),
+                            Item::Artist(a) => ItemId::Artist(a.id.clone()),
+                            Item::Track(t) => ItemId::Track(t.id.clone()),
+                        })
+                        .collect::<Vec<_>>();
+                    if ids.is_empty() {
+                        ui.show_toast(tr("No marked items"));
+                    } else if !*confirming {
+                        // ask for a confirmation before removing the items
+                        *confirming = true;
+                    } else if !ui.reject_in_party_mode() {
+                        client_pub.send(ClientRequest::DeleteItemsFromLibrary(ids))?;
+                        ui.popup = None;
+                    }
+                    Ok(())
+                },
+                |ui: &mut UIStateGuard| {
+                    ui.popup = None;
+                },
+            )
+        }
+        PopupState::GuestRequestList(_) => {
+            let id = popup.list_selected().unwrap_or_default();
+            if command == Command::RemoveFromQueue {
+                // reject the selected request
+                if id < ui.guest_requests.len() {
+                    ui.guest_requests.remove(id);
+                    update_guest_request_list_popup(ui, id);
+                }
+                return Ok(true);
+            }
+
+            let n_requests = ui.guest_requests.len();
+            handle_command_for_list_popup(
+                command,
+                ui,
+                n_requests,
+                |_, _| {},
+                |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                    // accept the selected request by adding the requested track to the queue
+                    let track = ui.guest_requests.remove(id);
+                    client_pub.send(ClientRequest::AddTrackToQueue(track.id))?;
+                    update_guest_request_list_popup(ui, id);
+                    Ok(())
+                },
+                |ui: &mut UIStateGuard| {
+                    ui.popup = None;
+                },
+            )
+        }
         PopupState::DeviceList(_) => {
             let player = state.player.read();
 
+            if command == Command::SetDefaultDevice {
+                let id = popup.list_selected().unwrap_or_default();
+                if ui.reject_in_party_mode() {
+                    return Ok(true);
+                }
+                if let Some(device) = player.devices.get(id) {
+                    config::AppConfig::set_default_device(
+                        &config::get_config().config_folder,
+                        device.name.clone(),
+                    )
+                    .context("update the default device in the config file")?;
+                    tracing::info!("Set device (name={}) as the default device", device.name);
+                }
+                return Ok(true);
+            }
+
             handle_command_for_list_popup(
                 command,
                 ui,
                 player.devices.len(),
                 |_, _| {},
                 |ui: &mut UIStateGuard, id: usize| -> Result<()> {
+                    // preserve the current play state when transferring the playback
                     let is_playing = player
-                        .playback
+                        .buffered_playback
                         .as_ref()
                         .map(|p| p.is_playing)
                         .unwrap_or(false);
@@ -228,6 +562,17 @@ pub fn handle_key_sequence_for_popup(
     }
 }
 
+/// Update the guest request list popup after removing the `id`-th request,
+/// closing the popup if there is no remaining request
+fn update_guest_request_list_popup(ui: &mut UIStateGuard, id: usize) {
+    let n_requests = ui.guest_requests.len();
+    if n_requests == 0 {
+        ui.popup = None;
+    } else if let Some(popup) = ui.popup.as_mut() {
+        popup.list_select(Some(id.min(n_requests - 1)));
+    }
+}
+
 fn handle_key_sequence_for_create_playlist_popup(
     key_sequence: &KeySequence,
     client_pub: &flume::Sender<ClientRequest>,
@@ -275,36 +620,345 @@ fn handle_key_sequence_for_create_playlist_popup(
     Ok(false)
 }
 
-fn handle_key_sequence_for_search_popup(
+fn handle_key_sequence_for_authenticate_popup(
     key_sequence: &KeySequence,
     client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let (username, password, current_field) = match ui.popup {
+        Some(PopupState::Authenticate {
+            ref mut username,
+            ref mut password,
+            ref mut current_field,
+        }) => (username, password, current_field),
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                // submitting the username moves to the password field
+                if *current_field == AuthenticateCurrentField::Username {
+                    *current_field = AuthenticateCurrentField::Password;
+                    return Ok(true);
+                }
+                if username.is_empty() || password.is_empty() {
+                    return Ok(true);
+                }
+                client_pub.send(ClientRequest::Authenticate {
+                    username: username.get_text(),
+                    password: Secret(password.get_text()),
+                })?;
+                ui.popup = None;
+                return Ok(true);
+            }
+            Key::None(crossterm::event::KeyCode::Tab)
+            | Key::None(crossterm::event::KeyCode::BackTab) => {
+                *current_field = match &current_field {
+                    AuthenticateCurrentField::Username => AuthenticateCurrentField::Password,
+                    AuthenticateCurrentField::Password => AuthenticateCurrentField::Username,
+                };
+                return Ok(true);
+            }
+            k => {
+                let line_input = match current_field {
+                    AuthenticateCurrentField::Username => username,
+                    AuthenticateCurrentField::Password => password,
+                };
+                if line_input.input(k).is_some() {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_create_bookmark_popup(
+    key_sequence: &KeySequence,
     state: &SharedState,
     ui: &mut UIStateGuard,
 ) -> Result<bool> {
-    // handle user's input that updates the search query
-    let query = match ui.popup {
-        Some(PopupState::Search { ref mut query }) => query,
+    let (uri, position, name) = match ui.popup {
+        Some(PopupState::BookmarkCreate {
+            ref uri,
+            position,
+            ref mut name,
+        }) => (uri, position, name),
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let mut name = name.get_text();
+                if name.is_empty() {
+                    name = crate::utils::format_duration(&chrono::Duration::from_std(position)?);
+                }
+                state
+                    .data
+                    .write()
+                    .user_data
+                    .add_bookmark(uri.clone(), Bookmark { name, position });
+                ui.popup = None;
+                return Ok(true);
+            }
+            k => {
+                if name.input(k).is_some() {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_track_year_filter_popup(
+    key_sequence: &KeySequence,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let years = match ui.popup {
+        Some(PopupState::TrackYearFilter { ref mut years }) => years,
         _ => return Ok(false),
     };
     if key_sequence.keys.len() == 1 {
-        if let Key::None(c) = key_sequence.keys[0] {
-            match c {
-                crossterm::event::KeyCode::Char(c) => {
-                    query.push(c);
-                    ui.current_page_mut().select(0);
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let text = years.get_text();
+                // an empty input clears the filter
+                if text.trim().is_empty() {
+                    ui.track_year_filter = None;
+                } else {
+                    match crate::utils::parse_year_range(&text) {
+                        Some(range) => ui.track_year_filter = Some(range),
+                        None => {
+                            ui.show_toast(format!("{}: {text}", tr("Invalid year range")));
+                            return Ok(true);
+                        }
+                    }
+                }
+                ui.popup = None;
+                ui.current_page_mut().select(0);
+                return Ok(true);
+            }
+            k => {
+                if years.input(k).is_some() {
                     return Ok(true);
                 }
-                crossterm::event::KeyCode::Backspace => {
-                    if !query.is_empty() {
-                        query.pop().unwrap();
-                        ui.current_page_mut().select(0);
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_track_tempo_filter_popup(
+    key_sequence: &KeySequence,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let tempos = match ui.popup {
+        Some(PopupState::TrackTempoFilter { ref mut tempos }) => tempos,
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let text = tempos.get_text();
+                // an empty input clears the filter
+                if text.trim().is_empty() {
+                    ui.track_tempo_filter = None;
+                } else {
+                    match crate::utils::parse_tempo_range(&text) {
+                        Some(range) => ui.track_tempo_filter = Some(range),
+                        None => {
+                            ui.show_toast(format!("{}: {text}", tr("Invalid tempo range")));
+                            return Ok(true);
+                        }
                     }
+                }
+                ui.popup = None;
+                ui.current_page_mut().select(0);
+                return Ok(true);
+            }
+            k => {
+                if tempos.input(k).is_some() {
                     return Ok(true);
                 }
-                _ => {}
             }
         }
     }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_seek_position_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let position = match ui.popup {
+        Some(PopupState::SeekPosition { ref mut position }) => position,
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let text = position.get_text();
+                match crate::utils::parse_position(&text) {
+                    Some(position) => {
+                        client_pub
+                            .send(ClientRequest::Player(PlayerRequest::SeekTrack(position)))?;
+                    }
+                    None => {
+                        ui.show_toast(format!("{}: {text}", tr("Invalid position")));
+                        return Ok(true);
+                    }
+                }
+                ui.popup = None;
+                return Ok(true);
+            }
+            k => {
+                if position.input(k).is_some() {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_track_number_popup(
+    key_sequence: &KeySequence,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let number = match ui.popup {
+        Some(PopupState::TrackNumber { ref mut number }) => number,
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let text = number.get_text();
+                let number = text.trim().parse::<usize>().ok().filter(|&n| n > 0);
+                match number.and_then(|n| track_number_row(n, state, ui)) {
+                    Some(row) => {
+                        ui.popup = None;
+                        if let PageState::Context {
+                            state: Some(ContextPageUIState::Artist { focus, .. }),
+                            ..
+                        } = ui.current_page_mut()
+                        {
+                            *focus = ArtistFocusState::TopTracks;
+                        }
+                        ui.current_page_mut().select(row);
+                        ui.track_selection_moved_at = Some(std::time::Instant::now());
+                    }
+                    None => ui.show_toast(format!("{}: {text}", tr("Invalid track number"))),
+                }
+                return Ok(true);
+            }
+            k => {
+                if number.input(k).is_some() {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Get the row of the context page's track table showing the track of a number,
+/// which is the track's position (starting from 1) in the filtered track table.
+/// A track in a collapsed group is shown by the row of the group's first track.
+fn track_number_row(number: usize, state: &SharedState, ui: &UIStateGuard) -> Option<usize> {
+    let PageState::Context { id: Some(id), .. } = ui.current_page() else {
+        return None;
+    };
+    let data = state.data.read();
+    let tracks = data.caches.context.get(&id.uri())?.tracks();
+    let filtered_tracks = ui.filtered_tracks(tracks);
+    if number > filtered_tracks.len() {
+        return None;
+    }
+
+    // the track table's items are a subsequence of the filtered tracks
+    let items = ui.track_table_items(tracks);
+    let mut row = 0;
+    let mut n_items = 0;
+    for t in filtered_tracks.into_iter().take(number) {
+        if items
+            .get(n_items)
+            .is_some_and(|item| std::ptr::eq(*item, t))
+        {
+            row = n_items;
+            n_items += 1;
+        }
+    }
+    Some(row)
+}
+
+fn handle_key_sequence_for_party_mode_passphrase_popup(
+    key_sequence: &KeySequence,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    let passphrase = match ui.popup {
+        Some(PopupState::PartyModePassphrase { ref mut passphrase }) => passphrase,
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match &key_sequence.keys[0] {
+            Key::None(crossterm::event::KeyCode::Enter) => {
+                let text = passphrase.get_text();
+                match ui.party_mode_passphrase.as_deref() {
+                    Some(expected) if expected == text => {
+                        ui.party_mode_passphrase = None;
+                        ui.show_toast(tr("Party mode disabled"));
+                    }
+                    Some(_) => {
+                        ui.popup = Some(PopupState::PartyModePassphrase {
+                            passphrase: LineInput::default(),
+                        });
+                        ui.show_toast(tr("Wrong passphrase"));
+                        return Ok(true);
+                    }
+                    // an empty passphrase is ignored as it would disable the party mode with no effort
+                    None if text.is_empty() => return Ok(true),
+                    None => {
+                        ui.party_mode_passphrase = Some(text);
+                        ui.show_toast(tr("Party mode enabled"));
+                    }
+                }
+                ui.popup = None;
+                return Ok(true);
+            }
+            k => {
+                if passphrase.input(k).is_some() {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+fn handle_key_sequence_for_search_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<bool> {
+    // handle user's input that updates the search query
+    let query = match ui.popup {
+        Some(PopupState::Search { ref mut query }) => query,
+        _ => return Ok(false),
+    };
+    if key_sequence.keys.len() == 1 {
+        match query.input(&key_sequence.keys[0]) {
+            Some(InputEffect::TextChanged) => {
+                ui.current_page_mut().select(0);
+                return Ok(true);
+            }
+            Some(_) => return Ok(true),
+            None => {}
+        }
+    }
 
     // key sequence not handle by the popup should be moved to the current page's event handler
     page::handle_key_sequence_for_page(key_sequence, client_pub, state, ui)
@@ -403,12 +1057,6 @@ fn handle_command_for_list_popup(
     Ok(true)
 }
 
-fn execute_copy_command(text: String) -> Result<()> {
-    CLIPBOARD_PROVIDER
-        .get_or_init(|| get_clipboard_provider())
-        .set_contents(text)
-}
-
 fn handle_key_sequence_for_action_list_popup(
     n_actions: usize,
     key_sequence: &KeySequence,
@@ -461,6 +1109,9 @@ fn handle_item_action(
         Some(PopupState::ActionList(ref item, ..)) => item.clone(),
         _ => return Ok(()),
     };
+    if item.is_action_disabled_in_party_mode(n) && ui.reject_in_party_mode() {
+        return Ok(());
+    }
 
     match item {
         ActionListItem::Track(track, actions) => match actions[n] {
@@ -488,6 +1139,10 @@ fn handle_item_action(
                 client_pub.send(ClientRequest::AddTrackToQueue(track.id))?;
                 ui.popup = None;
             }
+            TrackAction::PlayNext => {
+                client_pub.send(ClientRequest::PlayTrackNext(track.id))?;
+                ui.popup = None;
+            }
             TrackAction::CopyTrackLink => {
                 let track_url = format!("https://open.spotify.com/track/{}", track.id.id());
                 execute_copy_command(track_url)?;
@@ -496,7 +1151,7 @@ fn handle_item_action(
             TrackAction::AddToPlaylist => {
                 client_pub.send(ClientRequest::GetUserPlaylists)?;
                 ui.popup = Some(PopupState::UserPlaylistList(
-                    PlaylistPopupAction::AddTrack(track.id),
+                    PlaylistPopupAction::AddTrack(Box::new(track)),
                     new_list_state(),
                 ));
             }
@@ -532,6 +1187,10 @@ fn handle_item_action(
                     ));
                 }
             }
+            TrackAction::ShowCredits => {
+                client_pub.send(ClientRequest::GetTrackCredits(track.id.clone()))?;
+                ui.popup = Some(PopupState::TrackCredits(track));
+            }
             TrackAction::DeleteFromLikedTracks => {
                 client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Track(track.id)))?;
                 ui.popup = None;
@@ -643,6 +1302,32 @@ fn handle_item_action(
                 ui.popup = None;
             }
         },
+        ActionListItem::Episode(episode, actions) => match actions[n] {
+            EpisodeAction::AddToQueue => {
+                client_pub.send(ClientRequest::AddEpisodeToQueue(episode.id))?;
+                ui.popup = None;
+            }
+            EpisodeAction::CopyEpisodeLink => {
+                let episode_url = format!("https://open.spotify.com/episode/{}", episode.id.id());
+                execute_copy_command(episode_url)?;
+                ui.popup = None;
+            }
+            EpisodeAction::DeleteFromSavedEpisodes => {
+                client_pub.send(ClientRequest::DeleteSavedEpisode(episode.id))?;
+                ui.popup = None;
+            }
+            EpisodeAction::GoToShow => {
+                if let Some(show_id) = episode.show_id {
+                    ui.new_page(PageState::Show {
+                        show_id: show_id.clone(),
+                        show_name: episode.show_name,
+                        episode_table: new_table_state(),
+                    });
+                    client_pub.send(ClientRequest::GetShowEpisodes(show_id))?;
+                }
+                ui.popup = None;
+            }
+        },
     }
 
     Ok(())
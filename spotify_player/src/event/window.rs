@@ -34,6 +34,7 @@ pub fn handle_command_for_focused_context_window(
             Command::SortTrackByArtists => Some(TrackOrder::Artists),
             Command::SortTrackByAddedDate => Some(TrackOrder::AddedAt),
             Command::SortTrackByDuration => Some(TrackOrder::Duration),
+            Command::SortTrackByPopularity => Some(TrackOrder::Popularity),
             _ => None,
         };
 
@@ -43,6 +44,10 @@ pub fn handle_command_for_focused_context_window(
             if let Some(tracks) = data.context_tracks(context_id) {
                 tracks.sort_by(|x, y| order.compare(x, y));
             }
+            data.update_context_view(&context_id.uri(), |view| {
+                view.track_order = Some(order);
+                view.reversed = false;
+            });
             return Ok(true);
         }
         // reverse ordering command
@@ -51,6 +56,26 @@ pub fn handle_command_for_focused_context_window(
             if let Some(tracks) = data.context_tracks(context_id) {
                 tracks.reverse();
             }
+            data.update_context_view(&context_id.uri(), |view| {
+                view.reversed = !view.reversed;
+            });
+            return Ok(true);
+        }
+        // column toggling commands
+        let toggle_column: Option<fn(&mut TrackTableColumns) -> &mut bool> = match command {
+            Command::ToggleAlbumColumn => Some(|c| &mut c.album),
+            Command::ToggleYearColumn => Some(|c| &mut c.year),
+            Command::TogglePopularityColumn => Some(|c| &mut c.popularity),
+            _ => None,
+        };
+        if let Some(toggle_column) = toggle_column {
+            state
+                .data
+                .write()
+                .update_context_view(&context_id.uri(), |view| {
+                    let column = toggle_column(&mut view.columns);
+                    *column = !*column;
+                });
             return Ok(true);
         }
     }
@@ -76,13 +101,14 @@ pub fn handle_command_for_focused_context_window(
                 match focus_state {
                     ArtistFocusState::Albums => handle_command_for_album_list_window(
                         command,
+                        client_pub,
                         ui.search_filtered_items(albums),
                         &data,
                         ui,
-                        client_pub,
                     ),
                     ArtistFocusState::RelatedArtists => handle_command_for_artist_list_window(
                         command,
+                        client_pub,
                         ui.search_filtered_items(related_artists),
                         &data,
                         ui,
@@ -126,7 +152,23 @@ fn handle_playlist_modify_command(
     data: &DataReadGuard,
     ui: &mut UIStateGuard,
 ) -> Result<bool> {
+    // the items' positions in the track table don't match their positions in the playlist
+    // if a group of the track table is collapsed
+    let is_collapsed = ui.is_track_table_grouped && !ui.collapsed_track_groups.is_empty();
     match command {
+        Command::MovePlaylistItemUp | Command::MovePlaylistItemDown if is_collapsed => {
+            return Ok(true);
+        }
+        // or if the track table is narrowed by a track filter
+        Command::MovePlaylistItemUp | Command::MovePlaylistItemDown if ui.has_track_filters() => {
+            ui.show_toast(tr("Clear the track filters to reorder the playlist"));
+            return Ok(true);
+        }
+        Command::MovePlaylistItemUp | Command::MovePlaylistItemDown
+            if ui.reject_in_party_mode() =>
+        {
+            return Ok(true);
+        }
         Command::MovePlaylistItemUp => {
             if id > 0 {
                 client_pub.send(ClientRequest::ReorderPlaylistItems {
@@ -168,6 +210,50 @@ fn handle_playlist_modify_command(
     Ok(false)
 }
 
+/// Add a track to the liked tracks, or remove it from the liked tracks if it's already liked
+fn toggle_liked_track(
+    track: &Track,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    data: &DataReadGuard,
+) -> Result<()> {
+    if data.user_data.is_liked_track(track) {
+        ui.show_toast(format!("{} {}", tr("Unliked"), track.name));
+        client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Track(
+            track.id.clone(),
+        )))?;
+    } else {
+        ui.show_toast(format!("{} {}", tr("Liked"), track.name));
+        client_pub.send(ClientRequest::AddToLibrary(Item::Track(track.clone())))?;
+    }
+    Ok(())
+}
+
+/// Open the radio page of a track or an artist, requesting the item's recommendation tracks
+fn go_to_radio_page(
+    uri: String,
+    name: String,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    ui.new_radio_page(&uri);
+    client_pub.send(ClientRequest::GetRadioTracks {
+        seed_uri: uri,
+        seed_name: name,
+    })?;
+    Ok(())
+}
+
+/// Map a command on a track to the command to run in the kiosk mode (if enabled),
+/// in which choosing a track requests it by adding it to the queue instead of playing it
+fn kiosk_track_command(command: Command) -> Command {
+    if command == Command::ChooseSelected && config::get_config().app_config.kiosk_mode {
+        Command::AddSelectedItemToQueue
+    } else {
+        command
+    }
+}
+
 fn handle_command_for_track_table_window(
     command: Command,
     client_pub: &flume::Sender<ClientRequest>,
@@ -177,10 +263,11 @@ fn handle_command_for_track_table_window(
     ui: &mut UIStateGuard,
 ) -> Result<bool> {
     let id = ui.current_page_mut().selected().unwrap_or_default();
-    let filtered_tracks = ui.search_filtered_items(tracks);
+    let filtered_tracks = ui.track_table_items(tracks);
     if id >= filtered_tracks.len() {
         return Ok(false);
     }
+    let command = kiosk_track_command(command);
 
     if let Some(ContextId::Playlist(ref playlist_id)) = context_id {
         let modifiable = data
@@ -204,23 +291,43 @@ fn handle_command_for_track_table_window(
     }
 
     if handle_navigation_command(command, ui.current_page_mut(), id, filtered_tracks.len()) {
+        ui.track_selection_moved_at = Some(std::time::Instant::now());
         return Ok(true);
     }
 
     match command {
         Command::PlayRandom | Command::ChooseSelected => {
+            let playable_tracks = tracks
+                .iter()
+                .filter(|t| t.is_playable())
+                .collect::<Vec<_>>();
             let uri = if command == Command::PlayRandom {
-                tracks[rand::thread_rng().gen_range(0..tracks.len())]
+                if playable_tracks.is_empty() {
+                    return Ok(true);
+                }
+                playable_tracks[rand::thread_rng().gen_range(0..playable_tracks.len())]
                     .id
                     .uri()
             } else {
-                filtered_tracks[id].id.uri()
+                let track = filtered_tracks[id];
+                if let Some(ref reason) = track.unavailable_reason {
+                    ui.show_toast(format!("{}: {}", track.name, tr(reason)));
+                    return Ok(true);
+                }
+                track.id.uri()
             };
 
             let base_playback = if let Some(context_id) = context_id {
                 Playback::Context(context_id, None)
             } else {
-                Playback::URIs(tracks.iter().map(|t| t.id.clone_static()).collect(), None)
+                // unplayable tracks are excluded from a `URIs` playback
+                Playback::URIs(
+                    playable_tracks
+                        .iter()
+                        .map(|t| t.id.clone_static())
+                        .collect(),
+                    None,
+                )
             };
 
             client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
@@ -232,15 +339,95 @@ fn handle_command_for_track_table_window(
         Command::ShowActionsOnSelectedItem => {
             let actions = command::construct_track_actions(filtered_tracks[id], data);
             ui.popup = Some(PopupState::ActionList(
-                ActionListItem::Track(tracks[id].clone(), actions),
+                ActionListItem::Track(filtered_tracks[id].clone(), actions),
                 new_list_state(),
             ));
         }
+        Command::GoToSelectedItemRadio => {
+            go_to_radio_page(
+                filtered_tracks[id].id.uri(),
+                filtered_tracks[id].name.clone(),
+                client_pub,
+                ui,
+            )?;
+        }
         Command::AddSelectedItemToQueue => {
             client_pub.send(ClientRequest::AddTrackToQueue(
                 filtered_tracks[id].id.clone(),
             ))?;
         }
+        Command::PlaySelectedTrackNext => {
+            client_pub.send(ClientRequest::PlayTrackNext(filtered_tracks[id].id.clone()))?;
+        }
+        Command::ToggleLikeSelectedTrack => {
+            if !ui.reject_in_party_mode() {
+                toggle_liked_track(filtered_tracks[id], client_pub, ui, data)?;
+            }
+        }
+        Command::ToggleTrackGroupCollapse if ui.is_track_table_grouped => {
+            let groups = group_tracks(&ui.filtered_tracks(tracks));
+            let group = groups
+                .into_iter()
+                .find(|g| g.tracks.iter().any(|t| t.id == filtered_tracks[id].id));
+            if let Some(group) = group {
+                if !ui.collapsed_track_groups.remove(&group.name) {
+                    ui.collapsed_track_groups.insert(group.name);
+                }
+                // select the group's first track, which represents the group when collapsed
+                let first_track_id = &group.tracks[0].id;
+                if let Some(pos) = ui
+                    .track_table_items(tracks)
+                    .iter()
+                    .position(|t| t.id == *first_track_id)
+                {
+                    ui.current_page_mut().select(pos);
+                }
+            }
+            ui.visual_selection_anchor = None;
+        }
+        Command::ToggleVisualSelection => {
+            ui.visual_selection_anchor = match ui.visual_selection_anchor {
+                Some(_) => None,
+                None => Some(id),
+            };
+        }
+        Command::ClosePopup if ui.visual_selection_anchor.is_some() => {
+            ui.visual_selection_anchor = None;
+        }
+        Command::CopySelectedTrackUris => {
+            // copy the selected track's URI if the visual selection is not active
+            let range = ui.visual_selection_range().unwrap_or(id..=id);
+            let uris = filtered_tracks
+                .iter()
+                .skip(*range.start())
+                .take(range.end() - range.start() + 1)
+                .map(|t| t.id.uri())
+                .collect::<Vec<_>>();
+            execute_copy_command(uris.join("\n"))?;
+
+            ui.visual_selection_anchor = None;
+            ui.show_toast(format!("{}: {}", tr("Copied track URIs"), uris.len()));
+        }
+        Command::AddSelectedTracksToPlaylist => {
+            if ui.reject_in_party_mode() {
+                return Ok(true);
+            }
+            // add the selected track if the visual selection is not active
+            let range = ui.visual_selection_range().unwrap_or(id..=id);
+            let tracks = filtered_tracks
+                .iter()
+                .skip(*range.start())
+                .take(range.end() - range.start() + 1)
+                .map(|&t| t.clone())
+                .collect::<Vec<_>>();
+
+            ui.visual_selection_anchor = None;
+            client_pub.send(ClientRequest::GetUserPlaylists)?;
+            ui.popup = Some(PopupState::UserPlaylistList(
+                PlaylistPopupAction::AddTracks(tracks),
+                new_list_state(),
+            ));
+        }
         _ => return Ok(false),
     }
     Ok(true)
@@ -257,6 +444,7 @@ pub fn handle_command_for_track_list_window(
     if id >= tracks.len() {
         return Ok(false);
     }
+    let command = kiosk_track_command(command);
 
     if handle_navigation_command(command, ui.current_page_mut(), id, tracks.len()) {
         return Ok(true);
@@ -268,6 +456,10 @@ pub fn handle_command_for_track_list_window(
             // This is different from the track table, which handles
             // `ChooseSelected` by starting a `URIs` playback
             // containing all the tracks in the table.
+            if let Some(ref reason) = tracks[id].unavailable_reason {
+                ui.show_toast(format!("{}: {}", tracks[id].name, tr(reason)));
+                return Ok(true);
+            }
             client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
                 Playback::URIs(vec![tracks[id].id.clone()], None),
                 None,
@@ -280,9 +472,20 @@ pub fn handle_command_for_track_list_window(
                 new_list_state(),
             ));
         }
+        Command::GoToSelectedItemRadio => {
+            go_to_radio_page(tracks[id].id.uri(), tracks[id].name.clone(), client_pub, ui)?;
+        }
         Command::AddSelectedItemToQueue => {
             client_pub.send(ClientRequest::AddTrackToQueue(tracks[id].id.clone()))?;
         }
+        Command::PlaySelectedTrackNext => {
+            client_pub.send(ClientRequest::PlayTrackNext(tracks[id].id.clone()))?;
+        }
+        Command::ToggleLikeSelectedTrack => {
+            if !ui.reject_in_party_mode() {
+                toggle_liked_track(tracks[id], client_pub, ui, data)?;
+            }
+        }
         _ => return Ok(false),
     }
     Ok(true)
@@ -290,6 +493,7 @@ pub fn handle_command_for_track_list_window(
 
 pub fn handle_command_for_artist_list_window(
     command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
     artists: Vec<&Artist>,
     data: &DataReadGuard,
     ui: &mut UIStateGuard,
@@ -318,6 +522,14 @@ pub fn handle_command_for_artist_list_window(
                 new_list_state(),
             ));
         }
+        Command::GoToSelectedItemRadio => {
+            go_to_radio_page(
+                artists[id].id.uri(),
+                artists[id].name.clone(),
+                client_pub,
+                ui,
+            )?;
+        }
         _ => return Ok(false),
     }
     Ok(true)
@@ -325,10 +537,10 @@ pub fn handle_command_for_artist_list_window(
 
 pub fn handle_command_for_album_list_window(
     command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
     albums: Vec<&Album>,
     data: &DataReadGuard,
     ui: &mut UIStateGuard,
-    client_pub: &flume::Sender<ClientRequest>,
 ) -> Result<bool> {
     let id = ui.current_page_mut().selected().unwrap_or_default();
     if id >= albums.len() {
@@ -0,0 +1,150 @@
+use anyhow::{Context, Result};
+use rspotify::{
+    model::{SearchResult, SearchType, TrackId},
+    prelude::Id,
+};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::{
+    client::{Client, ClientRequest},
+    config, http,
+    i18n::tr,
+    state::{SharedState, Track},
+};
+
+/// the maximum number of guest requests waiting for the user's moderation
+const MAX_PENDING_GUEST_REQUESTS: usize = 50;
+
+/// Start an HTTP server handling guests' song requests.
+///
+/// A guest requests a song with either `GET /request?q=<query>` or `POST /request`
+/// with the query as the request's body. The query can be a search query or a track's link (URI).
+pub async fn start_guest_request_server(
+    client: Client,
+    state: SharedState,
+    client_pub: flume::Sender<ClientRequest>,
+    listener: TcpListener,
+) {
+    loop {
+        match listener.accept().await {
+            Err(err) => tracing::warn!("Failed to accept a guest request connection: {err:#}"),
+            Ok((stream, addr)) => {
+                tokio::task::spawn({
+                    let client = client.clone();
+                    let state = state.clone();
+                    let client_pub = client_pub.clone();
+                    async move {
+                        if let Err(err) =
+                            handle_connection(&client, &state, &client_pub, stream).await
+                        {
+                            tracing::warn!("Failed to handle a guest request from {addr}: {err:#}");
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+async fn handle_connection(
+    client: &Client,
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+    mut stream: TcpStream,
+) -> Result<()> {
+    let (status, body) = match read_request_query(&mut stream).await {
+        Err(err) => ("400 Bad Request", format!("Bad request: {err:#}")),
+        Ok(query) => match handle_guest_request(client, state, client_pub, &query).await {
+            Ok(Some(track)) => (
+                "200 OK",
+                format!("Requested {} by {}", track.name, track.artists_info()),
+            ),
+            Ok(None) => ("404 Not Found", format!("No track found for \"{query}\"")),
+            Err(err) => (
+                "500 Internal Server Error",
+                format!("Failed to handle the request: {err:#}"),
+            ),
+        },
+    };
+
+    http::write_response(&mut stream, status, "text/plain; charset=utf-8", &body).await
+}
+
+/// Read a guest's HTTP request and return its (non-empty) query
+async fn read_request_query(stream: &mut TcpStream) -> Result<String> {
+    let request = http::read_request(stream).await?;
+    anyhow::ensure!(
+        request.url.path() == "/request",
+        "unknown path: {}",
+        request.url.path()
+    );
+
+    let query = match request.method.as_str() {
+        "GET" => request
+            .url
+            .query_pairs()
+            .find(|(key, _)| key == "q")
+            .map(|(_, value)| value.into_owned())
+            .unwrap_or_default(),
+        "POST" => String::from_utf8(request.body).context("non UTF-8 request body")?,
+        method => anyhow::bail!("unsupported method: {method}"),
+    };
+
+    let query = query.trim();
+    anyhow::ensure!(!query.is_empty(), "empty query");
+    Ok(query.to_string())
+}
+
+/// Handle a guest's song request, returning the requested track (if found).
+///
+/// The requested track is added to the guest requests waiting for the user's moderation,
+/// or directly to the queue if the application can't moderate requests (daemon or kiosk mode).
+async fn handle_guest_request(
+    client: &Client,
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+    query: &str,
+) -> Result<Option<Track>> {
+    let Some(track) = resolve_track(client, query).await? else {
+        return Ok(None);
+    };
+    tracing::info!("Received a guest request for track (id={})", track.id.id());
+
+    if state.is_daemon || config::get_config().app_config.kiosk_mode {
+        client_pub.send(ClientRequest::AddTrackToQueue(track.id.clone()))?;
+    } else {
+        let mut ui = state.ui.lock();
+        anyhow::ensure!(
+            ui.guest_requests.len() < MAX_PENDING_GUEST_REQUESTS,
+            "too many guest requests waiting for moderation"
+        );
+        ui.show_toast(format!("{}: {}", tr("New guest request"), track.name));
+        ui.guest_requests.push(track.clone());
+    }
+    Ok(Some(track))
+}
+
+/// Resolve a guest's query into a track, either by the track's link (URI)
+/// or by the first track of the query's search results
+async fn resolve_track(client: &Client, query: &str) -> Result<Option<Track>> {
+    let re = regex::Regex::new(r"https://open.spotify.com/track/(?P<id>[[:alnum:]]*)")?;
+    let id = match re.captures(query) {
+        Some(cap) => Some(TrackId::from_id(
+            cap.name("id").expect("valid capture").as_str(),
+        )?),
+        None => TrackId::from_uri(query).ok(),
+    };
+    if let Some(id) = id {
+        return client.track(id).await.map(Some);
+    }
+
+    match client
+        .search_specific_type(query, SearchType::Track)
+        .await?
+    {
+        SearchResult::Tracks(page) => {
+            Ok(page.items.into_iter().find_map(Track::try_from_full_track))
+        }
+        _ => anyhow::bail!("expect a track search result"),
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// the maximum size (in bytes) of an HTTP request, including its headers
+const MAX_REQUEST_SIZE: usize = 8192;
+
+/// the longest duration to wait for a client to send its whole HTTP request
+const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// A minimal HTTP request, served by the application's HTTP endpoints
+pub struct Request {
+    pub method: String,
+    pub url: reqwest::Url,
+    pub body: Vec<u8>,
+}
+
+/// Read an HTTP request from a connection
+pub async fn read_request(stream: &mut TcpStream) -> Result<Request> {
+    tokio::time::timeout(READ_TIMEOUT, read_request_inner(stream))
+        .await
+        .context("timed out reading the request")?
+}
+
+async fn read_request_inner(stream: &mut TcpStream) -> Result<Request> {
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+
+    // read the request until the end of its headers
+    let header_end = loop {
+        let n_bytes = stream.read(&mut chunk).await?;
+        anyhow::ensure!(
+            n_bytes > 0,
+            "connection closed before the end of the request"
+        );
+        buf.extend_from_slice(&chunk[..n_bytes]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        anyhow::ensure!(
+            buf.len() <= MAX_REQUEST_SIZE,
+            "the request exceeds the size limit ({MAX_REQUEST_SIZE} bytes)"
+        );
+    };
+    let headers = std::str::from_utf8(&buf[..header_end]).context("non UTF-8 request headers")?;
+    let mut lines = headers.lines();
+
+    let request_line = lines.next().unwrap_or_default();
+    let (method, target) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, target, _] => (method.to_string(), target),
+        _ => anyhow::bail!("invalid request line: {request_line}"),
+    };
+    let url = reqwest::Url::parse(&format!("http://localhost{target}"))
+        .with_context(|| format!("invalid request target: {target}"))?;
+
+    let content_length = lines
+        .filter_map(|l| l.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .map(|(_, value)| value.trim().parse::<usize>())
+        .transpose()
+        .context("invalid content length")?
+        .unwrap_or_default();
+    // the content length is checked before computing the body's end to avoid overflowing
+    anyhow::ensure!(
+        content_length <= MAX_REQUEST_SIZE - header_end.min(MAX_REQUEST_SIZE),
+        "the request exceeds the size limit ({MAX_REQUEST_SIZE} bytes)"
+    );
+    let body_end = header_end + content_length;
+
+    // read the remaining of the request's body
+    while buf.len() < body_end {
+        let n_bytes = stream.read(&mut chunk).await?;
+        anyhow::ensure!(
+            n_bytes > 0,
+            "connection closed before the end of the request's body"
+        );
+        buf.extend_from_slice(&chunk[..n_bytes]);
+    }
+    buf.truncate(body_end);
+    let body = buf.split_off(header_end);
+
+    Ok(Request { method, url, body })
+}
+
+/// Write a plain text HTTP response to a connection
+pub async fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    Ok(())
+}
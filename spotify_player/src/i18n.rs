@@ -0,0 +1,670 @@
+//! Localization of the application's UI labels and help text.
+//!
+//! UI labels are translated by looking up their English text in the translation table
+//! of the configured locale. A label without a translation is displayed in English.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::config::{self, Locale};
+
+/// translations of the UI labels in the configured locale, keyed by the English labels
+static TRANSLATIONS: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    let translations: &[(&str, &str)] = match config::get_config().app_config.locale {
+        Locale::En => &[],
+        Locale::Es => ES_TRANSLATIONS,
+        Locale::Fr => FR_TRANSLATIONS,
+    };
+    translations.iter().copied().collect()
+});
+
+/// Translate a UI label into the configured locale
+pub fn tr(text: &str) -> &str {
+    TRANSLATIONS.get(text).copied().unwrap_or(text)
+}
+
+const ES_TRANSLATIONS: &[(&str, &str)] = &[
+    // windows and popups
+    ("Playback", "Reproducción"),
+    ("Search", "Buscar"),
+    ("Tracks", "Canciones"),
+    ("Albums", "Álbumes"),
+    ("Artists", "Artistas"),
+    ("Playlists", "Listas de reproducción"),
+    ("Categories", "Categorías"),
+    ("Lyric", "Letra"),
+    ("Commands", "Comandos"),
+    ("Queue", "Cola"),
+    ("Added to the queue", "Añadido a la cola"),
+    ("Related Artists", "Artistas relacionados"),
+    ("Shortcuts", "Atajos"),
+    ("Devices", "Dispositivos"),
+    ("Themes", "Temas"),
+    ("Genres", "Géneros"),
+    ("User Playlists", "Listas del usuario"),
+    ("User Followed Artists", "Artistas seguidos"),
+    ("User Saved Albums", "Álbumes guardados"),
+    ("Saved Episodes", "Episodios guardados"),
+    ("Chapters", "Capítulos"),
+    ("Details", "Detalles"),
+    ("Enter Name for New Playlist:", "Nombre de la nueva lista:"),
+    (
+        "Enter Description for New Playlist:",
+        "Descripción de la nueva lista:",
+    ),
+    ("Enter Name for New Bookmark:", "Nombre del nuevo marcador:"),
+    (
+        "Enter Release Years (e.g. 1995, 1990s, 1990-1999):",
+        "Años de publicación (p. ej. 1995, 1990s, 1990-1999):",
+    ),
+    (
+        "Enter Tempos in BPM (e.g. 124, 120-128):",
+        "Tempos en BPM (p. ej. 124, 120-128):",
+    ),
+    (
+        "Enter Passphrase to Enable Party Mode:",
+        "Contraseña para activar el modo fiesta:",
+    ),
+    (
+        "Enter Passphrase to Disable Party Mode:",
+        "Contraseña para desactivar el modo fiesta:",
+    ),
+    ("Actions on", "Acciones sobre"),
+    // contexts
+    ("Current Playing", "Reproduciendo ahora"),
+    ("Playlist", "Lista de reproducción"),
+    ("Album", "Álbum"),
+    ("Artist", "Artista"),
+    ("Top Tracks", "Canciones más escuchadas"),
+    ("Liked Tracks", "Canciones que te gustan"),
+    ("Recently Played Tracks", "Reproducidas recientemente"),
+    ("Recently Added Tracks", "Añadidas recientemente"),
+    ("Recommendations", "Recomendaciones"),
+    ("Genre", "Género"),
+    ("songs", "canciones"),
+    ("followers", "seguidores"),
+    ("popularity", "popularidad"),
+    ("genres", "géneros"),
+    // table headers
+    ("Command", "Comando"),
+    ("Description", "Descripción"),
+    ("Title", "Título"),
+    ("Played", "Reproducida"),
+    ("Duration", "Duración"),
+    ("Show", "Programa"),
+    ("Released", "Publicado"),
+    ("Resume", "Reanudar"),
+    // item details
+    ("Explicit", "Explícito"),
+    ("Unavailable", "No disponible"),
+    ("Followers", "Seguidores"),
+    ("Popularity", "Popularidad"),
+    ("Owner", "Propietario"),
+    ("Collaborative", "Colaborativa"),
+    ("No item selected", "Ningún elemento seleccionado"),
+    ("Credits of", "Créditos de"),
+    ("Main Artist", "Artista principal"),
+    ("Featured Artists", "Artistas invitados"),
+    ("Disc Number", "Número de disco"),
+    ("Disc", "Disco"),
+    ("Track Number", "Número de canción"),
+    ("Available Markets", "Países disponibles"),
+    // playback
+    ("Next", "Siguiente"),
+    ("repeat", "repetir"),
+    ("shuffle", "aleatorio"),
+    ("volume", "volumen"),
+    ("device", "dispositivo"),
+    ("muted", "silenciado"),
+    // messages
+    (
+        "Cannot determine the current page's context",
+        "No se puede determinar el contexto de la página actual",
+    ),
+    ("Failed to load the context", "No se pudo cargar el contexto"),
+    ("press `{key}` to retry", "pulsa `{key}` para reintentar"),
+    ("Lyric not found", "Letra no encontrada"),
+    ("Failed to get the lyric", "No se pudo obtener la letra"),
+    ("No playback found.", "No se encontró ninguna reproducción."),
+    (
+        "Please make sure there is a running Spotify device and try to connect to one using the `SwitchDevice` command.",
+        "Asegúrate de que haya un dispositivo de Spotify en ejecución e intenta conectarte a uno con el comando `SwitchDevice`.",
+    ),
+    (
+        "You may also need to set up Spotify Connect to see available devices as in",
+        "También puede que necesites configurar Spotify Connect para ver los dispositivos disponibles, como se indica en",
+    ),
+    ("Liked", "Te gusta"),
+    ("Unliked", "Ya no te gusta"),
+    ("Disliked", "Bloqueada"),
+    ("Skipped", "Omitida"),
+    ("Playing", "Reproduciendo"),
+    ("Paused: audio output changed", "En pausa: la salida de audio cambió"),
+    ("not available in your market", "no disponible en tu país"),
+    ("not available for your product", "no disponible para tu suscripción"),
+    ("explicit content restricted", "contenido explícito restringido"),
+    ("unavailable", "no disponible"),
+    ("Paused", "En pausa"),
+    ("by", "de"),
+    ("Bookmark", "Marcador"),
+    ("No next bookmark", "No hay más marcadores"),
+    ("Copied track URIs", "URIs de canciones copiadas"),
+    ("Failed to update the playlist", "No se pudo actualizar la lista"),
+    ("Invalid year range", "Rango de años no válido"),
+    ("Invalid tempo range", "Rango de tempos no válido"),
+    ("Invalid position", "Posición no válida"),
+    ("Invalid track number", "Número de canción no válido"),
+    ("Authentication required", "Autenticación requerida"),
+    ("Authenticated", "Autenticado"),
+    ("Failed to authenticate", "Error de autenticación"),
+    (
+        "Auto-mix is only available on the integrated device",
+        "El auto-mix solo está disponible en el dispositivo integrado",
+    ),
+    ("No playing track to mix from", "No hay ninguna canción en reproducción"),
+    ("No episode is playing", "No se está reproduciendo ningún episodio"),
+    ("No audiobook is playing", "No se está reproduciendo ningún audiolibro"),
+    ("Auto-mix enabled", "Auto-mix activado"),
+    ("Auto-mix disabled", "Auto-mix desactivado"),
+    ("Disabled in party mode", "Desactivado en el modo fiesta"),
+    ("Party mode enabled", "Modo fiesta activado"),
+    ("Party mode disabled", "Modo fiesta desactivado"),
+    ("Wrong passphrase", "Contraseña incorrecta"),
+    ("Not available in kiosk mode", "No disponible en el modo quiosco"),
+    ("New guest request", "Nueva petición de un invitado"),
+    ("Guest Requests", "Peticiones de invitados"),
+    ("New Releases", "Novedades"),
+    ("Library Cleanup", "Limpieza de la biblioteca"),
+    ("No skipped tracks", "No hay canciones omitidas"),
+    ("No other context to swap to", "No hay otro contexto al que cambiar"),
+    ("Saving", "Guardando"),
+    ("Added", "Añadidas"),
+    ("tracks to the playlist", "canciones a la lista"),
+    ("Only in", "Solo en"),
+    ("In both", "En ambas"),
+    (
+        "Open a playlist to compare it with another playlist",
+        "Abre una lista para compararla con otra lista",
+    ),
+    (
+        "The tracks are already in both playlists",
+        "Las canciones ya están en ambas listas",
+    ),
+    ("No missing tracks", "No faltan canciones"),
+    ("No recommendations for the genre", "No hay recomendaciones para el género"),
+    ("No tracks to merge", "No hay canciones para combinar"),
+    ("Year", "Año"),
+    ("filtered", "filtrado"),
+    (
+        "Clear the track filters to reorder the playlist",
+        "Quita los filtros de canciones para reordenar la lista",
+    ),
+    ("Archived the playlist", "Lista archivada"),
+    ("Open a playlist to archive it", "Abre una lista para archivarla"),
+    (
+        "Open the device list to set the default device",
+        "Abre la lista de dispositivos para elegir el dispositivo predeterminado",
+    ),
+    ("Cannot modify the playlist", "No se puede modificar la lista"),
+    ("Confirm removing", "Confirmar la eliminación de"),
+    ("items (choose again to confirm)", "elementos (elige de nuevo para confirmar)"),
+    ("Remove", "Eliminar"),
+    ("marked items", "elementos marcados"),
+    ("Track", "Canción"),
+    ("No marked items", "No hay elementos marcados"),
+    ("Removing items from the library", "Eliminando elementos de la biblioteca"),
+    ("items from the library", "elementos de la biblioteca"),
+    ("failed", "fallidos"),
+    ("albums to the library", "álbumes en la biblioteca"),
+    ("Most Skipped Tracks", "Canciones más omitidas"),
+    ("Remove all from the playlist", "Eliminar todas de la lista"),
+    ("Add all to the blocklist", "Añadir todas a la lista de bloqueo"),
+    ("skips", "omisiones"),
+    ("Removed", "Eliminadas"),
+    ("tracks from the playlist", "canciones de la lista"),
+    ("Blocked", "Bloqueadas"),
+    ("tracks", "canciones"),
+    ("min left", "min restantes"),
+    (
+        "No chapters found. The playing show may not be an audiobook.",
+        "No se encontraron capítulos. Es posible que el programa actual no sea un audiolibro.",
+    ),
+    ("Featured Playlists", "Listas destacadas"),
+    ("Enter Position to Seek to (e.g. 90, 1:30, 1:02:30):", "Posición a la que ir (p. ej. 90, 1:30, 1:02:30):"),
+    ("Enter Track Number to Go to:", "Número de canción al que ir:"),
+    ("Authentication Required, Enter Username:", "Autenticación requerida, nombre de usuario:"),
+    ("Enter Password:", "Contraseña:"),
+    ("Loading...", "Cargando..."),
+    // command descriptions in the command help page
+    ("do nothing", "no hacer nada"),
+    ("next track", "siguiente canción"),
+    ("previous track (or the start of the current track)", "canción anterior (o el inicio de la canción actual)"),
+    ("skip to the next chapter of the playing audiobook", "pasar al siguiente capítulo del audiolibro en reproducción"),
+    ("add a named bookmark at the current playback position", "añadir un marcador con nombre en la posición actual de la reproducción"),
+    ("jump to the next bookmark of the current track/episode", "saltar al siguiente marcador de la canción/episodio actual"),
+    ("resume/pause based on the current playback", "reanudar/pausar según la reproducción actual"),
+    ("play a random track in the current context", "reproducir una canción aleatoria del contexto actual"),
+    ("shuffle-play liked tracks (or saved albums if focused) in the library", "reproducir en aleatorio las canciones que te gustan (o los álbumes guardados si están enfocados) de la biblioteca"),
+    ("cycle the repeat mode", "cambiar el modo de repetición"),
+    ("toggle fake track repeat mode", "activar/desactivar el modo de repetición simulada de la canción"),
+    ("toggle the shuffle mode", "activar/desactivar el modo aleatorio"),
+    ("increase playback volume by 5% (configurable)", "subir el volumen un 5% (configurable)"),
+    ("decrease playback volume by 5% (configurable)", "bajar el volumen un 5% (configurable)"),
+    ("toggle playback volume between 0% and previous level", "alternar el volumen entre 0% y el nivel anterior"),
+    ("seek forward by 5s (configurable)", "avanzar 5s (configurable)"),
+    ("seek backward by 5s (configurable)", "retroceder 5s (configurable)"),
+    ("seek to a position (mm:ss) of the current playback", "ir a una posición (mm:ss) de la reproducción actual"),
+    ("like the current track and continue playing", "marcar como favorita la canción actual y seguir reproduciendo"),
+    ("remove the current track from the liked tracks", "quitar la canción actual de las canciones que te gustan"),
+    ("unlike, add to the blocklist and skip the current track", "quitar de favoritas, añadir a la lista de bloqueo y saltar la canción actual"),
+    ("quit the application", "salir de la aplicación"),
+    ("suspend the application", "suspender la aplicación"),
+    ("close a popup", "cerrar una ventana emergente"),
+    ("restart the integrated librespot client", "reiniciar el cliente librespot integrado"),
+    ("take over the playback on the integrated device", "pasar la reproducción al dispositivo integrado"),
+    ("toggle the (experimental) auto-mix mode on the integrated device", "activar/desactivar el modo de mezcla automática (experimental) en el dispositivo integrado"),
+    ("select the next item in a list/table or scroll down", "seleccionar el siguiente elemento de una lista/tabla o desplazarse hacia abajo"),
+    ("select the previous item in a list/table or scroll up", "seleccionar el elemento anterior de una lista/tabla o desplazarse hacia arriba"),
+    ("select the next page item in a list/table or scroll a page down", "seleccionar el elemento de la página siguiente de una lista/tabla o bajar una página"),
+    ("select the previous page item in a list/table or scroll a page up", "seleccionar el elemento de la página anterior de una lista/tabla o subir una página"),
+    ("select the first item in a list/table or scroll to the top", "seleccionar el primer elemento de una lista/tabla o ir al principio"),
+    ("select the last item in a list/table or scroll to the bottom", "seleccionar el último elemento de una lista/tabla o ir al final"),
+    ("choose the selected item and act on it", "elegir el elemento seleccionado y actuar sobre él"),
+    ("manually refresh the current playback", "actualizar manualmente la reproducción actual"),
+    ("open a popup showing actions on a selected item", "abrir una ventana con las acciones sobre el elemento seleccionado"),
+    ("open a popup showing actions on the current track", "abrir una ventana con las acciones sobre la canción actual"),
+    ("go to the radio (recommendations) page of the selected track or artist", "ir a la página de radio (recomendaciones) de la canción o artista seleccionado"),
+    ("add the selected item to queue", "añadir el elemento seleccionado a la cola"),
+    ("play the selected track right after the current track", "reproducir la canción seleccionada justo después de la actual"),
+    ("add the selected track to (or remove it from) the liked tracks", "añadir la canción seleccionada a (o quitarla de) las canciones que te gustan"),
+    ("start/stop selecting a range of tracks in a track table", "empezar/terminar de seleccionar un rango de canciones en una tabla"),
+    ("copy the selected tracks' URIs (newline-separated) to the clipboard", "copiar los URI de las canciones seleccionadas (uno por línea) al portapapeles"),
+    ("add the selected tracks to a playlist", "añadir las canciones seleccionadas a una lista"),
+    ("focus the next focusable window (if any)", "enfocar la siguiente ventana enfocable (si existe)"),
+    ("focus the previous focusable window (if any)", "enfocar la ventana enfocable anterior (si existe)"),
+    ("grow the focused window (if resizable)", "agrandar la ventana enfocada (si es redimensionable)"),
+    ("shrink the focused window (if resizable)", "reducir la ventana enfocada (si es redimensionable)"),
+    ("grow the playback window", "agrandar la ventana de reproducción"),
+    ("shrink the playback window", "reducir la ventana de reproducción"),
+    ("toggle between the list and grid views of album lists", "alternar entre la vista de lista y de cuadrícula de los álbumes"),
+    ("toggle a panel showing details of the selected item", "mostrar/ocultar un panel con los detalles del elemento seleccionado"),
+    ("toggle grouping context track tables by albums (or discs)", "agrupar/desagrupar las tablas de canciones por álbumes (o discos)"),
+    ("collapse (or expand) the selected track's group", "contraer (o expandir) el grupo de la canción seleccionada"),
+    ("open a popup for switching theme", "abrir una ventana para cambiar de tema"),
+    ("open a popup for switching device", "abrir una ventana para cambiar de dispositivo"),
+    ("set the selected device in the device popup as the default device", "establecer el dispositivo seleccionado en la ventana de dispositivos como predeterminado"),
+    ("open a popup for searching in the current page", "abrir una ventana para buscar en la página actual"),
+    ("open a popup for searching by a genre of the current artist", "abrir una ventana para buscar por un género del artista actual"),
+    ("browse recommendations of the artist's or selected category's genre", "explorar recomendaciones del género del artista o de la categoría seleccionada"),
+    ("open a popup for browsing user's playlists", "abrir una ventana para explorar las listas del usuario"),
+    ("open a popup for browsing user's followed artists", "abrir una ventana para explorar los artistas seguidos"),
+    ("open a popup for browsing user's saved albums", "abrir una ventana para explorar los álbumes guardados"),
+    ("go to the currently playing context page", "ir a la página del contexto en reproducción"),
+    ("go to the user top track page", "ir a la página de canciones más escuchadas"),
+    ("go to the user recently played track page", "ir a la página de canciones reproducidas recientemente"),
+    ("go to the user recently added track page", "ir a la página de canciones añadidas recientemente"),
+    ("go to the page of liked tracks not in any playlist", "ir a la página de canciones que te gustan que no están en ninguna lista"),
+    ("go to the user liked track page", "ir a la página de canciones que te gustan"),
+    ("go to the lyric page of the current track", "ir a la página de la letra de la canción actual"),
+    ("go to the user library page", "ir a la página de la biblioteca"),
+    (
+        "go to the user saved episodes of the library page",
+        "ir a los episodios guardados de la página de la biblioteca",
+    ),
+    ("go to the chapter page of the playing audiobook", "ir a la página de capítulos del audiolibro en reproducción"),
+    ("go to the episode page of the playing podcast show", "ir a la página de episodios del podcast en reproducción"),
+    ("go to the search page", "ir a la página de búsqueda"),
+    ("go to the browse page", "ir a la página de exploración"),
+    ("go to the new releases page", "ir a la página de novedades"),
+    ("go to the featured playlists page", "ir a la página de listas destacadas"),
+    ("add all albums of the new releases page to the library", "añadir todos los álbumes de la página de novedades a la biblioteca"),
+    ("go to the queue page", "ir a la página de la cola"),
+    ("go to the command help page", "ir a la página de ayuda de comandos"),
+    ("go to the previous page", "ir a la página anterior"),
+    ("swap between the current and the previously shown context pages", "alternar entre la página de contexto actual y la mostrada anteriormente"),
+    ("open a Spotify link from clipboard", "abrir un enlace de Spotify desde el portapapeles"),
+    ("select the track of a number in the context's track table", "seleccionar la canción con un número en la tabla del contexto"),
+    ("sort the track table (if any) by track's title", "ordenar la tabla de canciones (si existe) por título"),
+    ("sort the track table (if any) by track's artists", "ordenar la tabla de canciones (si existe) por artistas"),
+    ("sort the track table (if any) by track's album", "ordenar la tabla de canciones (si existe) por álbum"),
+    ("sort the track table (if any) by track's duration", "ordenar la tabla de canciones (si existe) por duración"),
+    ("sort the track table (if any) by track's added date", "ordenar la tabla de canciones (si existe) por fecha de adición"),
+    ("sort the track table (if any) by track's popularity", "ordenar la tabla de canciones (si existe) por popularidad"),
+    ("reverse the order of the track table (if any)", "invertir el orden de la tabla de canciones (si existe)"),
+    ("filter the track tables by a range of release years", "filtrar las tablas de canciones por un rango de años de publicación"),
+    ("filter the track tables by a range of tempos (BPM)", "filtrar las tablas de canciones por un rango de tempos (BPM)"),
+    ("toggle the album column of the context's track table", "mostrar/ocultar la columna de álbum de la tabla del contexto"),
+    ("toggle the release year column of the context's track table", "mostrar/ocultar la columna de año de publicación de la tabla del contexto"),
+    ("toggle the popularity column of the context's track table", "mostrar/ocultar la columna de popularidad de la tabla del contexto"),
+    ("move playlist (or queue) item up one position", "subir una posición el elemento de la lista (o de la cola)"),
+    ("move playlist (or queue) item down one position", "bajar una posición el elemento de la lista (o de la cola)"),
+    ("remove the selected item from the queue", "quitar el elemento seleccionado de la cola"),
+    ("create a new playlist", "crear una nueva lista"),
+    ("toggle the party mode (protected by a passphrase)", "activar/desactivar el modo fiesta (protegido por contraseña)"),
+    ("accept or reject the guests' song requests", "aceptar o rechazar las peticiones de canciones de los invitados"),
+    ("remove multiple playlists, albums and artists from the library at once", "quitar varias listas, álbumes y artistas de la biblioteca a la vez"),
+    ("show the most skipped tracks of the current context", "mostrar las canciones más saltadas del contexto actual"),
+    ("compare the current playlist with another playlist", "comparar la lista actual con otra lista"),
+    ("append the tracks of a playlist to another playlist", "añadir las canciones de una lista al final de otra lista"),
+    ("copy the current playlist's tracks into a new dated playlist", "copiar las canciones de la lista actual en una nueva lista con fecha"),
+    ("copy the focused comparison window's tracks to the other playlist", "copiar las canciones de la ventana de comparación enfocada a la otra lista"),
+];
+
+const FR_TRANSLATIONS: &[(&str, &str)] = &[
+    // windows and popups
+    ("Playback", "Lecture"),
+    ("Search", "Recherche"),
+    ("Tracks", "Titres"),
+    ("Artists", "Artistes"),
+    ("Categories", "Catégories"),
+    ("Lyric", "Paroles"),
+    ("Commands", "Commandes"),
+    ("Queue", "File d'attente"),
+    ("Added to the queue", "Ajouté à la file d'attente"),
+    ("Related Artists", "Artistes similaires"),
+    ("Shortcuts", "Raccourcis"),
+    ("Devices", "Appareils"),
+    ("Themes", "Thèmes"),
+    ("User Playlists", "Playlists de l'utilisateur"),
+    ("User Followed Artists", "Artistes suivis"),
+    ("User Saved Albums", "Albums sauvegardés"),
+    ("Saved Episodes", "Épisodes sauvegardés"),
+    ("Chapters", "Chapitres"),
+    ("Details", "Détails"),
+    ("Enter Name for New Playlist:", "Nom de la nouvelle playlist :"),
+    (
+        "Enter Description for New Playlist:",
+        "Description de la nouvelle playlist :",
+    ),
+    ("Enter Name for New Bookmark:", "Nom du nouveau signet :"),
+    (
+        "Enter Release Years (e.g. 1995, 1990s, 1990-1999):",
+        "Années de sortie (ex. 1995, 1990s, 1990-1999) :",
+    ),
+    (
+        "Enter Tempos in BPM (e.g. 124, 120-128):",
+        "Tempos en BPM (ex. 124, 120-128) :",
+    ),
+    (
+        "Enter Passphrase to Enable Party Mode:",
+        "Phrase secrète pour activer le mode fête :",
+    ),
+    (
+        "Enter Passphrase to Disable Party Mode:",
+        "Phrase secrète pour désactiver le mode fête :",
+    ),
+    ("Actions on", "Actions sur"),
+    // contexts
+    ("Current Playing", "En cours de lecture"),
+    ("Artist", "Artiste"),
+    ("Top Tracks", "Titres les plus écoutés"),
+    ("Liked Tracks", "Titres aimés"),
+    ("Recently Played Tracks", "Écoutés récemment"),
+    ("Recently Added Tracks", "Ajoutés récemment"),
+    ("Recommendations", "Recommandations"),
+    ("songs", "titres"),
+    ("followers", "abonnés"),
+    ("popularity", "popularité"),
+    // table headers
+    ("Command", "Commande"),
+    ("Title", "Titre"),
+    ("Played", "Écouté"),
+    ("Duration", "Durée"),
+    ("Show", "Émission"),
+    ("Released", "Publié"),
+    ("Resume", "Reprise"),
+    // item details
+    ("Explicit", "Explicite"),
+    ("Unavailable", "Indisponible"),
+    ("Followers", "Abonnés"),
+    ("Popularity", "Popularité"),
+    ("Owner", "Propriétaire"),
+    ("Collaborative", "Collaborative"),
+    ("No item selected", "Aucun élément sélectionné"),
+    ("Credits of", "Crédits de"),
+    ("Main Artist", "Artiste principal"),
+    ("Featured Artists", "Artistes invités"),
+    ("Disc Number", "Numéro de disque"),
+    ("Disc", "Disque"),
+    ("Track Number", "Numéro de piste"),
+    ("Available Markets", "Pays disponibles"),
+    // playback
+    ("Next", "Suivant"),
+    ("repeat", "répétition"),
+    ("shuffle", "aléatoire"),
+    ("device", "appareil"),
+    ("muted", "muet"),
+    // messages
+    (
+        "Cannot determine the current page's context",
+        "Impossible de déterminer le contexte de la page actuelle",
+    ),
+    ("Failed to load the context", "Échec du chargement du contexte"),
+    ("press `{key}` to retry", "appuyez sur `{key}` pour réessayer"),
+    ("Lyric not found", "Paroles introuvables"),
+    ("Failed to get the lyric", "Impossible de récupérer les paroles"),
+    ("No playback found.", "Aucune lecture trouvée."),
+    (
+        "Please make sure there is a running Spotify device and try to connect to one using the `SwitchDevice` command.",
+        "Assurez-vous qu'un appareil Spotify est en cours d'exécution et essayez de vous y connecter avec la commande `SwitchDevice`.",
+    ),
+    (
+        "You may also need to set up Spotify Connect to see available devices as in",
+        "Vous devrez peut-être aussi configurer Spotify Connect pour voir les appareils disponibles, comme décrit dans",
+    ),
+    ("Liked", "Ajouté aux favoris"),
+    ("Unliked", "Retiré des favoris"),
+    ("Disliked", "Bloqué"),
+    ("Skipped", "Passé"),
+    ("Playing", "Lecture"),
+    ("Paused: audio output changed", "En pause : la sortie audio a changé"),
+    ("not available in your market", "indisponible dans votre pays"),
+    ("not available for your product", "indisponible pour votre abonnement"),
+    ("explicit content restricted", "contenu explicite restreint"),
+    ("unavailable", "indisponible"),
+    ("Paused", "En pause"),
+    ("by", "par"),
+    ("Bookmark", "Signet"),
+    ("No next bookmark", "Aucun signet suivant"),
+    ("Copied track URIs", "URI de titres copiées"),
+    ("Failed to update the playlist", "Échec de la mise à jour de la playlist"),
+    ("Invalid year range", "Plage d'années invalide"),
+    ("Invalid tempo range", "Plage de tempos invalide"),
+    ("Invalid position", "Position invalide"),
+    ("Invalid track number", "Numéro de titre invalide"),
+    ("Authentication required", "Authentification requise"),
+    ("Authenticated", "Authentifié"),
+    ("Failed to authenticate", "Échec de l'authentification"),
+    (
+        "Auto-mix is only available on the integrated device",
+        "L'auto-mix n'est disponible que sur l'appareil intégré",
+    ),
+    ("No playing track to mix from", "Aucun titre en cours de lecture"),
+    ("No episode is playing", "Aucun épisode en cours de lecture"),
+    ("No audiobook is playing", "Aucun livre audio en cours de lecture"),
+    ("Auto-mix enabled", "Auto-mix activé"),
+    ("Auto-mix disabled", "Auto-mix désactivé"),
+    ("Disabled in party mode", "Désactivé en mode fête"),
+    ("Party mode enabled", "Mode fête activé"),
+    ("Party mode disabled", "Mode fête désactivé"),
+    ("Wrong passphrase", "Phrase secrète incorrecte"),
+    ("Not available in kiosk mode", "Indisponible en mode kiosque"),
+    ("New guest request", "Nouvelle demande d'un invité"),
+    ("Guest Requests", "Demandes des invités"),
+    ("New Releases", "Nouveautés"),
+    ("Library Cleanup", "Nettoyage de la bibliothèque"),
+    ("No skipped tracks", "Aucun titre passé"),
+    ("No other context to swap to", "Aucun autre contexte à permuter"),
+    ("Saving", "Enregistrement de"),
+    ("Added", "Ajouté"),
+    ("tracks to the playlist", "titres à la playlist"),
+    ("Only in", "Seulement dans"),
+    ("In both", "Dans les deux"),
+    (
+        "Open a playlist to compare it with another playlist",
+        "Ouvrez une playlist pour la comparer avec une autre playlist",
+    ),
+    (
+        "The tracks are already in both playlists",
+        "Les titres sont déjà dans les deux playlists",
+    ),
+    ("No missing tracks", "Aucun titre manquant"),
+    ("No recommendations for the genre", "Aucune recommandation pour le genre"),
+    ("No tracks to merge", "Aucun titre à fusionner"),
+    ("Year", "Année"),
+    ("filtered", "filtré"),
+    (
+        "Clear the track filters to reorder the playlist",
+        "Retirez les filtres des titres pour réordonner la playlist",
+    ),
+    ("Archived the playlist", "Playlist archivée"),
+    ("Open a playlist to archive it", "Ouvrez une playlist pour l'archiver"),
+    (
+        "Open the device list to set the default device",
+        "Ouvrez la liste des appareils pour choisir l'appareil par défaut",
+    ),
+    ("Cannot modify the playlist", "Impossible de modifier la playlist"),
+    ("albums to the library", "albums dans la bibliothèque"),
+    ("Confirm removing", "Confirmer la suppression de"),
+    ("items (choose again to confirm)", "éléments (choisir à nouveau pour confirmer)"),
+    ("Remove", "Retirer"),
+    ("marked items", "éléments marqués"),
+    ("Track", "Titre"),
+    ("No marked items", "Aucun élément marqué"),
+    ("Removing items from the library", "Suppression d'éléments de la bibliothèque"),
+    ("items from the library", "éléments de la bibliothèque"),
+    ("failed", "échecs"),
+    ("Most Skipped Tracks", "Titres les plus passés"),
+    ("Remove all from the playlist", "Tout retirer de la playlist"),
+    ("Add all to the blocklist", "Tout ajouter à la liste de blocage"),
+    ("skips", "passages"),
+    ("Removed", "Retiré"),
+    ("tracks from the playlist", "titres de la playlist"),
+    ("Blocked", "Bloqué"),
+    ("tracks", "titres"),
+    ("min left", "min restantes"),
+    (
+        "No chapters found. The playing show may not be an audiobook.",
+        "Aucun chapitre trouvé. L'émission en cours n'est peut-être pas un livre audio.",
+    ),
+    ("Featured Playlists", "Playlists en vedette"),
+    ("Enter Position to Seek to (e.g. 90, 1:30, 1:02:30):", "Position à atteindre (ex. 90, 1:30, 1:02:30) :"),
+    ("Enter Track Number to Go to:", "Numéro du titre à atteindre :"),
+    ("Authentication Required, Enter Username:", "Authentification requise, nom d'utilisateur :"),
+    ("Enter Password:", "Mot de passe :"),
+    ("Loading...", "Chargement..."),
+    // command descriptions in the command help page
+    ("do nothing", "ne rien faire"),
+    ("next track", "titre suivant"),
+    ("previous track (or the start of the current track)", "titre précédent (ou le début du titre en cours)"),
+    ("skip to the next chapter of the playing audiobook", "passer au chapitre suivant du livre audio en cours"),
+    ("add a named bookmark at the current playback position", "ajouter un signet nommé à la position de lecture actuelle"),
+    ("jump to the next bookmark of the current track/episode", "aller au signet suivant du titre/épisode en cours"),
+    ("resume/pause based on the current playback", "reprendre/mettre en pause selon la lecture en cours"),
+    ("play a random track in the current context", "lire un titre aléatoire du contexte actuel"),
+    ("shuffle-play liked tracks (or saved albums if focused) in the library", "lire aléatoirement les titres aimés (ou les albums sauvegardés s'ils sont sélectionnés) de la bibliothèque"),
+    ("cycle the repeat mode", "changer le mode de répétition"),
+    ("toggle fake track repeat mode", "activer/désactiver le mode de répétition simulée du titre"),
+    ("toggle the shuffle mode", "activer/désactiver le mode aléatoire"),
+    ("increase playback volume by 5% (configurable)", "augmenter le volume de 5% (configurable)"),
+    ("decrease playback volume by 5% (configurable)", "baisser le volume de 5% (configurable)"),
+    ("toggle playback volume between 0% and previous level", "basculer le volume entre 0% et le niveau précédent"),
+    ("seek forward by 5s (configurable)", "avancer de 5s (configurable)"),
+    ("seek backward by 5s (configurable)", "reculer de 5s (configurable)"),
+    ("seek to a position (mm:ss) of the current playback", "aller à une position (mm:ss) de la lecture en cours"),
+    ("like the current track and continue playing", "aimer le titre en cours et continuer la lecture"),
+    ("remove the current track from the liked tracks", "retirer le titre en cours des titres aimés"),
+    ("unlike, add to the blocklist and skip the current track", "retirer des titres aimés, ajouter à la liste de blocage et passer le titre en cours"),
+    ("quit the application", "quitter l'application"),
+    ("suspend the application", "suspendre l'application"),
+    ("close a popup", "fermer une fenêtre contextuelle"),
+    ("restart the integrated librespot client", "redémarrer le client librespot intégré"),
+    ("take over the playback on the integrated device", "reprendre la lecture sur l'appareil intégré"),
+    ("toggle the (experimental) auto-mix mode on the integrated device", "activer/désactiver le mode de mixage automatique (expérimental) sur l'appareil intégré"),
+    ("select the next item in a list/table or scroll down", "sélectionner l'élément suivant d'une liste/d'un tableau ou défiler vers le bas"),
+    ("select the previous item in a list/table or scroll up", "sélectionner l'élément précédent d'une liste/d'un tableau ou défiler vers le haut"),
+    ("select the next page item in a list/table or scroll a page down", "sélectionner l'élément de la page suivante d'une liste/d'un tableau ou descendre d'une page"),
+    ("select the previous page item in a list/table or scroll a page up", "sélectionner l'élément de la page précédente d'une liste/d'un tableau ou monter d'une page"),
+    ("select the first item in a list/table or scroll to the top", "sélectionner le premier élément d'une liste/d'un tableau ou défiler jusqu'en haut"),
+    ("select the last item in a list/table or scroll to the bottom", "sélectionner le dernier élément d'une liste/d'un tableau ou défiler jusqu'en bas"),
+    ("choose the selected item and act on it", "choisir l'élément sélectionné et agir dessus"),
+    ("manually refresh the current playback", "actualiser manuellement la lecture en cours"),
+    ("open a popup showing actions on a selected item", "ouvrir une fenêtre affichant les actions sur l'élément sélectionné"),
+    ("open a popup showing actions on the current track", "ouvrir une fenêtre affichant les actions sur le titre en cours"),
+    ("go to the radio (recommendations) page of the selected track or artist", "aller à la page radio (recommandations) du titre ou de l'artiste sélectionné"),
+    ("add the selected item to queue", "ajouter l'élément sélectionné à la file d'attente"),
+    ("play the selected track right after the current track", "lire le titre sélectionné juste après le titre en cours"),
+    ("add the selected track to (or remove it from) the liked tracks", "ajouter le titre sélectionné aux (ou le retirer des) titres aimés"),
+    ("start/stop selecting a range of tracks in a track table", "commencer/arrêter la sélection d'une plage de titres dans un tableau"),
+    ("copy the selected tracks' URIs (newline-separated) to the clipboard", "copier les URI des titres sélectionnés (un par ligne) dans le presse-papiers"),
+    ("add the selected tracks to a playlist", "ajouter les titres sélectionnés à une playlist"),
+    ("focus the next focusable window (if any)", "activer la fenêtre suivante (s'il y en a une)"),
+    ("focus the previous focusable window (if any)", "activer la fenêtre précédente (s'il y en a une)"),
+    ("grow the focused window (if resizable)", "agrandir la fenêtre active (si redimensionnable)"),
+    ("shrink the focused window (if resizable)", "réduire la fenêtre active (si redimensionnable)"),
+    ("grow the playback window", "agrandir la fenêtre de lecture"),
+    ("shrink the playback window", "réduire la fenêtre de lecture"),
+    ("toggle between the list and grid views of album lists", "basculer entre les vues liste et grille des listes d'albums"),
+    ("toggle a panel showing details of the selected item", "afficher/masquer un panneau de détails de l'élément sélectionné"),
+    ("toggle grouping context track tables by albums (or discs)", "grouper/dégrouper les tableaux de titres par albums (ou disques)"),
+    ("collapse (or expand) the selected track's group", "réduire (ou développer) le groupe du titre sélectionné"),
+    ("open a popup for switching theme", "ouvrir une fenêtre pour changer de thème"),
+    ("open a popup for switching device", "ouvrir une fenêtre pour changer d'appareil"),
+    ("set the selected device in the device popup as the default device", "définir l'appareil sélectionné dans la fenêtre des appareils comme appareil par défaut"),
+    ("open a popup for searching in the current page", "ouvrir une fenêtre pour rechercher dans la page actuelle"),
+    ("open a popup for searching by a genre of the current artist", "ouvrir une fenêtre pour rechercher par un genre de l'artiste en cours"),
+    ("browse recommendations of the artist's or selected category's genre", "parcourir les recommandations du genre de l'artiste ou de la catégorie sélectionnée"),
+    ("open a popup for browsing user's playlists", "ouvrir une fenêtre pour parcourir les playlists de l'utilisateur"),
+    ("open a popup for browsing user's followed artists", "ouvrir une fenêtre pour parcourir les artistes suivis"),
+    ("open a popup for browsing user's saved albums", "ouvrir une fenêtre pour parcourir les albums sauvegardés"),
+    ("go to the currently playing context page", "aller à la page du contexte en cours de lecture"),
+    ("go to the user top track page", "aller à la page des titres les plus écoutés"),
+    ("go to the user recently played track page", "aller à la page des titres écoutés récemment"),
+    ("go to the user recently added track page", "aller à la page des titres ajoutés récemment"),
+    ("go to the page of liked tracks not in any playlist", "aller à la page des titres aimés absents de toute playlist"),
+    ("go to the user liked track page", "aller à la page des titres aimés"),
+    ("go to the lyric page of the current track", "aller à la page des paroles du titre en cours"),
+    ("go to the user library page", "aller à la page de la bibliothèque"),
+    (
+        "go to the user saved episodes of the library page",
+        "aller aux épisodes sauvegardés de la page de la bibliothèque",
+    ),
+    ("go to the chapter page of the playing audiobook", "aller à la page des chapitres du livre audio en cours"),
+    ("go to the episode page of the playing podcast show", "aller à la page des épisodes du podcast en cours"),
+    ("go to the search page", "aller à la page de recherche"),
+    ("go to the browse page", "aller à la page d'exploration"),
+    ("go to the new releases page", "aller à la page des nouveautés"),
+    ("go to the featured playlists page", "aller à la page des playlists en vedette"),
+    ("add all albums of the new releases page to the library", "ajouter tous les albums de la page des nouveautés à la bibliothèque"),
+    ("go to the queue page", "aller à la page de la file d'attente"),
+    ("go to the command help page", "aller à la page d'aide des commandes"),
+    ("go to the previous page", "aller à la page précédente"),
+    ("swap between the current and the previously shown context pages", "basculer entre la page de contexte actuelle et la précédente"),
+    ("open a Spotify link from clipboard", "ouvrir un lien Spotify depuis le presse-papiers"),
+    ("select the track of a number in the context's track table", "sélectionner le titre d'un numéro dans le tableau du contexte"),
+    ("sort the track table (if any) by track's title", "trier le tableau des titres (s'il y en a un) par titre"),
+    ("sort the track table (if any) by track's artists", "trier le tableau des titres (s'il y en a un) par artistes"),
+    ("sort the track table (if any) by track's album", "trier le tableau des titres (s'il y en a un) par album"),
+    ("sort the track table (if any) by track's duration", "trier le tableau des titres (s'il y en a un) par durée"),
+    ("sort the track table (if any) by track's added date", "trier le tableau des titres (s'il y en a un) par date d'ajout"),
+    ("sort the track table (if any) by track's popularity", "trier le tableau des titres (s'il y en a un) par popularité"),
+    ("reverse the order of the track table (if any)", "inverser l'ordre du tableau des titres (s'il y en a un)"),
+    ("filter the track tables by a range of release years", "filtrer les tableaux des titres par une plage d'années de sortie"),
+    ("filter the track tables by a range of tempos (BPM)", "filtrer les tableaux des titres par une plage de tempos (BPM)"),
+    ("toggle the album column of the context's track table", "afficher/masquer la colonne album du tableau du contexte"),
+    ("toggle the release year column of the context's track table", "afficher/masquer la colonne année de sortie du tableau du contexte"),
+    ("toggle the popularity column of the context's track table", "afficher/masquer la colonne popularité du tableau du contexte"),
+    ("move playlist (or queue) item up one position", "monter d'une position l'élément de la playlist (ou de la file d'attente)"),
+    ("move playlist (or queue) item down one position", "descendre d'une position l'élément de la playlist (ou de la file d'attente)"),
+    ("remove the selected item from the queue", "retirer l'élément sélectionné de la file d'attente"),
+    ("create a new playlist", "créer une nouvelle playlist"),
+    ("toggle the party mode (protected by a passphrase)", "activer/désactiver le mode fête (protégé par une phrase secrète)"),
+    ("accept or reject the guests' song requests", "accepter ou refuser les demandes de titres des invités"),
+    ("remove multiple playlists, albums and artists from the library at once", "retirer plusieurs playlists, albums et artistes de la bibliothèque en une fois"),
+    ("show the most skipped tracks of the current context", "afficher les titres les plus passés du contexte actuel"),
+    ("compare the current playlist with another playlist", "comparer la playlist actuelle avec une autre playlist"),
+    ("append the tracks of a playlist to another playlist", "ajouter les titres d'une playlist à la fin d'une autre playlist"),
+    ("copy the current playlist's tracks into a new dated playlist", "copier les titres de la playlist actuelle dans une nouvelle playlist datée"),
+    ("copy the focused comparison window's tracks to the other playlist", "copier les titres de la fenêtre de comparaison active vers l'autre playlist"),
+];
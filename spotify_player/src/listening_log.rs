@@ -0,0 +1,217 @@
+use anyhow::{Context, Result};
+use rspotify::{model::FullTrack, prelude::Id};
+use serde::{Deserialize, Serialize};
+
+/// the file name of the listening log's database in the cache folder
+const LISTENING_LOG_FILE_NAME: &str = "listening_log.db";
+
+/// a play ending earlier than this duration before the end of its track is considered skipped
+const SKIP_THRESHOLD: chrono::TimeDelta = chrono::TimeDelta::seconds(10);
+
+/// A log of the user's listening history, stored in a local SQLite database
+pub struct ListeningLog {
+    conn: rusqlite::Connection,
+    current_play: Option<Play>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// A recorded play of the listening log, used to export and import the listening history
+pub struct PlayRecord {
+    pub track_uri: String,
+    pub track_name: String,
+    pub artists: String,
+    pub album: String,
+    pub context_uri: Option<String>,
+    pub device: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub skipped: Option<bool>,
+}
+
+/// A play of a track being recorded
+struct Play {
+    /// the play's row ID in the database
+    id: i64,
+    track_uri: String,
+    track_duration: chrono::Duration,
+    /// the last observed playback progress of the play
+    progress: chrono::Duration,
+}
+
+impl ListeningLog {
+    /// Open the listening log in the cache folder, removing plays older than the retention period
+    pub fn open(cache_folder: &std::path::Path, retention_in_days: u64) -> Result<Self> {
+        let path = cache_folder.join(LISTENING_LOG_FILE_NAME);
+        let conn = rusqlite::Connection::open(&path)
+            .with_context(|| format!("open the listening log database at {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS plays (
+                id INTEGER PRIMARY KEY,
+                track_uri TEXT NOT NULL,
+                track_name TEXT NOT NULL,
+                artists TEXT NOT NULL,
+                album TEXT NOT NULL,
+                context_uri TEXT,
+                device TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                ended_at TEXT,
+                skipped INTEGER
+            );
+            CREATE INDEX IF NOT EXISTS plays_started_at ON plays (started_at);",
+        )
+        .context("create the listening log's table")?;
+
+        if retention_in_days > 0 {
+            let cutoff = chrono::Utc::now() - chrono::TimeDelta::days(retention_in_days as i64);
+            let n_removed = conn.execute(
+                "DELETE FROM plays WHERE started_at < ?1",
+                [format_time(cutoff)],
+            )?;
+            tracing::info!("Removed {n_removed} plays older than {retention_in_days} days from the listening log");
+        }
+
+        Ok(Self {
+            conn,
+            current_play: None,
+        })
+    }
+
+    /// Open the listening log in the cache folder without modifying it, e.g. to export its plays.
+    /// Return `None` if no listening log has been recorded.
+    pub fn open_read_only(cache_folder: &std::path::Path) -> Result<Option<Self>> {
+        let path = cache_folder.join(LISTENING_LOG_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let conn = rusqlite::Connection::open_with_flags(
+            &path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
+        )
+        .with_context(|| format!("open the listening log database at {}", path.display()))?;
+        Ok(Some(Self {
+            conn,
+            current_play: None,
+        }))
+    }
+
+    /// Record the playback of a track, starting a new play if the track differs
+    /// from the currently recorded play's track, which is then ended
+    pub fn record_playback(
+        &mut self,
+        track: &FullTrack,
+        progress: Option<chrono::Duration>,
+        context_uri: Option<String>,
+        device: &str,
+    ) -> Result<()> {
+        let Some(ref track_id) = track.id else {
+            return Ok(());
+        };
+        let track_uri = track_id.uri();
+        let progress = progress.unwrap_or_default();
+
+        if let Some(play) = self.current_play.as_mut() {
+            if play.track_uri == track_uri {
+                play.progress = progress;
+                return Ok(());
+            }
+        }
+        self.end_current_play()?;
+
+        self.conn.execute(
+            "INSERT INTO plays (track_uri, track_name, artists, album, context_uri, device, started_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![
+                track_uri,
+                track.name,
+                crate::utils::map_join(&track.artists, |a| &a.name, ", "),
+                track.album.name,
+                context_uri,
+                device,
+                format_time(chrono::Utc::now()),
+            ],
+        )?;
+        self.current_play = Some(Play {
+            id: self.conn.last_insert_rowid(),
+            track_uri,
+            track_duration: track.duration,
+            progress,
+        });
+        Ok(())
+    }
+
+    /// End the currently recorded play (if any)
+    pub fn end_current_play(&mut self) -> Result<()> {
+        if let Some(play) = self.current_play.take() {
+            let is_skipped = play.progress + SKIP_THRESHOLD < play.track_duration;
+            self.conn.execute(
+                "UPDATE plays SET ended_at = ?1, skipped = ?2 WHERE id = ?3",
+                rusqlite::params![format_time(chrono::Utc::now()), is_skipped, play.id],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get all recorded plays, ordered by their start times
+    pub fn plays(&self) -> Result<Vec<PlayRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT track_uri, track_name, artists, album, context_uri, device, started_at, ended_at, skipped
+            FROM plays ORDER BY started_at",
+        )?;
+        let plays = stmt
+            .query_map([], |row| {
+                Ok(PlayRecord {
+                    track_uri: row.get(0)?,
+                    track_name: row.get(1)?,
+                    artists: row.get(2)?,
+                    album: row.get(3)?,
+                    context_uri: row.get(4)?,
+                    device: row.get(5)?,
+                    started_at: row.get(6)?,
+                    ended_at: row.get(7)?,
+                    skipped: row.get(8)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        Ok(plays)
+    }
+
+    /// Add plays to the listening log, skipping plays that are already recorded.
+    /// Return the number of added plays.
+    pub fn import_plays(&mut self, plays: &[PlayRecord]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut n_added = 0;
+        for play in plays {
+            let is_recorded = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM plays WHERE track_uri = ?1 AND started_at = ?2)",
+                [&play.track_uri, &play.started_at],
+                |row| row.get::<_, bool>(0),
+            )?;
+            if is_recorded {
+                continue;
+            }
+            tx.execute(
+                "INSERT INTO plays (track_uri, track_name, artists, album, context_uri, device, started_at, ended_at, skipped)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                rusqlite::params![
+                    play.track_uri,
+                    play.track_name,
+                    play.artists,
+                    play.album,
+                    play.context_uri,
+                    play.device,
+                    play.started_at,
+                    play.ended_at,
+                    play.skipped,
+                ],
+            )?;
+            n_added += 1;
+        }
+        tx.commit()?;
+        Ok(n_added)
+    }
+}
+
+/// Format a time as a RFC 3339 string, which is ordered chronologically as text
+fn format_time(time: chrono::DateTime<chrono::Utc>) -> String {
+    time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
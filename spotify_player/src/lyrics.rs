@@ -0,0 +1,192 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+/// the URL of the LRCLIB API for searching lyrics
+const LRCLIB_SEARCH_URL: &str = "https://lrclib.net/api/search";
+
+#[derive(Debug, Clone, Copy)]
+/// A provider to retrieve the lyric of a track from
+pub enum LyricProvider {
+    /// [LRCLIB](https://lrclib.net), which provides time-synced lyrics
+    LrcLib,
+    /// [Genius](https://genius.com), which only provides plain lyrics
+    Genius,
+}
+
+/// the lyric providers ordered by their priorities
+const LYRIC_PROVIDERS: [LyricProvider; 2] = [LyricProvider::LrcLib, LyricProvider::Genius];
+
+#[derive(Debug, Clone)]
+/// A track's lyric
+pub struct Lyric {
+    pub track: String,
+    pub artists: String,
+    pub lines: Vec<LyricLine>,
+}
+
+#[derive(Debug, Clone)]
+/// A line of a lyric
+pub struct LyricLine {
+    /// the time when the line starts, which is only available in a synced lyric
+    pub timestamp: Option<std::time::Duration>,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LrcLibLyric {
+    track_name: String,
+    artist_name: String,
+    plain_lyrics: Option<String>,
+    synced_lyrics: Option<String>,
+}
+
+impl Lyric {
+    /// Whether the lyric's lines are synced with the track's playback
+    pub fn is_synced(&self) -> bool {
+        self.lines.iter().any(|l| l.timestamp.is_some())
+    }
+
+    /// Get the index of the synced lyric's line being sung at the given playback progress
+    pub fn current_line(&self, progress: std::time::Duration) -> Option<usize> {
+        self.lines
+            .iter()
+            .rposition(|l| l.timestamp.is_some_and(|t| t <= progress))
+    }
+
+    fn from_plain_lyric(track: String, artists: String, lyric: &str) -> Self {
+        Self {
+            track,
+            artists,
+            lines: lyric
+                .lines()
+                .map(|text| LyricLine {
+                    timestamp: None,
+                    text: text.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl LyricProvider {
+    /// Get the lyric of a track from the provider
+    async fn get_lyric(
+        &self,
+        http: &reqwest::Client,
+        track: &str,
+        artists: &str,
+    ) -> Result<Option<Lyric>> {
+        match self {
+            Self::LrcLib => {
+                // search with the track's first artist only, as LRCLIB doesn't match
+                // an artist name consisting of multiple artists
+                let artist = artists.split(", ").next().unwrap_or_default();
+                let results: Vec<LrcLibLyric> = http
+                    .get(LRCLIB_SEARCH_URL)
+                    .query(&[("track_name", track), ("artist_name", artist)])
+                    .header(
+                        reqwest::header::USER_AGENT,
+                        concat!("spotify_player/", env!("CARGO_PKG_VERSION")),
+                    )
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+
+                // prefer a synced lyric over a plain one
+                let result = results
+                    .iter()
+                    .find(|r| r.synced_lyrics.is_some())
+                    .or_else(|| results.iter().find(|r| r.plain_lyrics.is_some()));
+                Ok(result.map(|r| match r.synced_lyrics {
+                    Some(ref lyric) => Lyric {
+                        track: r.track_name.clone(),
+                        artists: r.artist_name.clone(),
+                        lines: parse_lrc(lyric),
+                    },
+                    None => Lyric::from_plain_lyric(
+                        r.track_name.clone(),
+                        r.artist_name.clone(),
+                        r.plain_lyrics.as_deref().unwrap_or_default(),
+                    ),
+                }))
+            }
+            Self::Genius => {
+                let client = lyric_finder::Client::from_http_client(http);
+                Ok(
+                    match client.get_lyric(&format!("{track} {artists}")).await? {
+                        lyric_finder::LyricResult::None => None,
+                        lyric_finder::LyricResult::Some {
+                            track,
+                            artists,
+                            lyric,
+                        } => Some(Lyric::from_plain_lyric(track, artists, &lyric)),
+                    },
+                )
+            }
+        }
+    }
+}
+
+/// Get the lyric of a track from the first lyric provider having it.
+///
+/// Return `Ok(None)` only if every provider doesn't have the lyric, and an error if
+/// a provider failed (e.g. a network failure) and no other provider has the lyric.
+pub async fn get_lyric(
+    http: &reqwest::Client,
+    track: &str,
+    artists: &str,
+) -> Result<Option<Lyric>> {
+    let mut failed_providers = vec![];
+    for provider in LYRIC_PROVIDERS {
+        match provider.get_lyric(http, track, artists).await {
+            Ok(Some(lyric)) => return Ok(Some(lyric)),
+            Ok(None) => {}
+            Err(err) => {
+                tracing::warn!("Failed to get lyric from {provider:?}: {err:#}");
+                failed_providers.push(provider);
+            }
+        }
+    }
+    if !failed_providers.is_empty() {
+        anyhow::bail!("failed to get lyric from {failed_providers:?}");
+    }
+    Ok(None)
+}
+
+/// Parse a lyric in the LRC format, in which each line is prefixed
+/// by one or more timestamps in the `[mm:ss.xx]` format
+fn parse_lrc(lrc: &str) -> Vec<LyricLine> {
+    let mut lines = vec![];
+    for line in lrc.lines() {
+        let mut text = line.trim();
+        let mut timestamps = vec![];
+        while let Some((tag, rest)) = text.strip_prefix('[').and_then(|t| t.split_once(']')) {
+            match parse_lrc_timestamp(tag) {
+                Some(timestamp) => timestamps.push(timestamp),
+                // skip a metadata tag (e.g. `[ar:Artist]`)
+                None => break,
+            }
+            text = rest;
+        }
+        lines.extend(timestamps.into_iter().map(|timestamp| LyricLine {
+            timestamp: Some(timestamp),
+            text: text.trim().to_string(),
+        }));
+    }
+    lines.sort_by_key(|l| l.timestamp);
+    lines
+}
+
+/// Parse a LRC timestamp in the `mm:ss.xx` format
+fn parse_lrc_timestamp(tag: &str) -> Option<std::time::Duration> {
+    let (minutes, seconds) = tag.split_once(':')?;
+    let minutes = minutes.parse::<u64>().ok()?;
+    let seconds = seconds
+        .parse::<f64>()
+        .ok()
+        .filter(|s| s.is_finite() && *s >= 0.0)?;
+    Some(std::time::Duration::from_secs(minutes * 60) + std::time::Duration::from_secs_f64(seconds))
+}
@@ -4,9 +4,17 @@ mod client;
 mod command;
 mod config;
 mod event;
+mod guest_request;
+mod http;
+mod i18n;
 mod key;
+#[cfg(feature = "listening-log")]
+mod listening_log;
+#[cfg(feature = "lyric-finder")]
+mod lyrics;
 #[cfg(feature = "media-control")]
 mod media_control;
+mod metrics;
 mod state;
 #[cfg(feature = "streaming")]
 mod streaming;
@@ -32,9 +40,30 @@ async fn init_spotify(
     // initialize the playback state
     client.retrieve_current_playback(state, false).await?;
 
-    if state.player.read().playback.is_none() {
-        tracing::info!("No playback found on startup, trying to connect to an available device...");
-        client_pub.send(client::ClientRequest::ConnectDevice)?;
+    let app_config = &config::get_config().app_config;
+    let playback_device = state
+        .player
+        .read()
+        .playback
+        .as_ref()
+        .map(|p| p.device.name.clone());
+    match playback_device {
+        None => {
+            tracing::info!(
+                "No playback found on startup, trying to connect to an available device..."
+            );
+            client_pub.send(client::ClientRequest::ConnectDevice)?;
+        }
+        Some(name)
+            if app_config.transfer_to_default_device_on_startup
+                && name != app_config.default_device() =>
+        {
+            tracing::info!(
+                "Found a playback on device (name={name}) on startup, trying to transfer the playback to the default device..."
+            );
+            client_pub.send(client::ClientRequest::ConnectDevice)?;
+        }
+        _ => {}
     }
 
     // request user data
@@ -42,11 +71,17 @@ async fn init_spotify(
     client_pub.send(client::ClientRequest::GetUserPlaylists)?;
     client_pub.send(client::ClientRequest::GetUserFollowedArtists)?;
     client_pub.send(client::ClientRequest::GetUserSavedAlbums)?;
+    client_pub.send(client::ClientRequest::GetUserSavedEpisodes)?;
     client_pub.send(client::ClientRequest::GetUserSavedTracks)?;
 
     Ok(())
 }
 
+thread_local! {
+    /// whether the current thread runs the application's UI or its terminal event handler
+    static IS_UI_THREAD: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
 fn init_logging(cache_folder: &std::path::Path) -> Result<()> {
     let log_prefix = format!(
         "spotify-player-{}",
@@ -66,20 +101,71 @@ fn init_logging(cache_folder: &std::path::Path) -> Result<()> {
         .init();
 
     // initialize the application's panic backtrace
+    let backtrace_path = cache_folder.join(format!("{log_prefix}.backtrace"));
     let backtrace_file =
-        std::fs::File::create(cache_folder.join(format!("{log_prefix}.backtrace")))
-            .context("failed to create backtrace file")?;
+        std::fs::File::create(&backtrace_path).context("failed to create backtrace file")?;
     let backtrace_file = std::sync::Mutex::new(backtrace_file);
+    let prev_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
-        let mut file = backtrace_file.lock().unwrap();
-        let backtrace = backtrace::Backtrace::new();
-        writeln!(&mut file, "Got a panic: {info:#?}\n").unwrap();
-        writeln!(&mut file, "Stack backtrace:\n{backtrace:?}").unwrap();
+        tracing::error!("Got a panic: {info}");
+        {
+            let mut file = backtrace_file.lock().unwrap_or_else(|e| e.into_inner());
+            let backtrace = backtrace::Backtrace::new();
+            _ = writeln!(&mut file, "Got a panic: {info:#?}\n");
+            _ = writeln!(&mut file, "Stack backtrace:\n{backtrace:?}");
+        }
+
+        let is_ui_running = crossterm::terminal::is_raw_mode_enabled().unwrap_or(false);
+        if !IS_UI_THREAD.get() {
+            // A panic in other threads (e.g. a spawned client request) only stops its own task,
+            // so the panic is unwound normally. Its message is logged instead of being printed
+            // while the UI is running, which would otherwise break the UI's rendering.
+            if !is_ui_running {
+                prev_hook(info);
+            }
+            return;
+        }
+
+        // The application can't be recovered from a panic in an UI thread,
+        // so restore the terminal before quitting to not leave it in a broken state.
+        if is_ui_running {
+            _ = ui::restore_terminal();
+        }
+        prev_hook(info);
+        eprintln!(
+            "spotify_player panicked. The stack backtrace was written to {}",
+            backtrace_path.display()
+        );
+        std::process::exit(1);
     }));
 
     Ok(())
 }
 
+/// Wait for a termination signal (`SIGINT`, `SIGTERM` or `SIGHUP`),
+/// then restore the terminal and quit the application.
+#[cfg(unix)]
+async fn handle_termination_signals(state: &state::SharedState) -> Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut sigint = signal(SignalKind::interrupt())?;
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sighup = signal(SignalKind::hangup())?;
+
+    let signal = tokio::select! {
+        _ = sigint.recv() => "SIGINT",
+        _ = sigterm.recv() => "SIGTERM",
+        _ = sighup.recv() => "SIGHUP",
+    };
+    tracing::info!("Received {signal} signal, quitting the application...");
+
+    // acquire the UI lock to prevent the application from rendering after the terminal is restored
+    let _ui = state.ui.lock();
+    state.data.write().store_context_views();
+    ui::restore_terminal().context("restore the terminal")?;
+    std::process::exit(0);
+}
+
 #[tokio::main]
 async fn start_app(state: &state::SharedState) -> Result<()> {
     let configs = config::get_config();
@@ -158,6 +244,77 @@ async fn start_app(state: &state::SharedState) -> Result<()> {
         }
     }));
 
+    // guest request server task (for handling guests' song requests)
+    if let Some(port) = configs.app_config.guest_request_port {
+        let address = configs.app_config.guest_request_bind_address.as_str();
+        tasks.push(tokio::task::spawn({
+            let client = client.clone();
+            let state = state.clone();
+            let client_pub = client_pub.clone();
+            async move {
+                tracing::info!("Starting a guest request server at {address}:{port}");
+                match tokio::net::TcpListener::bind((address, port)).await {
+                    Ok(listener) => {
+                        guest_request::start_guest_request_server(
+                            client, state, client_pub, listener,
+                        )
+                        .await;
+                    }
+                    Err(err) => {
+                        tracing::warn!(
+                            "Failed to create a server for handling guest requests: {err:#}"
+                        )
+                    }
+                }
+            }
+        }));
+    }
+
+    // metrics server task (for exposing the application's metrics)
+    if let Some(port) = configs.app_config.metrics_port {
+        let address = configs.app_config.metrics_bind_address.as_str();
+        tasks.push(tokio::task::spawn(async move {
+            tracing::info!("Starting a metrics server at {address}:{port}");
+            match tokio::net::TcpListener::bind((address, port)).await {
+                Ok(listener) => metrics::start_metrics_server(listener).await,
+                Err(err) => {
+                    tracing::warn!("Failed to create a server for exposing metrics: {err:#}")
+                }
+            }
+        }));
+    }
+
+    // token refresher task
+    tasks.push(tokio::task::spawn({
+        let client_pub = client_pub.clone();
+        async move {
+            client::start_token_refresher(client_pub).await;
+        }
+    }));
+
+    // playlist auto-archiver task
+    if !configs.app_config.auto_archived_playlists.is_empty() {
+        tasks.push(tokio::task::spawn({
+            let client_pub = client_pub.clone();
+            async move {
+                client::start_playlist_auto_archiver(client_pub).await;
+            }
+        }));
+    }
+
+    // audio sink watcher task
+    #[cfg(all(feature = "streaming", target_os = "linux"))]
+    if configs.app_config.pause_on_audio_sink_change && state.is_streaming_enabled() {
+        tasks.push(tokio::task::spawn({
+            let client = client.clone();
+            let state = state.clone();
+            let client_pub = client_pub.clone();
+            async move {
+                streaming::start_audio_sink_watcher(client, state, client_pub).await;
+            }
+        }));
+    }
+
     // client event handler task
     tasks.push(tokio::task::spawn({
         let state = state.clone();
@@ -183,6 +340,7 @@ async fn start_app(state: &state::SharedState) -> Result<()> {
             let client_pub = client_pub.clone();
             let state = state.clone();
             move || {
+                IS_UI_THREAD.set(true);
                 event::start_event_handler(state, client_pub);
             }
         });
@@ -190,12 +348,58 @@ async fn start_app(state: &state::SharedState) -> Result<()> {
         // application UI task
         tokio::task::spawn_blocking({
             let state = state.clone();
-            move || ui::run(state)
+            move || {
+                IS_UI_THREAD.set(true);
+                ui::run(state)
+            }
+        });
+
+        // termination signal handler task
+        #[cfg(unix)]
+        tokio::task::spawn({
+            let state = state.clone();
+            async move {
+                if let Err(err) = handle_termination_signals(&state).await {
+                    tracing::warn!("Failed to listen to termination signals: {err:#}");
+                }
+            }
+        });
+
+        // suspend signal handler task
+        #[cfg(unix)]
+        tokio::task::spawn({
+            let client_pub = client_pub.clone();
+            let state = state.clone();
+            async move {
+                let mut signals = match tokio::signal::unix::signal(
+                    tokio::signal::unix::SignalKind::from_raw(libc::SIGTSTP),
+                ) {
+                    Ok(signals) => signals,
+                    Err(err) => {
+                        tracing::warn!("Failed to listen to the SIGTSTP signal: {err:#}");
+                        return;
+                    }
+                };
+                while signals.recv().await.is_some() {
+                    let client_pub = client_pub.clone();
+                    let state = state.clone();
+                    let result = tokio::task::spawn_blocking(move || {
+                        let mut ui = state.ui.lock();
+                        event::suspend_application(&client_pub, &state, &mut ui)
+                    })
+                    .await;
+                    if let Ok(Err(err)) = result {
+                        tracing::error!("Failed to suspend the application: {err:#}");
+                    }
+                }
+            }
         });
     }
 
+    // a daemon can't listen to the OS window events on MacOS, in which case the playback
+    // is published to the Now Playing by the `now-playing` helper instead
     #[cfg(feature = "media-control")]
-    if configs.app_config.enable_media_control {
+    if configs.app_config.enable_media_control && !(cfg!(target_os = "macos") && state.is_daemon) {
         // media control task
         tokio::task::spawn_blocking({
             let state = state.clone();
@@ -261,6 +465,17 @@ fn main() -> Result<()> {
             // override the theme config if user specifies a `theme` cli argument
             configs.app_config.theme = theme.to_owned();
         }
+        if let Some(market) = args.get_one::<String>("market") {
+            // override the market config if user specifies a `market` cli argument
+            configs.app_config.market = Some(market.to_owned());
+        }
+        if args.get_flag("kiosk") {
+            // override the kiosk mode config if user specifies a `kiosk` cli flag
+            configs.app_config.kiosk_mode = true;
+        }
+        if let Err(err) = configs.app_config.validate_market() {
+            eprintln!("{err:#}, the user's market is used instead");
+        }
         config::set_config(configs);
     }
 
@@ -278,10 +493,8 @@ fn main() -> Result<()> {
             {
                 is_daemon = args.get_flag("daemon");
                 if is_daemon {
-                    if cfg!(any(target_os = "macos", target_os = "windows"))
-                        && cfg!(feature = "media-control")
-                    {
-                        eprintln!("Running the application as a daemon on windows/macos with `media-control` feature enabled is not supported!");
+                    if cfg!(target_os = "windows") && cfg!(feature = "media-control") {
+                        eprintln!("Running the application as a daemon on windows with `media-control` feature enabled is not supported!");
                         std::process::exit(1);
                     }
 
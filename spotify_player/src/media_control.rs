@@ -1,10 +1,13 @@
 #![allow(unused_imports)]
 use souvlaki::MediaPosition;
-use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+use souvlaki::{
+    MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig, SeekDirection,
+};
 
 use crate::utils;
 use crate::{
     client::{ClientRequest, PlayerRequest},
+    config,
     state::SharedState,
     utils::map_join,
 };
@@ -12,44 +15,197 @@ use crate::{
 fn update_control_metadata(
     state: &SharedState,
     controls: &mut MediaControls,
-    prev_track_info: &mut String,
+    prev_item_uri: &mut String,
 ) -> Result<(), souvlaki::Error> {
     let player = state.player.read();
+    update_controls(
+        controls,
+        player.playback.as_ref(),
+        player.playback_progress(),
+        prev_item_uri,
+    )
+}
 
-    match player.current_playing_track() {
-        None => {}
-        Some(track) => {
-            if let Some(ref playback) = player.playback {
-                let progress = player
-                    .playback_progress()
-                    .and_then(|p| Some(MediaPosition(p.to_std().ok()?)));
+/// Update the media controls with a playback and its progress,
+/// only updating the metadata if the playing item differs from the previously playing item
+pub fn update_controls(
+    controls: &mut MediaControls,
+    playback: Option<&rspotify::model::CurrentPlaybackContext>,
+    progress: Option<chrono::Duration>,
+    prev_item_uri: &mut String,
+) -> Result<(), souvlaki::Error> {
+    use rspotify::model::PlayableItem;
+    use rspotify::prelude::Id;
 
-                if playback.is_playing {
-                    controls.set_playback(MediaPlayback::Playing { progress })?;
-                } else {
-                    controls.set_playback(MediaPlayback::Paused { progress })?;
-                }
-            }
+    let Some(playback) = playback else {
+        return Ok(());
+    };
+    let Some(ref item) = playback.item else {
+        // clear the media controls once the playback stops, so that the OS media flyout
+        // doesn't keep showing the last played item
+        if !prev_item_uri.is_empty() {
+            controls.set_playback(MediaPlayback::Stopped)?;
+            prev_item_uri.clear();
+        }
+        return Ok(());
+    };
 
-            // only update metadata when the track information is changed
-            let track_info = format!("{}/{}", track.name, track.album.name);
-            if track_info != *prev_track_info {
-                controls.set_metadata(MediaMetadata {
-                    title: Some(&track.name),
-                    album: Some(&track.album.name),
-                    artist: Some(&map_join(&track.artists, |a| &a.name, ", ")),
-                    duration: track.duration.to_std().ok(),
-                    cover_url: utils::get_track_album_image_url(track),
-                })?;
-
-                *prev_track_info = track_info;
-            }
+    let progress = progress.and_then(|p| Some(MediaPosition(p.to_std().ok()?)));
+    if playback.is_playing {
+        controls.set_playback(MediaPlayback::Playing { progress })?;
+    } else {
+        controls.set_playback(MediaPlayback::Paused { progress })?;
+    }
+
+    // only update metadata when the playing item is changed.
+    // A local file has no URI, so it's identified by its name and album instead
+    let item_uri = match (item.id(), item) {
+        (Some(id), _) => id.uri(),
+        (None, PlayableItem::Track(track)) => format!("{}:{}", track.name, track.album.name),
+        (None, PlayableItem::Episode(episode)) => episode.name.clone(),
+    };
+    if item_uri != *prev_item_uri {
+        match item {
+            PlayableItem::Track(track) => controls.set_metadata(MediaMetadata {
+                title: Some(&track.name),
+                album: Some(&track.album.name),
+                artist: Some(&map_join(&track.artists, |a| &a.name, ", ")),
+                duration: track.duration.to_std().ok(),
+                cover_url: utils::get_track_album_image_url(track),
+            })?,
+            // an episode is shown as a track of its show, published by the show's publisher
+            PlayableItem::Episode(episode) => controls.set_metadata(MediaMetadata {
+                title: Some(&episode.name),
+                album: Some(&episode.show.name),
+                artist: Some(&episode.show.publisher),
+                duration: episode.duration.to_std().ok(),
+                cover_url: episode
+                    .images
+                    .first()
+                    .or(episode.show.images.first())
+                    .map(|i| i.url.as_str()),
+            })?,
         }
+
+        *prev_item_uri = item_uri;
     }
 
     Ok(())
 }
 
+/// the object path of the D-Bus signals emitted on the playback's changes
+#[cfg(target_os = "linux")]
+const DBUS_SIGNAL_PATH: &str = "/org/spotify_player/Player";
+/// the interface of the D-Bus signals emitted on the playback's changes
+#[cfg(target_os = "linux")]
+const DBUS_SIGNAL_INTERFACE: &str = "org.spotify_player.Player";
+/// a playback's progress deviating from its expected progress by more than this duration
+/// is considered to be seeked
+#[cfg(target_os = "linux")]
+const SEEK_DETECTION_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// An emitter of D-Bus signals on the track changes and seeks of the playback,
+/// which lets subscribers react to the changes without polling the MPRIS properties
+#[cfg(target_os = "linux")]
+struct SignalEmitter {
+    conn: dbus::blocking::Connection,
+    track_uri: String,
+    /// the playback's progress and playing state observed at the last update,
+    /// and the update's time
+    last_progress: Option<(std::time::Duration, bool, std::time::Instant)>,
+}
+
+#[cfg(target_os = "linux")]
+impl SignalEmitter {
+    fn new() -> Result<Self, dbus::Error> {
+        Ok(Self {
+            conn: dbus::blocking::Connection::new_session()?,
+            track_uri: String::new(),
+            last_progress: None,
+        })
+    }
+
+    fn emit(&self, member: &str, f: impl FnOnce(dbus::Message) -> dbus::Message) {
+        use dbus::channel::Sender as _;
+
+        let msg = dbus::Message::signal(
+            &DBUS_SIGNAL_PATH.into(),
+            &DBUS_SIGNAL_INTERFACE.into(),
+            &member.into(),
+        );
+        if self.conn.send(f(msg)).is_err() {
+            tracing::warn!("Failed to emit the D-Bus signal {member}");
+        }
+    }
+
+    /// Emit a `TrackChanged` signal if the current track changes,
+    /// or a `Seeked` signal if the current playback is seeked
+    fn update(&mut self, state: &SharedState) {
+        use rspotify::prelude::Id;
+
+        let player = state.player.read();
+        let Some(track) = player.current_playing_track() else {
+            return;
+        };
+        let uri = track.id.as_ref().map(|id| id.uri()).unwrap_or_default();
+        let progress = player
+            .playback_progress()
+            .and_then(|p| p.to_std().ok())
+            .unwrap_or_default();
+        let is_playing = player.playback.as_ref().is_some_and(|p| p.is_playing);
+        let now = std::time::Instant::now();
+
+        if uri != self.track_uri {
+            // TrackChanged(uri, title, artists, album, duration in microseconds)
+            self.emit("TrackChanged", |msg| {
+                msg.append3(
+                    &uri,
+                    &track.name,
+                    map_join(&track.artists, |a| &a.name, ", "),
+                )
+                .append2(
+                    &track.album.name,
+                    track.duration.num_microseconds().unwrap_or_default(),
+                )
+            });
+            self.track_uri = uri;
+        } else if let Some((last_progress, was_playing, last_updated)) = self.last_progress {
+            let expected_progress = if was_playing {
+                last_progress + now.duration_since(last_updated)
+            } else {
+                last_progress
+            };
+            if progress.abs_diff(expected_progress) > SEEK_DETECTION_THRESHOLD {
+                // Seeked(position in microseconds), like the MPRIS `Seeked` signal
+                self.emit("Seeked", |msg| msg.append1(progress.as_micros() as i64));
+            }
+        }
+        self.last_progress = Some((progress, is_playing, now));
+    }
+}
+
+/// Seek the current playback forward or backward by a duration relative to its progress
+fn seek_track(
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+    direction: SeekDirection,
+    dur: std::time::Duration,
+) {
+    let (Some(progress), Ok(dur)) = (
+        state.player.read().playback_progress(),
+        chrono::Duration::from_std(dur),
+    ) else {
+        return;
+    };
+    let position = match direction {
+        SeekDirection::Forward => progress + dur,
+        SeekDirection::Backward => std::cmp::max(chrono::Duration::zero(), progress - dur),
+    };
+    client_pub
+        .send(ClientRequest::Player(PlayerRequest::SeekTrack(position)))
+        .unwrap_or_default();
+}
+
 /// Start the application's media control event watcher
 pub fn start_event_watcher(
     state: SharedState,
@@ -74,47 +230,61 @@ pub fn start_event_watcher(
     };
     let mut controls = MediaControls::new(config)?;
 
-    controls.attach(move |e| {
-        tracing::info!("Got a media control event: {e:?}");
-        match e {
-            MediaControlEvent::Play => {
-                client_pub
-                    .send(ClientRequest::Player(PlayerRequest::Resume))
-                    .unwrap_or_default();
-            }
-            MediaControlEvent::Pause => {
-                client_pub
-                    .send(ClientRequest::Player(PlayerRequest::Pause))
-                    .unwrap_or_default();
-            }
-            MediaControlEvent::Toggle => {
-                client_pub
-                    .send(ClientRequest::Player(PlayerRequest::ResumePause))
-                    .unwrap_or_default();
-            }
-            MediaControlEvent::SetPosition(MediaPosition(dur)) => {
-                if let Ok(dur) = chrono::Duration::from_std(dur) {
+    controls.attach({
+        let state = state.clone();
+        move |e| {
+            tracing::info!("Got a media control event: {e:?}");
+            match e {
+                MediaControlEvent::Play => {
                     client_pub
-                        .send(ClientRequest::Player(PlayerRequest::SeekTrack(dur)))
+                        .send(ClientRequest::Player(PlayerRequest::Resume))
                         .unwrap_or_default();
                 }
+                MediaControlEvent::Pause | MediaControlEvent::Stop => {
+                    client_pub
+                        .send(ClientRequest::Player(PlayerRequest::Pause))
+                        .unwrap_or_default();
+                }
+                MediaControlEvent::Toggle => {
+                    client_pub
+                        .send(ClientRequest::Player(PlayerRequest::ResumePause))
+                        .unwrap_or_default();
+                }
+                MediaControlEvent::SetPosition(MediaPosition(dur)) => {
+                    if let Ok(dur) = chrono::Duration::from_std(dur) {
+                        client_pub
+                            .send(ClientRequest::Player(PlayerRequest::SeekTrack(dur)))
+                            .unwrap_or_default();
+                    }
+                }
+                MediaControlEvent::Seek(direction) => {
+                    // a seek event without a duration (e.g. the fast-forward and rewind
+                    // buttons of the Windows media flyout) seeks by the configured duration
+                    let dur = std::time::Duration::from_secs(
+                        config::get_config().app_config.seek_duration_in_secs,
+                    );
+                    seek_track(&state, &client_pub, direction, dur);
+                }
+                MediaControlEvent::SeekBy(direction, dur) => {
+                    seek_track(&state, &client_pub, direction, dur);
+                }
+                MediaControlEvent::Next => {
+                    client_pub
+                        .send(ClientRequest::Player(PlayerRequest::NextTrack))
+                        .unwrap_or_default();
+                }
+                MediaControlEvent::Previous => {
+                    client_pub
+                        .send(ClientRequest::Player(PlayerRequest::PreviousTrack))
+                        .unwrap_or_default();
+                }
+                MediaControlEvent::SetVolume(volume) => client_pub
+                    .send(ClientRequest::Player(PlayerRequest::Volume(
+                        (volume * 100.0) as u8,
+                    )))
+                    .unwrap_or_default(),
+                _ => {}
             }
-            MediaControlEvent::Next => {
-                client_pub
-                    .send(ClientRequest::Player(PlayerRequest::NextTrack))
-                    .unwrap_or_default();
-            }
-            MediaControlEvent::Previous => {
-                client_pub
-                    .send(ClientRequest::Player(PlayerRequest::PreviousTrack))
-                    .unwrap_or_default();
-            }
-            MediaControlEvent::SetVolume(volume) => client_pub
-                .send(ClientRequest::Player(PlayerRequest::Volume(
-                    (volume * 100.0) as u8,
-                )))
-                .unwrap_or_default(),
-            _ => {}
         }
     })?;
     // For some reason, on startup, media playback needs to be initialized with `Playing`
@@ -125,9 +295,21 @@ pub fn start_event_watcher(
     // handler provided by the souvlaki library, which only handles an event every 1s.
     // [1]: https://github.com/Sinono3/souvlaki/blob/b4d47bb2797ffdd625c17192df640510466762e1/src/platform/linux/mod.rs#L450
     let refresh_duration = std::time::Duration::from_millis(1000);
-    let mut track_info = String::new();
+    let mut item_uri = String::new();
+    #[cfg(target_os = "linux")]
+    let mut signal_emitter = match SignalEmitter::new() {
+        Ok(emitter) => Some(emitter),
+        Err(err) => {
+            tracing::warn!("Failed to connect to the D-Bus session bus to emit signals: {err:#}");
+            None
+        }
+    };
     loop {
-        update_control_metadata(&state, &mut controls, &mut track_info)?;
+        update_control_metadata(&state, &mut controls, &mut item_uri)?;
+        #[cfg(target_os = "linux")]
+        if let Some(ref mut emitter) = signal_emitter {
+            emitter.update(&state);
+        }
         std::thread::sleep(refresh_duration);
 
         // this must be run repeatedly to ensure that
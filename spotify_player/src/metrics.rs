@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fmt::Write as _};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::http;
+
+/// the application's metrics, collected over the application's lifetime
+static METRICS: Lazy<Mutex<Metrics>> = Lazy::new(|| Mutex::new(Metrics::new()));
+
+struct Metrics {
+    started_at: std::time::Instant,
+    /// the statistics of handled client requests, grouped by the requests' types
+    requests: BTreeMap<String, RequestStats>,
+    /// the numbers of hits and misses of each memory cache
+    caches: BTreeMap<&'static str, (u64, u64)>,
+    n_played_tracks: u64,
+    playing_duration: std::time::Duration,
+    is_playing: bool,
+}
+
+#[derive(Default)]
+struct RequestStats {
+    count: u64,
+    n_errors: u64,
+    duration: std::time::Duration,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Self {
+            started_at: std::time::Instant::now(),
+            requests: BTreeMap::new(),
+            caches: BTreeMap::new(),
+            n_played_tracks: 0,
+            playing_duration: std::time::Duration::ZERO,
+            is_playing: false,
+        }
+    }
+
+    /// Encode the metrics in the Prometheus text format
+    fn encode(&self) -> String {
+        let mut s = String::new();
+        let mut metric = |name: &str, typ: &str, help: &str, samples: Vec<(String, String)>| {
+            writeln!(s, "# HELP spotify_player_{name} {help}").unwrap();
+            writeln!(s, "# TYPE spotify_player_{name} {typ}").unwrap();
+            for (labels, value) in samples {
+                writeln!(s, "spotify_player_{name}{labels} {value}").unwrap();
+            }
+        };
+
+        let request_samples = |f: fn(&RequestStats) -> String| {
+            self.requests
+                .iter()
+                .map(|(request, stats)| (format!("{{request=\"{request}\"}}"), f(stats)))
+                .collect::<Vec<_>>()
+        };
+        metric(
+            "client_requests_total",
+            "counter",
+            "Number of handled client (API) requests",
+            request_samples(|r| r.count.to_string()),
+        );
+        metric(
+            "client_request_errors_total",
+            "counter",
+            "Number of failed client (API) requests",
+            request_samples(|r| r.n_errors.to_string()),
+        );
+        metric(
+            "client_request_duration_seconds_total",
+            "counter",
+            "Total duration of handling client (API) requests",
+            request_samples(|r| r.duration.as_secs_f64().to_string()),
+        );
+
+        let cache_samples = |f: fn(&(u64, u64)) -> u64| {
+            self.caches
+                .iter()
+                .map(|(cache, n)| (format!("{{cache=\"{cache}\"}}"), f(n).to_string()))
+                .collect::<Vec<_>>()
+        };
+        metric(
+            "cache_hits_total",
+            "counter",
+            "Number of memory cache hits",
+            cache_samples(|n| n.0),
+        );
+        metric(
+            "cache_misses_total",
+            "counter",
+            "Number of memory cache misses",
+            cache_samples(|n| n.1),
+        );
+
+        metric(
+            "played_tracks_total",
+            "counter",
+            "Number of tracks played in the session",
+            vec![(String::new(), self.n_played_tracks.to_string())],
+        );
+        metric(
+            "playing_seconds_total",
+            "counter",
+            "Duration of the playback being played in the session",
+            vec![(
+                String::new(),
+                self.playing_duration.as_secs_f64().to_string(),
+            )],
+        );
+        metric(
+            "is_playing",
+            "gauge",
+            "Whether the playback is being played",
+            vec![(String::new(), u8::from(self.is_playing).to_string())],
+        );
+        metric(
+            "uptime_seconds",
+            "gauge",
+            "Duration since the application started",
+            vec![(
+                String::new(),
+                self.started_at.elapsed().as_secs_f64().to_string(),
+            )],
+        );
+
+        s
+    }
+}
+
+/// Record a handled client request of the given type
+pub fn record_client_request(request: &str, duration: std::time::Duration, is_error: bool) {
+    let mut metrics = METRICS.lock();
+    let stats = match metrics.requests.get_mut(request) {
+        Some(stats) => stats,
+        None => metrics.requests.entry(request.to_string()).or_default(),
+    };
+    stats.count += 1;
+    stats.duration += duration;
+    if is_error {
+        stats.n_errors += 1;
+    }
+}
+
+/// Record a lookup of a memory cache, returning whether the lookup is a hit
+pub fn record_cache_lookup(cache: &'static str, is_hit: bool) -> bool {
+    let mut metrics = METRICS.lock();
+    let n = metrics.caches.entry(cache).or_default();
+    if is_hit {
+        n.0 += 1;
+    } else {
+        n.1 += 1;
+    }
+    is_hit
+}
+
+/// Record a newly played track
+pub fn record_played_track() {
+    METRICS.lock().n_played_tracks += 1;
+}
+
+/// Record the playback state observed for the given duration since the last observation
+pub fn record_playback(is_playing: bool, duration: std::time::Duration) {
+    let mut metrics = METRICS.lock();
+    if metrics.is_playing {
+        metrics.playing_duration += duration;
+    }
+    metrics.is_playing = is_playing;
+}
+
+/// Start an HTTP server exposing the application's metrics at `GET /metrics`
+/// in the Prometheus text format
+pub async fn start_metrics_server(listener: TcpListener) {
+    loop {
+        match listener.accept().await {
+            Err(err) => tracing::warn!("Failed to accept a metrics connection: {err:#}"),
+            Ok((stream, addr)) => {
+                tokio::task::spawn(async move {
+                    if let Err(err) = handle_connection(stream).await {
+                        tracing::warn!("Failed to handle a metrics request from {addr}: {err:#}");
+                    }
+                });
+            }
+        }
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    let request = http::read_request(&mut stream).await?;
+    let (status, body) = if request.method == "GET" && request.url.path() == "/metrics" {
+        ("200 OK", METRICS.lock().encode())
+    } else {
+        ("404 Not Found", "Not found\n".to_string())
+    };
+
+    http::write_response(
+        &mut stream,
+        status,
+        "text/plain; version=0.0.4; charset=utf-8",
+        &body,
+    )
+    .await
+}
@@ -4,10 +4,13 @@ use once_cell::sync::Lazy;
 pub static USER_TOP_TRACKS_ID: Lazy<TracksId> =
     Lazy::new(|| TracksId::new("tracks:user-top-tracks", "Top Tracks"));
 
-pub static USER_RECENTLY_PLAYED_TRACKS_ID: Lazy<TracksId> = Lazy::new(|| {
+pub static USER_RECENTLY_ADDED_TRACKS_ID: Lazy<TracksId> =
+    Lazy::new(|| TracksId::new("tracks:user-recently-added-tracks", "Recently Added Tracks"));
+
+pub static USER_UNSORTED_LIKED_TRACKS_ID: Lazy<TracksId> = Lazy::new(|| {
     TracksId::new(
-        "tracks:user-recently-played-tracks",
-        "Recently Played Tracks",
+        "tracks:user-unsorted-liked-tracks",
+        "Liked Tracks Not in Playlists",
     )
 });
 
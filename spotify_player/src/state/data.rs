@@ -1,18 +1,51 @@
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 use once_cell::sync::Lazy;
 use serde::{de::DeserializeOwned, Serialize};
 
 use super::model::*;
+use crate::config;
 
 pub type DataReadGuard<'a> = parking_lot::RwLockReadGuard<'a, AppData>;
 
+/// the maximum number of remembered context views, after which the least recently changed
+/// views are evicted
+const MAX_CONTEXT_VIEWS: usize = 500;
+
 #[derive(Debug)]
 pub enum FileCacheKey {
     Playlists,
     FollowedArtists,
     SavedAlbums,
     SavedTracks,
+    BlockedTracks,
+    ContextViews,
+    SavedEpisodes,
+    Bookmarks,
+    SkipCounts,
+    SearchHistory,
+}
+
+impl FileCacheKey {
+    /// Whether the data is stored in the sync folder (if configured) to be shared between machines
+    fn is_synced(&self) -> bool {
+        matches!(
+            self,
+            Self::Bookmarks | Self::ContextViews | Self::SearchHistory
+        )
+    }
+
+    /// Get the path of the data's file, which is in the sync folder for a synced data
+    /// if the `sync_folder` config option is set, or in the cache folder otherwise
+    fn file_path(&self, cache_folder: &Path) -> PathBuf {
+        match config::get_config().app_config.sync_folder {
+            Some(ref sync_folder) if self.is_synced() => sync_folder.join(format!("{self:?}.json")),
+            _ => cache_folder.join(format!("{self:?}_cache.json")),
+        }
+    }
 }
 
 /// default time-to-live cache duration
@@ -24,6 +57,19 @@ pub struct AppData {
     pub user_data: UserData,
     pub caches: MemoryCaches,
     pub browse: BrowseData,
+    /// the loading status of contexts (identified by URIs) that are not in the caches yet
+    pub context_loading_status: HashMap<String, LoadingStatus>,
+    /// the remembered view states of contexts (identified by URIs)
+    pub context_views: HashMap<String, ContextViewState>,
+    /// the last time the context views changed since they were stored into the file cache
+    pub context_views_changed_at: Option<std::time::Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// the loading status of a data requested from Spotify
+pub enum LoadingStatus {
+    Loading,
+    Failed,
 }
 
 #[derive(Debug)]
@@ -34,14 +80,29 @@ pub struct UserData {
     pub followed_artists: Vec<Artist>,
     pub saved_albums: Vec<Album>,
     pub saved_tracks: HashMap<String, Track>,
+    /// the tracks (identified by URIs) that are automatically skipped when played
+    pub blocked_tracks: HashSet<String>,
+    /// the numbers of times each track (identified by URI) was skipped
+    pub skip_counts: HashMap<String, u32>,
+    pub saved_episodes: Vec<Episode>,
+    /// the user's recently played tracks, ordered from the most recently played
+    pub recently_played_tracks: Vec<PlayedTrack>,
+    /// the local bookmarks of tracks and episodes (identified by URIs), sorted by positions
+    pub bookmarks: HashMap<String, Vec<Bookmark>>,
 }
 
 /// the application's in-memory caches
 pub struct MemoryCaches {
     pub context: ttl_cache::TtlCache<String, Context>,
     pub search: ttl_cache::TtlCache<String, SearchResults>,
+    /// the chapters of audiobooks (identified by IDs)
+    pub chapters: ttl_cache::TtlCache<String, Vec<Chapter>>,
+    /// the episodes of shows (identified by IDs)
+    pub show_episodes: ttl_cache::TtlCache<String, Vec<Episode>>,
+    /// the credits of tracks (identified by URIs)
+    pub track_credits: ttl_cache::TtlCache<String, TrackCredits>,
     #[cfg(feature = "lyric-finder")]
-    pub lyrics: ttl_cache::TtlCache<String, lyric_finder::LyricResult>,
+    pub lyrics: ttl_cache::TtlCache<String, Option<crate::lyrics::Lyric>>,
     #[cfg(feature = "image")]
     pub images: ttl_cache::TtlCache<String, image::DynamicImage>,
 }
@@ -51,6 +112,36 @@ pub struct MemoryCaches {
 pub struct BrowseData {
     pub categories: Vec<Category>,
     pub category_playlists: HashMap<String, Vec<Playlist>>,
+    /// Spotify's new album releases, which are `None` until loaded
+    pub new_releases: Option<Vec<Album>>,
+    /// Spotify's featured playlists, which are `None` until loaded
+    pub featured_playlists: Option<Vec<Playlist>>,
+    /// the genres available as recommendation seeds, which are `None` until loaded
+    pub genre_seeds: Option<Vec<String>>,
+}
+
+impl BrowseData {
+    /// Find the recommendation genre seed matching a genre (e.g. a browse category's name)
+    /// by comparing their letters and digits case-insensitively, so that "Hip-Hop" matches `hip-hop`
+    pub fn genre_seed(&self, genre: &str) -> Option<&str> {
+        let key = |s: &str| {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        };
+        let genre = key(genre);
+        self.genre_seeds
+            .as_ref()?
+            .iter()
+            .find(|seed| key(seed) == genre)
+            .map(String::as_str)
+    }
+
+    /// Check if a genre is known to have no matching recommendation genre seed
+    pub fn has_no_genre_seed(&self, genre: &str) -> bool {
+        self.genre_seeds.is_some() && self.genre_seed(genre).is_none()
+    }
 }
 
 impl MemoryCaches {
@@ -58,6 +149,9 @@ impl MemoryCaches {
         Self {
             context: ttl_cache::TtlCache::new(64),
             search: ttl_cache::TtlCache::new(64),
+            chapters: ttl_cache::TtlCache::new(16),
+            show_episodes: ttl_cache::TtlCache::new(16),
+            track_credits: ttl_cache::TtlCache::new(16),
             #[cfg(feature = "lyric-finder")]
             lyrics: ttl_cache::TtlCache::new(64),
             #[cfg(feature = "image")]
@@ -72,19 +166,102 @@ impl AppData {
             user_data: UserData::new_from_file_caches(cache_folder),
             caches: MemoryCaches::new(),
             browse: BrowseData::default(),
+            context_loading_status: HashMap::new(),
+            context_views: load_data_from_file_cache(FileCacheKey::ContextViews, cache_folder)
+                .unwrap_or_default(),
+            context_views_changed_at: None,
+        }
+    }
+
+    /// Reset the time-to-live of a cached context (if any), keeping it loaded in memory
+    pub fn keep_context_loaded(&mut self, uri: &str) {
+        if let Some(context) = self.caches.context.remove(uri) {
+            self.caches
+                .context
+                .insert(uri.to_string(), context, *TTL_CACHE_DURATION);
         }
     }
 
     /// Get a list of tracks inside a given context
     pub fn context_tracks(&mut self, id: &ContextId) -> Option<&mut Vec<Track>> {
-        self.caches.context.get_mut(&id.uri()).map(|c| match c {
-            Context::Album { tracks, .. } => tracks,
-            Context::Playlist { tracks, .. } => tracks,
-            Context::Artist {
-                top_tracks: tracks, ..
-            } => tracks,
-            Context::Tracks { tracks, .. } => tracks,
-        })
+        self.caches
+            .context
+            .get_mut(&id.uri())
+            .map(|c| c.tracks_mut())
+    }
+
+    /// Compare the tracks of two cached playlists, returning the tracks only in the first playlist,
+    /// the tracks only in the second playlist and the tracks in both playlists
+    pub fn compare_playlists(
+        &self,
+        first: &Playlist,
+        second: &Playlist,
+    ) -> Option<[Vec<&Track>; 3]> {
+        let tracks = |p: &Playlist| self.caches.context.get(&p.id.uri()).map(|c| c.tracks());
+        let (first, second) = (tracks(first)?, tracks(second)?);
+        let first_ids = first.iter().map(|t| &t.id).collect::<HashSet<_>>();
+        let second_ids = second.iter().map(|t| &t.id).collect::<HashSet<_>>();
+
+        Some([
+            first
+                .iter()
+                .filter(|t| !second_ids.contains(&t.id))
+                .collect(),
+            second
+                .iter()
+                .filter(|t| !first_ids.contains(&t.id))
+                .collect(),
+            first
+                .iter()
+                .filter(|t| second_ids.contains(&t.id))
+                .collect(),
+        ])
+    }
+
+    /// Update the remembered view state of a context.
+    /// The changed view states are persisted into the file cache later by `store_context_views`.
+    ///
+    /// Only the views that differ from the default view are remembered.
+    pub fn update_context_view(&mut self, uri: &str, f: impl FnOnce(&mut ContextViewState)) {
+        let view = self.context_views.entry(uri.to_string()).or_default();
+        let old_view = view.clone();
+        f(view);
+        let changed = *view != old_view;
+        if view.is_default() {
+            self.context_views.remove(uri);
+        } else if changed {
+            view.updated_at = chrono::Utc::now().timestamp() as u64;
+        }
+        if !changed {
+            return;
+        }
+
+        while self.context_views.len() > MAX_CONTEXT_VIEWS {
+            let oldest = self
+                .context_views
+                .iter()
+                .min_by_key(|(_, v)| v.updated_at)
+                .map(|(uri, _)| uri.clone());
+            match oldest {
+                Some(uri) => self.context_views.remove(&uri),
+                None => break,
+            };
+        }
+        self.context_views_changed_at = Some(std::time::Instant::now());
+    }
+
+    /// Store the context views into the file cache if they changed since the last store
+    pub fn store_context_views(&mut self) {
+        if self.context_views_changed_at.take().is_none() {
+            return;
+        }
+        if let Err(err) = store_data_into_file_cache(
+            FileCacheKey::ContextViews,
+            &config::get_config().cache_folder,
+            &self.context_views,
+        ) {
+            tracing::error!("Failed to store context views into the file cache: {err:#}");
+        }
     }
 }
 
@@ -104,6 +281,15 @@ impl UserData {
                 .unwrap_or_default(),
             saved_tracks: load_data_from_file_cache(FileCacheKey::SavedTracks, cache_folder)
                 .unwrap_or_default(),
+            blocked_tracks: load_data_from_file_cache(FileCacheKey::BlockedTracks, cache_folder)
+                .unwrap_or_default(),
+            skip_counts: load_data_from_file_cache(FileCacheKey::SkipCounts, cache_folder)
+                .unwrap_or_default(),
+            saved_episodes: load_data_from_file_cache(FileCacheKey::SavedEpisodes, cache_folder)
+                .unwrap_or_default(),
+            recently_played_tracks: vec![],
+            bookmarks: load_data_from_file_cache(FileCacheKey::Bookmarks, cache_folder)
+                .unwrap_or_default(),
         }
     }
 
@@ -123,6 +309,74 @@ impl UserData {
     pub fn is_liked_track(&self, track: &Track) -> bool {
         self.saved_tracks.contains_key(&track.id.uri())
     }
+
+    /// Add a track to (or remove a track from) the blocklist.
+    /// The blocklist is persisted into the file cache whenever it changes.
+    pub fn set_blocked_track(&mut self, track_id: &TrackId, blocked: bool) {
+        let uri = track_id.uri();
+        let changed = if blocked {
+            self.blocked_tracks.insert(uri)
+        } else {
+            self.blocked_tracks.remove(&uri)
+        };
+        if !changed {
+            return;
+        }
+
+        if let Err(err) = store_data_into_file_cache(
+            FileCacheKey::BlockedTracks,
+            &config::get_config().cache_folder,
+            &self.blocked_tracks,
+        ) {
+            tracing::error!("Failed to store blocked tracks into the file cache: {err:#}");
+        }
+    }
+
+    /// Increase the skip count of a track.
+    /// The skip counts are persisted into the file cache whenever they change.
+    pub fn record_skipped_track(&mut self, track_id: &TrackId) {
+        *self.skip_counts.entry(track_id.uri()).or_default() += 1;
+
+        if let Err(err) = store_data_into_file_cache(
+            FileCacheKey::SkipCounts,
+            &config::get_config().cache_folder,
+            &self.skip_counts,
+        ) {
+            tracing::error!("Failed to store skip counts into the file cache: {err:#}");
+        }
+    }
+
+    /// Get the number of times a track was skipped
+    pub fn skip_count(&self, track: &Track) -> u32 {
+        self.skip_counts
+            .get(&track.id.uri())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Add a bookmark to a track or an episode (identified by URI).
+    /// The bookmarks are persisted into the file cache whenever they change.
+    pub fn add_bookmark(&mut self, uri: String, bookmark: Bookmark) {
+        let bookmarks = self.bookmarks.entry(uri).or_default();
+        let pos = bookmarks.partition_point(|b| b.position <= bookmark.position);
+        bookmarks.insert(pos, bookmark);
+
+        if let Err(err) = store_data_into_file_cache(
+            FileCacheKey::Bookmarks,
+            &config::get_config().cache_folder,
+            &self.bookmarks,
+        ) {
+            tracing::error!("Failed to store bookmarks into the file cache: {err:#}");
+        }
+    }
+
+    /// Get the first bookmark of a track or an episode (identified by URI) after a position
+    pub fn next_bookmark(&self, uri: &str, position: std::time::Duration) -> Option<&Bookmark> {
+        self.bookmarks
+            .get(uri)?
+            .iter()
+            .find(|b| b.position > position)
+    }
 }
 
 pub fn store_data_into_file_cache<T: Serialize>(
@@ -130,9 +384,20 @@ pub fn store_data_into_file_cache<T: Serialize>(
     cache_folder: &Path,
     data: &T,
 ) -> std::io::Result<()> {
-    let path = cache_folder.join(format!("{key:?}_cache.json"));
-    let f = std::fs::File::create(path)?;
-    serde_json::to_writer(f, data)?;
+    let path = key.file_path(cache_folder);
+    if key.is_synced() {
+        // store a synced data as a pretty-printed JSON with sorted keys, which keeps
+        // the data's diffs small and easy to merge in a version-controlled sync folder
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut json = serde_json::to_string_pretty(&serde_json::to_value(data)?)?;
+        json.push('\n');
+        std::fs::write(path, json)?;
+    } else {
+        let f = std::fs::File::create(path)?;
+        serde_json::to_writer(f, data)?;
+    }
     Ok(())
 }
 
@@ -140,7 +405,7 @@ pub fn load_data_from_file_cache<T>(key: FileCacheKey, cache_folder: &Path) -> O
 where
     T: DeserializeOwned,
 {
-    let path = cache_folder.join(format!("{key:?}_cache.json"));
+    let path = key.file_path(cache_folder);
     if path.exists() {
         tracing::info!("Loading {key:?} data from {}...", path.display());
         let f = std::fs::File::open(path).expect("path exists");
@@ -36,6 +36,27 @@ impl State {
             ui.theme = theme;
         }
 
+        // restore the window sizes adjusted in previous sessions
+        ui.window_sizes = configs.app_config.window_sizes.clone();
+
+        if let Some(history) =
+            load_data_from_file_cache(FileCacheKey::SearchHistory, &configs.cache_folder)
+        {
+            // restore the search history of previous sessions
+            ui.search_history = history;
+        }
+
+        if configs.app_config.kiosk_mode {
+            // the library page is unavailable in the kiosk mode, start with the search page instead
+            ui.history = vec![PageState::Search {
+                line_input: crate::ui::single_line_input::LineInput::with_history(
+                    ui.search_history.clone(),
+                ),
+                current_query: String::new(),
+                state: SearchPageUIState::new(),
+            }];
+        }
+
         let app_data = AppData::new(&configs.cache_folder);
 
         Self {
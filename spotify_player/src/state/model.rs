@@ -1,8 +1,8 @@
 pub use rspotify::model as rspotify_model;
 use rspotify::model::CurrentPlaybackContext;
-pub use rspotify::model::{AlbumId, ArtistId, Id, PlaylistId, TrackId, UserId};
+pub use rspotify::model::{AlbumId, ArtistId, EpisodeId, Id, PlaylistId, ShowId, TrackId, UserId};
 
-use crate::utils::map_join;
+use crate::{i18n::tr, utils::map_join};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
@@ -55,6 +55,8 @@ pub enum ContextId {
 pub enum Playback {
     Context(ContextId, Option<rspotify_model::Offset>),
     URIs(Vec<TrackId<'static>>, Option<rspotify_model::Offset>),
+    /// an episode starting from a position
+    Episode(EpisodeId<'static>, Option<chrono::Duration>),
 }
 
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
@@ -66,7 +68,7 @@ pub struct SearchResults {
     pub playlists: Vec<Playlist>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 /// A track order
 pub enum TrackOrder {
     AddedAt,
@@ -74,6 +76,52 @@ pub enum TrackOrder {
     Album,
     Artists,
     Duration,
+    Popularity,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+/// The view state of a context page, remembered so that
+/// reopening the context restores how it was left
+pub struct ContextViewState {
+    pub track_order: Option<TrackOrder>,
+    pub reversed: bool,
+    pub filter: Option<String>,
+    pub selected: usize,
+    #[serde(default)]
+    pub columns: TrackTableColumns,
+    /// the time (in seconds since the Unix epoch) the view was last changed,
+    /// used to evict the least recently changed views
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+impl ContextViewState {
+    /// Check if the view is the same as a newly opened context's view, ignoring its update time
+    pub fn is_default(&self) -> bool {
+        *self
+            == Self {
+                updated_at: self.updated_at,
+                ..Self::default()
+            }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+/// The optional columns shown in a context's track table
+pub struct TrackTableColumns {
+    pub album: bool,
+    pub year: bool,
+    pub popularity: bool,
+}
+
+impl Default for TrackTableColumns {
+    fn default() -> Self {
+        Self {
+            album: true,
+            year: false,
+            popularity: false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -114,6 +162,9 @@ pub struct PlaybackMetadata {
 pub struct Device {
     pub id: String,
     pub name: String,
+    pub device_type: rspotify_model::DeviceType,
+    pub volume: Option<u32>,
+    pub is_active: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -127,6 +178,37 @@ pub struct Track {
     pub explicit: bool,
     #[serde(skip)]
     pub added_at: u64,
+    /// the reason why the track is unplayable in the user's market (if any)
+    #[serde(default)]
+    pub unavailable_reason: Option<String>,
+    #[serde(default)]
+    pub disc_number: i32,
+    /// the track's tempo in beats per minute, which is only known once the track's
+    /// audio features have been fetched
+    #[serde(skip)]
+    pub tempo: Option<f32>,
+    /// the track's popularity (0-100), which is only known for a track fetched in full
+    #[serde(default)]
+    pub popularity: Option<u32>,
+}
+
+/// A group of consecutive tracks in a track table sharing the same album (or disc)
+pub struct TrackGroup<'a> {
+    pub name: String,
+    pub tracks: Vec<&'a Track>,
+}
+
+#[derive(Debug, Clone)]
+/// The credits and release information of a Spotify track
+pub struct TrackCredits {
+    /// the track's artists, the first of which is the track's main artist
+    pub artists: Vec<String>,
+    pub album: String,
+    pub isrc: Option<String>,
+    pub disc_number: i32,
+    pub track_number: u32,
+    /// the markets (ISO 3166-1 alpha-2 country codes) in which the track is available
+    pub available_markets: Vec<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -136,6 +218,9 @@ pub struct Album {
     pub release_date: String,
     pub name: String,
     pub artists: Vec<Artist>,
+    /// the URL of the album's smallest cover image, used to render the album's thumbnail
+    #[serde(default)]
+    pub image_url: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -143,6 +228,53 @@ pub struct Album {
 pub struct Artist {
     pub id: ArtistId<'static>,
     pub name: String,
+    // the below fields are only available for artists retrieved from the artist endpoints
+    #[serde(default)]
+    pub followers: Option<u32>,
+    #[serde(default)]
+    pub popularity: Option<u32>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A Spotify episode
+pub struct Episode {
+    pub id: EpisodeId<'static>,
+    pub name: String,
+    #[serde(default)]
+    pub show_id: Option<ShowId<'static>>,
+    pub show_name: String,
+    pub release_date: String,
+    pub duration: std::time::Duration,
+    /// the position to resume the episode from, if the episode was partially played
+    pub resume_position: Option<std::time::Duration>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A locally saved bookmark at a position of a track or an episode
+pub struct Bookmark {
+    pub name: String,
+    pub position: std::time::Duration,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A chapter of a Spotify audiobook
+pub struct Chapter {
+    /// the chapter's ID, which is played as an episode
+    pub id: EpisodeId<'static>,
+    pub name: String,
+    pub chapter_number: usize,
+    pub duration: std::time::Duration,
+    pub resume_position: Option<std::time::Duration>,
+    pub fully_played: bool,
+}
+
+#[derive(Debug, Clone)]
+/// A play of a track in the user's recently played history
+pub struct PlayedTrack {
+    pub track: Track,
+    pub played_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -153,6 +285,9 @@ pub struct Playlist {
     pub name: String,
     pub owner: (String, UserId<'static>),
     pub desc: String,
+    /// the version identifier of the playlist, which changes whenever the playlist is modified
+    #[serde(default)]
+    pub snapshot_id: String,
 }
 
 #[derive(Clone, Debug)]
@@ -171,10 +306,11 @@ impl Context {
                 ref tracks,
             } => {
                 format!(
-                    "{} | {} | {} songs",
+                    "{} | {} | {} {}",
                     album.name,
                     album.release_date,
-                    tracks.len()
+                    tracks.len(),
+                    tr("songs")
                 )
             }
             Context::Playlist {
@@ -182,14 +318,59 @@ impl Context {
                 tracks,
             } => {
                 format!(
-                    "{} | {} | {} songs",
+                    "{} | {} | {} {}",
                     playlist.name,
                     playlist.owner.0,
-                    tracks.len()
+                    tracks.len(),
+                    tr("songs")
                 )
             }
-            Context::Artist { ref artist, .. } => artist.name.to_string(),
-            Context::Tracks { desc, tracks } => format!("{} | {} songs", desc, tracks.len()),
+            Context::Artist { ref artist, .. } => {
+                let mut desc = artist.name.to_string();
+                if let Some(followers) = artist.followers {
+                    desc += &format!(" | {followers} {}", tr("followers"));
+                }
+                if let Some(popularity) = artist.popularity {
+                    desc += &format!(" | {}: {popularity}", tr("popularity"));
+                }
+                if !artist.genres.is_empty() {
+                    desc += &format!(" | {}: {}", tr("genres"), artist.genres.join(", "));
+                }
+                desc
+            }
+            Context::Tracks { desc, tracks } => {
+                format!("{} | {} {}", desc, tracks.len(), tr("songs"))
+            }
+        }
+    }
+
+    /// checks if the context's tracks are all the tracks played in the context.
+    /// An artist context only has the artist's top tracks.
+    pub fn has_complete_tracks(&self) -> bool {
+        !matches!(self, Context::Artist { .. })
+    }
+
+    /// gets the context's tracks
+    pub fn tracks(&self) -> &[Track] {
+        match self {
+            Context::Album { tracks, .. } => tracks,
+            Context::Playlist { tracks, .. } => tracks,
+            Context::Artist {
+                top_tracks: tracks, ..
+            } => tracks,
+            Context::Tracks { tracks, .. } => tracks,
+        }
+    }
+
+    /// gets a mutable reference to the context's tracks
+    pub fn tracks_mut(&mut self) -> &mut Vec<Track> {
+        match self {
+            Context::Album { tracks, .. } => tracks,
+            Context::Playlist { tracks, .. } => tracks,
+            Context::Artist {
+                top_tracks: tracks, ..
+            } => tracks,
+            Context::Tracks { tracks, .. } => tracks,
         }
     }
 }
@@ -213,6 +394,19 @@ impl TrackOrder {
             Self::Album => x.album_info().cmp(&y.album_info()),
             Self::Duration => x.duration.cmp(&y.duration),
             Self::Artists => x.artists_info().cmp(&y.artists_info()),
+            Self::Popularity => x.popularity.cmp(&y.popularity),
+        }
+    }
+}
+
+impl ContextViewState {
+    /// sorts a list of tracks based on the view's track order
+    pub fn sort_tracks(&self, tracks: &mut [Track]) {
+        if let Some(order) = self.track_order {
+            tracks.sort_by(|x, y| order.compare(x, y));
+        }
+        if self.reversed {
+            tracks.reverse();
         }
     }
 }
@@ -223,6 +417,9 @@ impl Device {
         Some(Self {
             id: device.id?,
             name: device.name,
+            device_type: device._type,
+            volume: device.volume_percent,
+            is_active: device.is_active,
         })
     }
 }
@@ -250,46 +447,82 @@ impl Track {
         }
     }
 
-    /// tries to convert from a `rspotify_model::SimplifiedTrack` into `Track`
-    pub fn try_from_simplified_track(track: rspotify_model::SimplifiedTrack) -> Option<Self> {
-        if track.is_playable.unwrap_or(true) {
-            let id = match track.linked_from {
-                Some(d) => d.id,
-                None => track.id?,
-            };
-            Some(Self {
-                id,
-                name: track.name,
-                artists: from_simplified_artists_to_artists(track.artists),
-                album: None,
-                duration: track.duration.to_std().expect("valid chrono duration"),
-                explicit: track.explicit,
-                added_at: 0,
-            })
+    /// gets the release year of the track's album (if known)
+    pub fn year(&self) -> Option<i32> {
+        self.album.as_ref()?.year().parse().ok()
+    }
+
+    /// checks if the track is playable in the user's market
+    pub fn is_playable(&self) -> bool {
+        self.unavailable_reason.is_none()
+    }
+
+    /// gets the name of the track table group containing the track
+    fn group_name(&self, by_disc: bool) -> String {
+        if by_disc {
+            format!("{} {}", tr("Disc"), self.disc_number)
         } else {
-            None
+            self.album_info()
         }
     }
 
-    /// tries to convert from a `rspotify_model::FullTrack` into `Track`
+    /// tries to convert from a `rspotify_model::SimplifiedTrack` into `Track`,
+    /// which fails if the track is unplayable in the user's market
+    pub fn try_from_simplified_track(track: rspotify_model::SimplifiedTrack) -> Option<Self> {
+        Self::try_from_listed_simplified_track(track).filter(Self::is_playable)
+    }
+
+    /// tries to convert from a `rspotify_model::FullTrack` into `Track`,
+    /// which fails if the track is unplayable in the user's market
     pub fn try_from_full_track(track: rspotify_model::FullTrack) -> Option<Self> {
-        if track.is_playable.unwrap_or(true) {
-            let id = match track.linked_from {
-                Some(d) => d.id,
-                None => track.id?,
-            };
-            Some(Self {
-                id,
-                name: track.name,
-                artists: from_simplified_artists_to_artists(track.artists),
-                album: Album::try_from_simplified_album(track.album),
-                duration: track.duration.to_std().expect("valid chrono duration"),
-                explicit: track.explicit,
-                added_at: 0,
-            })
-        } else {
-            None
-        }
+        Self::try_from_listed_full_track(track).filter(Self::is_playable)
+    }
+
+    /// tries to convert from a `rspotify_model::SimplifiedTrack` into `Track` listed in
+    /// a context's track table, which keeps an unplayable track to show it with its reason
+    pub fn try_from_listed_simplified_track(
+        track: rspotify_model::SimplifiedTrack,
+    ) -> Option<Self> {
+        // a relinked track is identified by the ID of the originally requested track
+        let id = match track.linked_from {
+            Some(d) => d.id,
+            None => track.id?,
+        };
+        Some(Self {
+            id,
+            name: track.name,
+            artists: from_simplified_artists_to_artists(track.artists),
+            album: None,
+            duration: track.duration.to_std().expect("valid chrono duration"),
+            explicit: track.explicit,
+            added_at: 0,
+            unavailable_reason: unavailable_reason(track.is_playable, track.restrictions),
+            disc_number: track.disc_number,
+            tempo: None,
+            popularity: None,
+        })
+    }
+
+    /// tries to convert from a `rspotify_model::FullTrack` into `Track` listed in
+    /// a context's track table, which keeps an unplayable track to show it with its reason
+    pub fn try_from_listed_full_track(track: rspotify_model::FullTrack) -> Option<Self> {
+        let id = match track.linked_from {
+            Some(d) => d.id,
+            None => track.id?,
+        };
+        Some(Self {
+            id,
+            name: track.name,
+            artists: from_simplified_artists_to_artists(track.artists),
+            album: Album::try_from_simplified_album(track.album),
+            duration: track.duration.to_std().expect("valid chrono duration"),
+            explicit: track.explicit,
+            added_at: 0,
+            unavailable_reason: unavailable_reason(track.is_playable, track.restrictions),
+            disc_number: track.disc_number,
+            tempo: None,
+            popularity: Some(track.popularity),
+        })
     }
 }
 
@@ -305,6 +538,19 @@ impl std::fmt::Display for Track {
     }
 }
 
+impl From<rspotify_model::FullTrack> for TrackCredits {
+    fn from(track: rspotify_model::FullTrack) -> Self {
+        Self {
+            artists: track.artists.into_iter().map(|a| a.name).collect(),
+            album: track.album.name,
+            isrc: track.external_ids.get("isrc").cloned(),
+            disc_number: track.disc_number,
+            track_number: track.track_number,
+            available_markets: track.available_markets,
+        }
+    }
+}
+
 impl Album {
     /// tries to convert from a `rspotify_model::SimplifiedAlbum` into `Album`
     pub fn try_from_simplified_album(album: rspotify_model::SimplifiedAlbum) -> Option<Self> {
@@ -313,6 +559,7 @@ impl Album {
             name: album.name,
             release_date: album.release_date.unwrap_or_default(),
             artists: from_simplified_artists_to_artists(album.artists),
+            image_url: smallest_image_url(album.images),
         })
     }
 
@@ -333,6 +580,7 @@ impl From<rspotify_model::FullAlbum> for Album {
             id: album.id,
             release_date: album.release_date,
             artists: from_simplified_artists_to_artists(album.artists),
+            image_url: smallest_image_url(album.images),
         }
     }
 }
@@ -355,6 +603,9 @@ impl Artist {
         Some(Self {
             id: artist.id?,
             name: artist.name,
+            followers: None,
+            popularity: None,
+            genres: vec![],
         })
     }
 }
@@ -364,6 +615,9 @@ impl From<rspotify_model::FullArtist> for Artist {
         Self {
             name: artist.name,
             id: artist.id,
+            followers: Some(artist.followers.total),
+            popularity: Some(artist.popularity),
+            genres: artist.genres,
         }
     }
 }
@@ -374,6 +628,46 @@ impl std::fmt::Display for Artist {
     }
 }
 
+/// a helper function to get the reason why a track is unplayable in the user's market
+/// based on the track's `is_playable` and `restrictions` fields.
+/// These fields are only returned by the API when a market is specified in the request.
+fn unavailable_reason(
+    is_playable: Option<bool>,
+    restrictions: Option<rspotify_model::Restriction>,
+) -> Option<String> {
+    if is_playable.unwrap_or(true) {
+        return None;
+    }
+    let reason = match restrictions.map(|r| r.reason) {
+        Some(rspotify_model::RestrictionReason::Market) => "not available in your market",
+        Some(rspotify_model::RestrictionReason::Product) => "not available for your product",
+        Some(rspotify_model::RestrictionReason::Explicit) => "explicit content restricted",
+        None => "unavailable",
+    };
+    Some(reason.to_string())
+}
+
+/// Group consecutive tracks of a track table by their albums.
+/// Tracks of a single album are grouped by their discs instead.
+pub fn group_tracks<'a>(tracks: &[&'a Track]) -> Vec<TrackGroup<'a>> {
+    let by_disc = tracks
+        .windows(2)
+        .all(|w| w[0].album.as_ref().map(|a| &a.id) == w[1].album.as_ref().map(|a| &a.id));
+
+    let mut groups: Vec<TrackGroup> = vec![];
+    for &track in tracks {
+        let name = track.group_name(by_disc);
+        match groups.last_mut() {
+            Some(group) if group.name == name => group.tracks.push(track),
+            _ => groups.push(TrackGroup {
+                name,
+                tracks: vec![track],
+            }),
+        }
+    }
+    groups
+}
+
 /// a helper function to convert a vector of `rspotify_model::SimplifiedArtist`
 /// into a vector of `Artist`.
 fn from_simplified_artists_to_artists(
@@ -385,6 +679,14 @@ fn from_simplified_artists_to_artists(
         .collect()
 }
 
+/// a helper function to get the URL of the smallest image in a list of images
+fn smallest_image_url(images: Vec<rspotify_model::Image>) -> Option<String> {
+    images
+        .into_iter()
+        .min_by_key(|image| image.width.unwrap_or(u32::MAX))
+        .map(|image| image.url)
+}
+
 impl From<rspotify_model::SimplifiedPlaylist> for Playlist {
     fn from(playlist: rspotify_model::SimplifiedPlaylist) -> Self {
         Self {
@@ -396,6 +698,7 @@ impl From<rspotify_model::SimplifiedPlaylist> for Playlist {
                 playlist.owner.id,
             ),
             desc: String::new(),
+            snapshot_id: playlist.snapshot_id,
         }
     }
 }
@@ -417,10 +720,71 @@ impl From<rspotify_model::FullPlaylist> for Playlist {
                 playlist.owner.id,
             ),
             desc,
+            snapshot_id: playlist.snapshot_id,
+        }
+    }
+}
+
+impl Episode {
+    /// Construct an episode of a show from a simplified episode returned by the show's API
+    pub fn from_simplified_episode(
+        episode: rspotify_model::SimplifiedEpisode,
+        show_id: ShowId<'static>,
+        show_name: String,
+    ) -> Self {
+        Self {
+            id: episode.id,
+            name: episode.name,
+            show_id: Some(show_id),
+            show_name,
+            release_date: episode.release_date,
+            duration: episode.duration.to_std().expect("valid chrono duration"),
+            resume_position: resume_position(episode.resume_point),
         }
     }
 }
 
+impl From<rspotify_model::FullEpisode> for Episode {
+    fn from(episode: rspotify_model::FullEpisode) -> Self {
+        Self {
+            id: episode.id,
+            name: episode.name,
+            show_id: Some(episode.show.id),
+            show_name: episode.show.name,
+            release_date: episode.release_date,
+            duration: episode.duration.to_std().expect("valid chrono duration"),
+            resume_position: resume_position(episode.resume_point),
+        }
+    }
+}
+
+/// Get the position to resume a partially played episode from
+fn resume_position(
+    resume_point: Option<rspotify_model::ResumePoint>,
+) -> Option<std::time::Duration> {
+    resume_point
+        .filter(|p| !p.fully_played && p.resume_position > chrono::TimeDelta::zero())
+        .and_then(|p| p.resume_position.to_std().ok())
+}
+
+impl std::fmt::Display for Episode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} • {}", self.name, self.show_name)
+    }
+}
+
+impl std::fmt::Display for Chapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}. {}", self.chapter_number, self.name)
+    }
+}
+
+impl std::fmt::Display for PlayedTrack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.track.fmt(f)
+    }
+}
+
 impl std::fmt::Display for Playlist {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} • {}", self.name, self.owner.0)
@@ -481,6 +845,7 @@ impl Playback {
 
                 Playback::URIs(ids, Some(rspotify_model::Offset::Uri(uri)))
             }
+            Playback::Episode(..) => self.clone(),
         }
     }
 }
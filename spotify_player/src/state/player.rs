@@ -12,6 +12,10 @@ pub struct PlayerState {
     pub buffered_playback: Option<PlaybackMetadata>,
 
     pub queue: Option<rspotify_model::CurrentUserQueue>,
+
+    /// whether the (experimental) auto-mix mode is enabled on the integrated device
+    #[cfg(feature = "streaming")]
+    pub is_auto_mix_enabled: bool,
 }
 
 impl PlayerState {
@@ -46,6 +50,15 @@ impl PlayerState {
         Some(playback)
     }
 
+    /// Set the current playback's progress, which is used to update the progress bar
+    /// without waiting for the playback to be refreshed
+    pub fn set_playback_progress(&mut self, progress: chrono::Duration) {
+        if let Some(ref mut playback) = self.playback {
+            playback.progress = Some(progress);
+            self.playback_last_updated_time = Some(std::time::Instant::now());
+        }
+    }
+
     pub fn current_playing_track(&self) -> Option<&rspotify_model::FullTrack> {
         match self.playback {
             None => None,
@@ -56,6 +69,26 @@ impl PlayerState {
         }
     }
 
+    pub fn current_playing_episode(&self) -> Option<&rspotify_model::FullEpisode> {
+        match self.playback {
+            None => None,
+            Some(ref playback) => match playback.item {
+                Some(rspotify::model::PlayableItem::Episode(ref episode)) => Some(episode),
+                _ => None,
+            },
+        }
+    }
+
+    /// Get the URI of the currently playing track or episode
+    pub fn current_playing_item_uri(&self) -> Option<String> {
+        self.playback
+            .as_ref()?
+            .item
+            .as_ref()?
+            .id()
+            .map(|id| id.uri())
+    }
+
     pub fn playback_progress(&self) -> Option<chrono::Duration> {
         match self.playback {
             None => None,
@@ -74,6 +107,32 @@ impl PlayerState {
         }
     }
 
+    /// Get the number of items in the queue and the total remaining play time of the current
+    /// playing item and the queue's items
+    pub fn queue_remaining(&self) -> Option<(usize, chrono::Duration)> {
+        let queue = self.queue.as_ref()?;
+        let duration = |item: &rspotify_model::PlayableItem| match item {
+            rspotify_model::PlayableItem::Track(track) => track.duration,
+            rspotify_model::PlayableItem::Episode(episode) => episode.duration,
+        };
+
+        let current_remaining = match (
+            self.playback.as_ref().and_then(|p| p.item.as_ref()),
+            self.playback_progress(),
+        ) {
+            (Some(item), Some(progress)) => {
+                std::cmp::max(duration(item) - progress, chrono::Duration::zero())
+            }
+            _ => chrono::Duration::zero(),
+        };
+        let remaining = queue
+            .queue
+            .iter()
+            .map(duration)
+            .fold(current_remaining, |acc, d| acc + d);
+        Some((queue.queue.len(), remaining))
+    }
+
     pub fn playing_context_id(&self) -> Option<ContextId> {
         match self.playback {
             Some(ref playback) => match playback.context {
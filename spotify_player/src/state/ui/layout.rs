@@ -0,0 +1,156 @@
+use anyhow::Result;
+
+use super::*;
+use crate::config;
+
+pub use crate::config::WindowSizes;
+
+/// the minimum width percentage of a side-by-side window
+const MIN_WINDOW_PERCENTAGE: u16 = 10;
+/// the minimum height of a resizable window
+const MIN_WINDOW_HEIGHT: u16 = 3;
+
+impl WindowSizes {
+    /// The playback window's height (excluding borders)
+    pub fn playback_window(&self) -> usize {
+        self.playback_window
+            .unwrap_or(config::get_config().app_config.playback_window_width)
+    }
+
+    /// Grow (or shrink if `delta` is negative) the playback window,
+    /// keeping the window within the given frame height
+    pub fn resize_playback_window(&mut self, delta: i32, frame_height: u16) {
+        let height = (self.playback_window() as i32 + delta)
+            .min(max_window_height(frame_height))
+            .max(MIN_WINDOW_HEIGHT as i32);
+        self.playback_window = Some(height as usize);
+    }
+
+    /// Grow (or shrink if `delta` is negative) the focused window of a page,
+    /// keeping the window within the given frame height.
+    ///
+    /// Returns `false` if the page doesn't have resizable windows.
+    pub fn resize_focused_window(
+        &mut self,
+        page: &PageState,
+        delta: i32,
+        frame_height: u16,
+    ) -> bool {
+        match page {
+            PageState::Library { state } => {
+                let id = match state.focus {
+                    LibraryFocusState::Playlists => 0,
+                    LibraryFocusState::SavedAlbums => 1,
+                    // the followed artists and saved episodes share the last window
+                    LibraryFocusState::FollowedArtists | LibraryFocusState::SavedEpisodes => 2,
+                };
+                resize_library_window(&mut self.library_windows, id, delta);
+            }
+            PageState::Context {
+                state: Some(ContextPageUIState::Artist { focus, .. }),
+                ..
+            } => match focus {
+                ArtistFocusState::TopTracks => {
+                    self.artist_top_tracks_window = (self.artist_top_tracks_window as i32 + delta)
+                        .min(max_window_height(frame_height))
+                        .max(MIN_WINDOW_HEIGHT as i32)
+                        as u16;
+                }
+                ArtistFocusState::Albums => {
+                    resize_percentage(&mut self.artist_albums_window, delta);
+                }
+                ArtistFocusState::RelatedArtists => {
+                    resize_percentage(&mut self.artist_albums_window, -delta);
+                }
+            },
+            PageState::Search { state, .. } => {
+                // grow a search result window towards the page's center
+                let (is_left, is_top) = match state.focus {
+                    SearchFocusState::Input => return false,
+                    SearchFocusState::Tracks => (true, true),
+                    SearchFocusState::Albums => (false, true),
+                    SearchFocusState::Artists => (true, false),
+                    SearchFocusState::Playlists => (false, false),
+                };
+                resize_percentage(
+                    &mut self.search_left_windows,
+                    if is_left { delta } else { -delta },
+                );
+                resize_percentage(
+                    &mut self.search_top_windows,
+                    if is_top { delta } else { -delta },
+                );
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Store the window sizes into the `window_sizes` and `playback_window_width` options
+    /// of the config file
+    pub fn store(&self) {
+        if let Err(err) = self.store_into_config_file() {
+            tracing::error!("Failed to store window sizes into the config file: {err:#}");
+        }
+    }
+
+    fn store_into_config_file(&self) -> Result<()> {
+        let path = &config::get_config().config_folder;
+        let sizes: toml_edit::DocumentMut = std::str::FromStr::from_str(&toml::to_string(self)?)?;
+        config::AppConfig::update_config_file_option(
+            path,
+            "window_sizes",
+            toml_edit::Item::Table(sizes.as_table().clone()),
+        )?;
+        if let Some(height) = self.playback_window {
+            config::AppConfig::update_config_file_option(
+                path,
+                "playback_window_width",
+                toml_edit::value(height as i64),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The maximum height of a resizable window, which leaves room for another window
+/// (including its borders) in a frame of the given height
+fn max_window_height(frame_height: u16) -> i32 {
+    frame_height as i32 - MIN_WINDOW_HEIGHT as i32 - 4
+}
+
+fn resize_percentage(percentage: &mut u16, delta: i32) {
+    *percentage = (*percentage as i32 + delta).clamp(
+        MIN_WINDOW_PERCENTAGE as i32,
+        (100 - MIN_WINDOW_PERCENTAGE) as i32,
+    ) as u16;
+}
+
+/// Grow (or shrink) the `id`-th library page's window by taking (or giving) widths
+/// from (or to) the other windows, one percent at a time
+fn resize_library_window(widths: &mut [u16; 3], id: usize, delta: i32) {
+    let others = (0..widths.len()).filter(|&i| i != id).collect::<Vec<_>>();
+    for _ in 0..delta.unsigned_abs() {
+        if delta > 0 {
+            let widest = *others
+                .iter()
+                .max_by_key(|&&i| widths[i])
+                .expect("non-empty");
+            if widths[widest] <= MIN_WINDOW_PERCENTAGE {
+                break;
+            }
+            widths[widest] -= 1;
+            widths[id] += 1;
+        } else {
+            if widths[id] <= MIN_WINDOW_PERCENTAGE {
+                break;
+            }
+            let narrowest = *others
+                .iter()
+                .min_by_key(|&&i| widths[i])
+                .expect("non-empty");
+            widths[id] -= 1;
+            widths[narrowest] += 1;
+        }
+    }
+}
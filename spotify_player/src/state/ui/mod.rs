@@ -1,15 +1,46 @@
-use crate::{config, key};
+use crate::{config, key, ui::single_line_input::LineInput};
 
 pub type UIStateGuard<'a> = parking_lot::MutexGuard<'a, UIState>;
 
+mod layout;
 mod page;
 mod popup;
 
 use super::*;
 
+pub use layout::*;
 pub use page::*;
 pub use popup::*;
 
+/// the maximum number of queries kept in the search history
+const SEARCH_HISTORY_LIMIT: usize = 100;
+
+/// the duration in which a toast is shown
+pub const TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// the duration in which the volume overlay is shown after a volume change
+pub const VOLUME_OVERLAY_DURATION: std::time::Duration = std::time::Duration::from_secs(1);
+
+#[derive(Debug)]
+/// A transient message shown on top of the application's layout
+pub struct Toast {
+    pub message: String,
+    pub created_at: std::time::Instant,
+}
+
+/// the maximum duration between two clicks on an item to be considered a double click
+pub const DOUBLE_CLICK_DURATION: std::time::Duration = std::time::Duration::from_millis(500);
+
+#[derive(Debug, Clone)]
+/// The area of the focused window's rows, used to handle mouse events on the window's items
+pub struct FocusedWindowArea {
+    pub rect: tui::layout::Rect,
+    pub n_items: usize,
+    /// the row positions of the items in a window whose items are interleaved
+    /// with non-selectable rows (e.g. group headers)
+    pub item_rows: Option<Vec<usize>>,
+}
+
 #[derive(Default, Debug)]
 pub struct ImageRenderInfo {
     pub url: String,
@@ -28,9 +59,63 @@ pub struct UIState {
     pub history: Vec<PageState>,
     pub popup: Option<PopupState>,
 
+    /// The queries submitted in the search page, used as the search input's history
+    pub search_history: Vec<String>,
+
     /// The rectangle representing the playback progress bar,
     /// which is mainly used to handle mouse click events (for seeking command)
     pub playback_progress_bar_rect: tui::layout::Rect,
+    /// The area of the focused window's rows in the last render (if any)
+    pub focused_window_area: Option<FocusedWindowArea>,
+    /// The time and position of the last mouse click, used to detect double clicks
+    pub last_mouse_click: Option<(std::time::Instant, u16, u16)>,
+
+    /// indicates if the whole terminal needs to be cleared and redrawn in the next render
+    pub needs_full_redraw: bool,
+
+    pub toast: Option<Toast>,
+
+    pub window_sizes: WindowSizes,
+
+    /// indicates if the playback window is focused instead of the current page's windows
+    pub is_playback_window_focused: bool,
+
+    /// the position in the focused track table where the visual selection starts,
+    /// if the visual selection is active
+    pub visual_selection_anchor: Option<usize>,
+
+    /// the time when the volume overlay was last shown (if any)
+    pub volume_overlay_shown_at: Option<std::time::Instant>,
+    /// the last rendered playback's volume and mute state, used to detect volume changes
+    pub last_volume: Option<(Option<u32>, Option<u32>)>,
+
+    /// indicates if album lists are rendered as a grid of albums instead of a list
+    pub is_album_grid_view: bool,
+    /// indicates if the detail panel of the selected item is shown
+    pub is_detail_panel_shown: bool,
+    /// indicates if the context page's track tables are grouped by albums (or discs)
+    pub is_track_table_grouped: bool,
+    /// the names of the collapsed groups in the current page's grouped track tables
+    pub collapsed_track_groups: std::collections::HashSet<String>,
+    /// the (inclusive) range of release years that tracks in the track tables are restricted to
+    pub track_year_filter: Option<(i32, i32)>,
+    /// the (inclusive) range of tempos in BPM that tracks in the track tables are restricted to
+    pub track_tempo_filter: Option<(u32, u32)>,
+    /// the last time the user moved the selection of a track table, before which
+    /// the selection may follow the playing track (see the `follow_playing_track` config option)
+    pub track_selection_moved_at: Option<std::time::Instant>,
+    /// the last time the user scrolled the lyric page, before which the page
+    /// isn't scrolled to the synced lyric's current line
+    #[cfg(feature = "lyric-finder")]
+    pub lyric_scrolled_at: Option<std::time::Instant>,
+    /// the passphrase to disable the party mode, which is enabled if the passphrase is set
+    pub party_mode_passphrase: Option<String>,
+    /// the guests' song requests waiting for the user to accept (or reject) them
+    pub guest_requests: Vec<Track>,
+
+    /// the cover image URLs of albums rendered in an album grid that are not yet in the image cache
+    #[cfg(feature = "image")]
+    pub missing_album_cover_urls: std::collections::HashSet<String>,
 
     #[cfg(feature = "image")]
     pub last_cover_image_render_info: ImageRenderInfo,
@@ -45,16 +130,152 @@ impl UIState {
         self.history.last_mut().expect("non-empty history")
     }
 
+    /// Update the search history, which is persisted into the file cache whenever it changes
+    pub fn set_search_history(&mut self, history: &[String]) {
+        let history = &history[history.len().saturating_sub(SEARCH_HISTORY_LIMIT)..];
+        if self.search_history == history {
+            return;
+        }
+        self.search_history = history.to_vec();
+
+        if let Err(err) = store_data_into_file_cache(
+            FileCacheKey::SearchHistory,
+            &config::get_config().cache_folder,
+            &self.search_history,
+        ) {
+            tracing::error!("Failed to store search history into the file cache: {err:#}");
+        }
+    }
+
     pub fn new_search_popup(&mut self) {
         self.current_page_mut().select(0);
         self.popup = Some(PopupState::Search {
-            query: "".to_owned(),
+            query: LineInput::default(),
         });
     }
 
+    /// Reject a command (or an action) disabled in the party mode with a toast
+    /// if the party mode is enabled. Returns whether it is rejected.
+    pub fn reject_in_party_mode(&mut self) -> bool {
+        if self.party_mode_passphrase.is_none() {
+            return false;
+        }
+        self.show_toast(crate::i18n::tr("Disabled in party mode"));
+        true
+    }
+
+    /// Reject a command disallowed in the kiosk mode with a toast if the kiosk mode is enabled.
+    /// Returns whether it is rejected.
+    pub fn reject_in_kiosk_mode(&mut self) -> bool {
+        if !config::get_config().app_config.kiosk_mode {
+            return false;
+        }
+        self.show_toast(crate::i18n::tr("Not available in kiosk mode"));
+        true
+    }
+
+    /// Show a toast with the given message, replacing the current toast (if any)
+    pub fn show_toast(&mut self, message: impl Into<String>) {
+        self.toast = Some(Toast {
+            message: message.into(),
+            created_at: std::time::Instant::now(),
+        });
+    }
+
+    /// Set the area of a window's rows if the window is focused, with the window's header
+    /// (if any) excluded from the area
+    pub fn set_focused_window_area(
+        &mut self,
+        rect: tui::layout::Rect,
+        is_focused: bool,
+        has_header: bool,
+        n_items: usize,
+    ) {
+        if is_focused {
+            let header_height = u16::from(has_header).min(rect.height);
+            self.focused_window_area = Some(FocusedWindowArea {
+                rect: tui::layout::Rect {
+                    y: rect.y + header_height,
+                    height: rect.height - header_height,
+                    ..rect
+                },
+                n_items,
+                item_rows: None,
+            });
+        }
+    }
+
+    /// Show the volume overlay, restarting its duration if it is already shown
+    pub fn show_volume_overlay(&mut self) {
+        self.volume_overlay_shown_at = Some(std::time::Instant::now());
+    }
+
     pub fn new_page(&mut self, page: PageState) {
         self.history.push(page);
+        self.reset_page_ui_states();
+    }
+
+    /// Swap the current page with the page at the given position of the history,
+    /// which becomes the current page without growing the history
+    pub fn swap_page(&mut self, index: usize) {
+        let last = self.history.len() - 1;
+        self.history.swap(index, last);
+        self.reset_page_ui_states();
+    }
+
+    /// Reset the states tied to the current page when moving to another page
+    fn reset_page_ui_states(&mut self) {
         self.popup = None;
+        self.is_playback_window_focused = false;
+        self.visual_selection_anchor = None;
+        self.collapsed_track_groups.clear();
+    }
+
+    /// The range of positions covered by the visual selection, if the visual selection is active
+    pub fn visual_selection_range(&mut self) -> Option<std::ops::RangeInclusive<usize>> {
+        let anchor = self.visual_selection_anchor?;
+        let selected = self.current_page_mut().selected()?;
+        Some(anchor.min(selected)..=anchor.max(selected))
+    }
+
+    /// Grow (or shrink if `delta` is negative) the playback window
+    pub fn resize_playback_window(&mut self, delta: i32) {
+        self.window_sizes
+            .resize_playback_window(delta, frame_height());
+        self.window_sizes.store();
+    }
+
+    /// Grow (or shrink if `delta` is negative) the focused window of the current page
+    pub fn resize_focused_window(&mut self, delta: i32) {
+        let page = self.history.last().expect("non-empty history");
+        if self
+            .window_sizes
+            .resize_focused_window(page, delta, frame_height())
+        {
+            self.window_sizes.store();
+        }
+    }
+
+    /// Focus the next window, cycling through the current page's windows and the playback window
+    pub fn focus_next_window(&mut self) {
+        self.visual_selection_anchor = None;
+        if self.is_playback_window_focused {
+            self.is_playback_window_focused = false;
+            self.current_page_mut().focus_first();
+        } else if !self.current_page_mut().next() {
+            self.is_playback_window_focused = true;
+        }
+    }
+
+    /// Focus the previous window, cycling through the current page's windows and the playback window
+    pub fn focus_previous_window(&mut self) {
+        self.visual_selection_anchor = None;
+        if self.is_playback_window_focused {
+            self.is_playback_window_focused = false;
+            self.current_page_mut().focus_last();
+        } else if !self.current_page_mut().previous() {
+            self.is_playback_window_focused = true;
+        }
     }
 
     pub fn new_radio_page(&mut self, uri: &str) {
@@ -68,6 +289,17 @@ impl UIState {
         });
     }
 
+    pub fn new_genre_page(&mut self, genre: &str) {
+        self.new_page(PageState::Context {
+            id: None,
+            context_page_type: ContextPageType::Browsing(super::ContextId::Tracks(TracksId::new(
+                format!("genre:{genre}"),
+                "Genre",
+            ))),
+            state: None,
+        });
+    }
+
     /// Return whether there exists a focused popup.
     ///
     /// Currently, only search popup is not focused when it's opened.
@@ -78,25 +310,124 @@ impl UIState {
         }
     }
 
-    /// Get a list of items possibly filtered by a search query if exists a search popup
-    pub fn search_filtered_items<'a, T: std::fmt::Display>(&self, items: &'a [T]) -> Vec<&'a T> {
+    /// Get the query of the search popup (if any), in lowercase
+    fn search_query(&self) -> Option<String> {
         match self.popup {
-            Some(PopupState::Search { ref query }) => {
-                let query = query.to_lowercase();
-                items
-                    .iter()
-                    .filter(|t| {
-                        if query.is_empty() {
-                            true
-                        } else {
-                            let t = t.to_string().to_lowercase();
-                            query.split(' ').any(|q| !q.is_empty() && t.contains(q))
-                        }
-                    })
-                    .collect::<Vec<_>>()
+            Some(PopupState::Search { ref query }) => Some(query.get_text().to_lowercase()),
+            _ => None,
+        }
+    }
+
+    /// Get a list of items possibly filtered by a search query if exists a search popup.
+    ///
+    /// By default, an item matches the query if it contains any of the query's words.
+    /// If the `fuzzy_search` config option is set, an item matches the query if it
+    /// fuzzy matches all of the query's words, and the items are ranked by their match scores.
+    pub fn search_filtered_items<'a, T: std::fmt::Display>(&self, items: &'a [T]) -> Vec<&'a T> {
+        let Some(query) = self.search_query().filter(|q| !q.trim().is_empty()) else {
+            return items.iter().collect::<Vec<_>>();
+        };
+
+        if config::get_config().app_config.fuzzy_search {
+            let mut items = items
+                .iter()
+                .filter_map(|t| {
+                    let t_str = t.to_string();
+                    let score = query
+                        .split_whitespace()
+                        .map(|q| crate::utils::fuzzy_match(&t_str, q).map(|(score, _)| score))
+                        .sum::<Option<i64>>()?;
+                    Some((score, t))
+                })
+                .collect::<Vec<_>>();
+            // the sort is stable, so items of the same score keep their order
+            items.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            items.into_iter().map(|(_, t)| t).collect::<Vec<_>>()
+        } else {
+            items
+                .iter()
+                .filter(|t| {
+                    let t = t.to_string().to_lowercase();
+                    query.split(' ').any(|q| !q.is_empty() && t.contains(q))
+                })
+                .collect::<Vec<_>>()
+        }
+    }
+
+    /// Get the positions (in characters) of a text's characters matching the search popup's
+    /// query (if any), which are highlighted when rendering the text
+    pub fn search_match_positions(&self, text: &str) -> Vec<usize> {
+        let Some(query) = self.search_query() else {
+            return vec![];
+        };
+
+        let mut positions = vec![];
+        if config::get_config().app_config.fuzzy_search {
+            for q in query.split_whitespace() {
+                if let Some((_, p)) = crate::utils::fuzzy_match(text, q) {
+                    positions.extend(p);
+                }
+            }
+        } else {
+            let text = text
+                .chars()
+                .map(|c| c.to_lowercase().next().unwrap_or(c))
+                .collect::<Vec<_>>();
+            for q in query.split_whitespace() {
+                let q = q.chars().collect::<Vec<_>>();
+                for i in 0..text.len().saturating_sub(q.len() - 1) {
+                    if text[i..i + q.len()] == q[..] {
+                        positions.extend(i..i + q.len());
+                    }
+                }
             }
-            _ => items.iter().collect::<Vec<_>>(),
         }
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
+    /// Check if the track tables are narrowed by the release year or tempo filters
+    pub fn has_track_filters(&self) -> bool {
+        self.track_year_filter.is_some() || self.track_tempo_filter.is_some()
+    }
+
+    /// Get a list of tracks filtered by a search query (if exists a search popup)
+    /// and by the release year and tempo filters (if set)
+    pub fn filtered_tracks<'a>(&self, tracks: &'a [Track]) -> Vec<&'a Track> {
+        let mut tracks = self.search_filtered_items(tracks);
+        if let Some((from, to)) = self.track_year_filter {
+            tracks.retain(|t| t.year().is_some_and(|year| (from..=to).contains(&year)));
+        }
+        // tracks with unknown tempos are hidden until their audio features are fetched
+        if let Some((from, to)) = self.track_tempo_filter {
+            tracks.retain(|t| {
+                t.tempo
+                    .is_some_and(|tempo| (from..=to).contains(&(tempo.round() as u32)))
+            });
+        }
+        tracks
+    }
+
+    /// Get a context page's track table items, which are the filtered tracks excluding
+    /// the tracks of collapsed groups if the track table is grouped.
+    ///
+    /// A collapsed group is represented by its first track.
+    pub fn track_table_items<'a>(&self, tracks: &'a [Track]) -> Vec<&'a Track> {
+        let tracks = self.filtered_tracks(tracks);
+        if !self.is_track_table_grouped {
+            return tracks;
+        }
+        group_tracks(&tracks)
+            .into_iter()
+            .flat_map(|group| {
+                if self.collapsed_track_groups.contains(&group.name) {
+                    group.tracks[..1].to_vec()
+                } else {
+                    group.tracks
+                }
+            })
+            .collect()
     }
 }
 
@@ -112,10 +443,47 @@ impl Default for UIState {
             }],
             popup: None,
 
+            search_history: vec![],
+
             playback_progress_bar_rect: Default::default(),
+            focused_window_area: None,
+            last_mouse_click: None,
+
+            needs_full_redraw: false,
+
+            toast: None,
+
+            window_sizes: WindowSizes::default(),
+
+            is_playback_window_focused: false,
 
+            visual_selection_anchor: None,
+
+            volume_overlay_shown_at: None,
+            last_volume: None,
+
+            is_album_grid_view: false,
+            is_detail_panel_shown: false,
+            is_track_table_grouped: false,
+            collapsed_track_groups: Default::default(),
+            track_year_filter: None,
+            track_tempo_filter: None,
+            track_selection_moved_at: None,
+            #[cfg(feature = "lyric-finder")]
+            lyric_scrolled_at: None,
+            party_mode_passphrase: None,
+            guest_requests: vec![],
+            #[cfg(feature = "image")]
+            missing_album_cover_urls: Default::default(),
             #[cfg(feature = "image")]
             last_cover_image_render_info: Default::default(),
         }
     }
 }
+
+/// The height of the terminal frame, used to bound the resizable windows
+fn frame_height() -> u16 {
+    crossterm::terminal::size()
+        .map(|(_, rows)| rows)
+        .unwrap_or(u16::MAX)
+}
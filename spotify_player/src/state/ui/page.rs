@@ -26,11 +26,28 @@ pub enum PageState {
         state: BrowsePageUIState,
     },
     Queue {
-        scroll_offset: usize,
+        queue_table: TableState,
+    },
+    RecentlyPlayed {
+        track_table: TableState,
+    },
+    Chapters {
+        audiobook_id: String,
+        audiobook_name: String,
+        chapter_table: TableState,
+    },
+    Show {
+        show_id: ShowId<'static>,
+        show_name: String,
+        episode_table: TableState,
     },
     CommandHelp {
         scroll_offset: usize,
     },
+    PlaylistComparison {
+        playlists: Box<(Playlist, Playlist)>,
+        state: PlaylistComparisonPageUIState,
+    },
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -42,7 +59,11 @@ pub enum PageType {
     #[cfg(feature = "lyric-finder")]
     Lyric,
     Queue,
+    RecentlyPlayed,
+    Chapters,
+    Show,
     CommandHelp,
+    PlaylistComparison,
 }
 
 #[derive(Clone, Debug)]
@@ -50,6 +71,7 @@ pub struct LibraryPageUIState {
     pub playlist_list: ListState,
     pub saved_album_list: ListState,
     pub followed_artist_list: ListState,
+    pub saved_episode_list: ListState,
     pub focus: LibraryFocusState,
 }
 
@@ -62,6 +84,14 @@ pub struct SearchPageUIState {
     pub focus: SearchFocusState,
 }
 
+#[derive(Clone, Debug)]
+pub struct PlaylistComparisonPageUIState {
+    pub first_only_list: ListState,
+    pub second_only_list: ListState,
+    pub common_list: ListState,
+    pub focus: PlaylistComparisonFocusState,
+}
+
 #[derive(Clone, Debug)]
 pub enum ContextPageType {
     CurrentPlaying,
@@ -92,6 +122,7 @@ pub enum LibraryFocusState {
     Playlists,
     SavedAlbums,
     FollowedArtists,
+    SavedEpisodes,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -101,6 +132,16 @@ pub enum ArtistFocusState {
     RelatedArtists,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlaylistComparisonFocusState {
+    /// tracks only in the first playlist
+    FirstOnly,
+    /// tracks only in the second playlist
+    SecondOnly,
+    /// tracks in both playlists
+    Common,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum SearchFocusState {
     Input,
@@ -119,6 +160,12 @@ pub enum BrowsePageUIState {
         category: Category,
         state: ListState,
     },
+    NewReleases {
+        state: ListState,
+    },
+    FeaturedPlaylists {
+        state: ListState,
+    },
 }
 
 pub enum MutableWindowState<'a> {
@@ -138,7 +185,11 @@ impl PageState {
             #[cfg(feature = "lyric-finder")]
             PageState::Lyric { .. } => PageType::Lyric,
             PageState::Queue { .. } => PageType::Queue,
+            PageState::RecentlyPlayed { .. } => PageType::RecentlyPlayed,
+            PageState::Chapters { .. } => PageType::Chapters,
+            PageState::Show { .. } => PageType::Show,
             PageState::CommandHelp { .. } => PageType::CommandHelp,
+            PageState::PlaylistComparison { .. } => PageType::PlaylistComparison,
         }
     }
 
@@ -164,6 +215,7 @@ impl PageState {
                         playlist_list,
                         saved_album_list,
                         followed_artist_list,
+                        saved_episode_list,
                         focus,
                     },
             } => Some(match focus {
@@ -172,6 +224,7 @@ impl PageState {
                 LibraryFocusState::FollowedArtists => {
                     MutableWindowState::List(followed_artist_list)
                 }
+                LibraryFocusState::SavedEpisodes => MutableWindowState::List(saved_episode_list),
             }),
             Self::Search {
                 state:
@@ -190,38 +243,40 @@ impl PageState {
                 SearchFocusState::Artists => Some(MutableWindowState::List(artist_list)),
                 SearchFocusState::Playlists => Some(MutableWindowState::List(playlist_list)),
             },
-            Self::Context { state, .. } => state.as_mut().map(|state| match state {
-                ContextPageUIState::Tracks { track_table } => {
-                    MutableWindowState::Table(track_table)
-                }
-                ContextPageUIState::Playlist { track_table } => {
-                    MutableWindowState::Table(track_table)
-                }
-                ContextPageUIState::Album { track_table } => MutableWindowState::Table(track_table),
-                ContextPageUIState::Artist {
-                    top_track_table,
-                    album_list,
-                    related_artist_list,
-                    focus,
-                } => match focus {
-                    ArtistFocusState::TopTracks => MutableWindowState::Table(top_track_table),
-                    ArtistFocusState::Albums => MutableWindowState::List(album_list),
-                    ArtistFocusState::RelatedArtists => {
-                        MutableWindowState::List(related_artist_list)
-                    }
-                },
-            }),
+            Self::Context { state, .. } => state
+                .as_mut()
+                .map(ContextPageUIState::focus_window_state_mut),
             Self::Browse { state } => match state {
-                BrowsePageUIState::CategoryList { state } => Some(MutableWindowState::List(state)),
+                BrowsePageUIState::CategoryList { state }
+                | BrowsePageUIState::NewReleases { state }
+                | BrowsePageUIState::FeaturedPlaylists { state } => {
+                    Some(MutableWindowState::List(state))
+                }
                 BrowsePageUIState::CategoryPlaylistList { state, .. } => {
                     Some(MutableWindowState::List(state))
                 }
             },
             #[cfg(feature = "lyric-finder")]
             Self::Lyric { scroll_offset, .. } => Some(MutableWindowState::Scroll(scroll_offset)),
-            Self::CommandHelp { scroll_offset } | Self::Queue { scroll_offset } => {
-                Some(MutableWindowState::Scroll(scroll_offset))
-            }
+            Self::RecentlyPlayed { track_table } => Some(MutableWindowState::Table(track_table)),
+            Self::Chapters { chapter_table, .. } => Some(MutableWindowState::Table(chapter_table)),
+            Self::Show { episode_table, .. } => Some(MutableWindowState::Table(episode_table)),
+            Self::Queue { queue_table } => Some(MutableWindowState::Table(queue_table)),
+            Self::CommandHelp { scroll_offset } => Some(MutableWindowState::Scroll(scroll_offset)),
+            Self::PlaylistComparison {
+                state:
+                    PlaylistComparisonPageUIState {
+                        first_only_list,
+                        second_only_list,
+                        common_list,
+                        focus,
+                    },
+                ..
+            } => Some(MutableWindowState::List(match focus {
+                PlaylistComparisonFocusState::FirstOnly => first_only_list,
+                PlaylistComparisonFocusState::SecondOnly => second_only_list,
+                PlaylistComparisonFocusState::Common => common_list,
+            })),
         }
     }
 }
@@ -232,6 +287,7 @@ impl LibraryPageUIState {
             playlist_list: utils::new_list_state(),
             saved_album_list: utils::new_list_state(),
             followed_artist_list: utils::new_list_state(),
+            saved_episode_list: utils::new_list_state(),
             focus: LibraryFocusState::Playlists,
         }
     }
@@ -249,6 +305,17 @@ impl SearchPageUIState {
     }
 }
 
+impl PlaylistComparisonPageUIState {
+    pub fn new() -> Self {
+        Self {
+            first_only_list: utils::new_list_state(),
+            second_only_list: utils::new_list_state(),
+            common_list: utils::new_list_state(),
+            focus: PlaylistComparisonFocusState::FirstOnly,
+        }
+    }
+}
+
 impl ContextPageType {
     pub fn title(&self) -> String {
         match self {
@@ -290,6 +357,25 @@ impl ContextPageUIState {
             track_table: utils::new_table_state(),
         }
     }
+
+    /// The currently focused window state of the context page.
+    pub fn focus_window_state_mut(&mut self) -> MutableWindowState<'_> {
+        match self {
+            Self::Tracks { track_table } => MutableWindowState::Table(track_table),
+            Self::Playlist { track_table } => MutableWindowState::Table(track_table),
+            Self::Album { track_table } => MutableWindowState::Table(track_table),
+            Self::Artist {
+                top_track_table,
+                album_list,
+                related_artist_list,
+                focus,
+            } => match focus {
+                ArtistFocusState::TopTracks => MutableWindowState::Table(top_track_table),
+                ArtistFocusState::Albums => MutableWindowState::List(album_list),
+                ArtistFocusState::RelatedArtists => MutableWindowState::List(related_artist_list),
+            },
+        }
+    }
 }
 
 impl<'a> MutableWindowState<'a> {
@@ -312,100 +398,131 @@ impl<'a> MutableWindowState<'a> {
     }
 }
 
+/// A state with windows that can be focused in order
 pub trait Focusable {
-    fn next(&mut self);
-    fn previous(&mut self);
+    /// Focus the next window, returning `false` if the last window is already focused
+    fn next(&mut self) -> bool;
+    /// Focus the previous window, returning `false` if the first window is already focused
+    fn previous(&mut self) -> bool;
+    fn focus_first(&mut self);
+    fn focus_last(&mut self);
 }
 
-impl Focusable for PageState {
-    fn next(&mut self) {
+impl PageState {
+    /// The focus state of the page's windows, if the page has multiple windows
+    fn focus_state_mut(&mut self) -> Option<&mut dyn Focusable> {
         match self {
             Self::Search {
                 state: SearchPageUIState { focus, .. },
                 ..
-            } => focus.next(),
+            } => Some(focus),
             Self::Library {
                 state: LibraryPageUIState { focus, .. },
                 ..
-            } => focus.next(),
+            } => Some(focus),
             Self::Context {
                 state: Some(ContextPageUIState::Artist { focus, .. }),
                 ..
-            } => focus.next(),
-            _ => {}
+            } => Some(focus),
+            Self::PlaylistComparison {
+                state: PlaylistComparisonPageUIState { focus, .. },
+                ..
+            } => Some(focus),
+            _ => None,
         }
+    }
 
-        // reset the list/table state of the focus window
+    /// Reset the list/table state of the focused window
+    fn reset_focus_window_state(&mut self) {
         if let Some(mut state) = self.focus_window_state_mut() {
             state.select(0)
         }
     }
+}
 
-    fn previous(&mut self) {
-        match self {
-            Self::Search {
-                state: SearchPageUIState { focus, .. },
-                ..
-            } => focus.previous(),
-            Self::Library {
-                state: LibraryPageUIState { focus, .. },
-                ..
-            } => focus.previous(),
-            Self::Context {
-                state: Some(ContextPageUIState::Artist { focus, .. }),
-                ..
-            } => focus.previous(),
-            _ => {}
+impl Focusable for PageState {
+    fn next(&mut self) -> bool {
+        let moved = self.focus_state_mut().is_some_and(|focus| focus.next());
+        if moved {
+            self.reset_focus_window_state();
         }
+        moved
+    }
 
-        // reset the list/table state of the focus window
-        if let Some(mut state) = self.focus_window_state_mut() {
-            state.select(0)
+    fn previous(&mut self) -> bool {
+        let moved = self.focus_state_mut().is_some_and(|focus| focus.previous());
+        if moved {
+            self.reset_focus_window_state();
+        }
+        moved
+    }
+
+    fn focus_first(&mut self) {
+        if let Some(focus) = self.focus_state_mut() {
+            focus.focus_first();
+            self.reset_focus_window_state();
+        }
+    }
+
+    fn focus_last(&mut self) {
+        if let Some(focus) = self.focus_state_mut() {
+            focus.focus_last();
+            self.reset_focus_window_state();
         }
     }
 }
 
 macro_rules! impl_focusable {
-	($struct:ty, $([$field:ident, $next_field:ident]),+) => {
-		impl Focusable for $struct {
-            fn next(&mut self) {
-                *self = match self {
-                    $(
-                        Self::$field => Self::$next_field,
-                    )+
-                };
+    ($struct:ty, [$($field:ident),+]) => {
+        impl $struct {
+            const WINDOWS: &'static [$struct] = &[$(Self::$field),+];
+        }
+
+        impl Focusable for $struct {
+            fn next(&mut self) -> bool {
+                match Self::WINDOWS.iter().position(|w| w == self) {
+                    Some(i) if i + 1 < Self::WINDOWS.len() => {
+                        *self = Self::WINDOWS[i + 1];
+                        true
+                    }
+                    _ => false,
+                }
             }
 
-            fn previous(&mut self) {
-                *self = match self {
-                    $(
-                        Self::$next_field => Self::$field,
-                    )+
-                };
+            fn previous(&mut self) -> bool {
+                match Self::WINDOWS.iter().position(|w| w == self) {
+                    Some(i) if i > 0 => {
+                        *self = Self::WINDOWS[i - 1];
+                        true
+                    }
+                    _ => false,
+                }
+            }
+
+            fn focus_first(&mut self) {
+                *self = Self::WINDOWS[0];
+            }
+
+            fn focus_last(&mut self) {
+                *self = Self::WINDOWS[Self::WINDOWS.len() - 1];
             }
         }
-	};
+    };
 }
 
 impl_focusable!(
     LibraryFocusState,
-    [Playlists, SavedAlbums],
-    [SavedAlbums, FollowedArtists],
-    [FollowedArtists, Playlists]
+    [Playlists, SavedAlbums, FollowedArtists, SavedEpisodes]
 );
 
+impl_focusable!(ArtistFocusState, [TopTracks, Albums, RelatedArtists]);
+
 impl_focusable!(
-    ArtistFocusState,
-    [TopTracks, Albums],
-    [Albums, RelatedArtists],
-    [RelatedArtists, TopTracks]
+    PlaylistComparisonFocusState,
+    [FirstOnly, SecondOnly, Common]
 );
 
 impl_focusable!(
     SearchFocusState,
-    [Input, Tracks],
-    [Tracks, Albums],
-    [Albums, Artists],
-    [Artists, Playlists],
-    [Playlists, Input]
+    [Input, Tracks, Albums, Artists, Playlists]
 );
@@ -7,10 +7,16 @@ pub enum PlaylistCreateCurrentField {
     Desc,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthenticateCurrentField {
+    Username,
+    Password,
+}
+
 #[derive(Debug)]
 pub enum PopupState {
     Search {
-        query: String,
+        query: LineInput,
     },
     UserPlaylistList(PlaylistPopupAction, ListState),
     UserFollowedArtistList(ListState),
@@ -18,12 +24,65 @@ pub enum PopupState {
     DeviceList(ListState),
     ArtistList(ArtistPopupAction, Vec<Artist>, ListState),
     ThemeList(Vec<crate::config::Theme>, ListState),
+    GenreList(GenrePopupAction, Vec<String>, ListState),
     ActionList(ActionListItem, ListState),
     PlaylistCreate {
         name: LineInput,
         desc: LineInput,
         current_field: PlaylistCreateCurrentField,
     },
+    BookmarkCreate {
+        /// the URI of the bookmarked track or episode
+        uri: String,
+        position: std::time::Duration,
+        name: LineInput,
+    },
+    /// a popup showing the credits of a track
+    TrackCredits(Track),
+    /// a popup to enter the range of release years to filter the track tables by
+    TrackYearFilter {
+        years: LineInput,
+    },
+    /// a popup to enter the range of tempos (in BPM) to filter the track tables by
+    TrackTempoFilter {
+        tempos: LineInput,
+    },
+    /// a popup to enter the position (e.g. `1:30`) to seek the current playback to
+    SeekPosition {
+        position: LineInput,
+    },
+    /// a popup to enter the number of the track to select in the context's track table
+    TrackNumber {
+        number: LineInput,
+    },
+    /// a popup to re-authenticate the application, shown when the cached credentials are invalid
+    Authenticate {
+        username: LineInput,
+        password: LineInput,
+        current_field: AuthenticateCurrentField,
+    },
+    /// a popup to enter the passphrase to enable (or disable) the party mode
+    PartyModePassphrase {
+        passphrase: LineInput,
+    },
+    /// a popup listing the guests' song requests waiting for moderation
+    GuestRequestList(ListState),
+    /// a popup listing the most skipped tracks of a context (with their skip counts),
+    /// preceded by bulk actions on the tracks
+    SkippedTrackList {
+        actions: Vec<SkippedTracksAction>,
+        tracks: Vec<(Track, u32)>,
+        list_state: ListState,
+    },
+    /// a popup to remove multiple playlists, albums and artists from the library at once,
+    /// listing the library's items preceded by an entry removing the marked items
+    LibraryCleanup {
+        items: Vec<Item>,
+        marked: Vec<bool>,
+        /// whether the removal of the marked items is waiting for a confirmation
+        confirming: bool,
+        list_state: ListState,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -32,13 +91,21 @@ pub enum ActionListItem {
     Artist(Artist, Vec<command::ArtistAction>),
     Album(Album, Vec<command::AlbumAction>),
     Playlist(Playlist, Vec<command::PlaylistAction>),
+    Episode(Episode, Vec<command::EpisodeAction>),
 }
 
 /// An action on an item in a playlist popup list
 #[derive(Debug)]
 pub enum PlaylistPopupAction {
     Browse,
-    AddTrack(TrackId<'static>),
+    AddTrack(Box<Track>),
+    AddTracks(Vec<Track>),
+    /// choose a playlist to compare with the given playlist
+    Compare(Box<Playlist>),
+    /// choose a playlist whose tracks are merged into another playlist
+    MergeFrom,
+    /// choose a playlist to merge the given playlist's tracks into
+    MergeInto(Box<Playlist>),
 }
 
 /// An action on an item in an artist popup list
@@ -48,6 +115,20 @@ pub enum ArtistPopupAction {
     ShowActions,
 }
 
+/// A bulk action on the tracks in a skipped track popup list
+#[derive(Clone, Debug)]
+pub enum SkippedTracksAction {
+    RemoveFromPlaylist(PlaylistId<'static>),
+    AddToBlocklist,
+}
+
+/// An action on an item in a genre popup list
+#[derive(Copy, Clone, Debug)]
+pub enum GenrePopupAction {
+    Search,
+    Browse,
+}
+
 impl PopupState {
     /// gets the (immutable) list state of a (list) popup
     pub fn list_state(&self) -> Option<&ListState> {
@@ -58,8 +139,22 @@ impl PopupState {
             Self::UserSavedAlbumList(list_state) => Some(list_state),
             Self::ArtistList(.., list_state) => Some(list_state),
             Self::ThemeList(.., list_state) => Some(list_state),
+            Self::GenreList(.., list_state) => Some(list_state),
             Self::ActionList(.., list_state) => Some(list_state),
-            Self::Search { .. } | Self::PlaylistCreate { .. } => None,
+            Self::GuestRequestList(list_state) => Some(list_state),
+            Self::SkippedTrackList { list_state, .. } | Self::LibraryCleanup { list_state, .. } => {
+                Some(list_state)
+            }
+            Self::Search { .. }
+            | Self::PlaylistCreate { .. }
+            | Self::BookmarkCreate { .. }
+            | Self::TrackCredits(_)
+            | Self::TrackYearFilter { .. }
+            | Self::TrackTempoFilter { .. }
+            | Self::SeekPosition { .. }
+            | Self::TrackNumber { .. }
+            | Self::Authenticate { .. }
+            | Self::PartyModePassphrase { .. } => None,
         }
     }
 
@@ -72,8 +167,22 @@ impl PopupState {
             Self::UserSavedAlbumList(list_state) => Some(list_state),
             Self::ArtistList(.., list_state) => Some(list_state),
             Self::ThemeList(.., list_state) => Some(list_state),
+            Self::GenreList(.., list_state) => Some(list_state),
             Self::ActionList(.., list_state) => Some(list_state),
-            Self::Search { .. } | Self::PlaylistCreate { .. } => None,
+            Self::GuestRequestList(list_state) => Some(list_state),
+            Self::SkippedTrackList { list_state, .. } | Self::LibraryCleanup { list_state, .. } => {
+                Some(list_state)
+            }
+            Self::Search { .. }
+            | Self::PlaylistCreate { .. }
+            | Self::BookmarkCreate { .. }
+            | Self::TrackCredits(_)
+            | Self::TrackYearFilter { .. }
+            | Self::TrackTempoFilter { .. }
+            | Self::SeekPosition { .. }
+            | Self::TrackNumber { .. }
+            | Self::Authenticate { .. }
+            | Self::PartyModePassphrase { .. } => None,
         }
     }
 
@@ -101,6 +210,38 @@ impl ActionListItem {
             ActionListItem::Artist(.., actions) => actions.len(),
             ActionListItem::Album(.., actions) => actions.len(),
             ActionListItem::Playlist(.., actions) => actions.len(),
+            ActionListItem::Episode(.., actions) => actions.len(),
+        }
+    }
+
+    /// checks if the `n`-th action is disabled in the party mode,
+    /// i.e. it edits a playlist or modifies the user's library
+    pub fn is_action_disabled_in_party_mode(&self, n: usize) -> bool {
+        match self {
+            ActionListItem::Track(.., actions) => matches!(
+                actions[n],
+                command::TrackAction::AddToPlaylist
+                    | command::TrackAction::DeleteFromCurrentPlaylist
+                    | command::TrackAction::AddToLikedTracks
+                    | command::TrackAction::DeleteFromLikedTracks
+            ),
+            ActionListItem::Artist(.., actions) => {
+                matches!(
+                    actions[n],
+                    command::ArtistAction::Follow | command::ArtistAction::Unfollow
+                )
+            }
+            ActionListItem::Album(.., actions) => matches!(
+                actions[n],
+                command::AlbumAction::AddToLibrary | command::AlbumAction::DeleteFromLibrary
+            ),
+            ActionListItem::Playlist(.., actions) => matches!(
+                actions[n],
+                command::PlaylistAction::AddToLibrary | command::PlaylistAction::DeleteFromLibrary
+            ),
+            ActionListItem::Episode(.., actions) => {
+                matches!(actions[n], command::EpisodeAction::DeleteFromSavedEpisodes)
+            }
         }
     }
 
@@ -110,6 +251,7 @@ impl ActionListItem {
             ActionListItem::Artist(artist, ..) => &artist.name,
             ActionListItem::Album(album, ..) => &album.name,
             ActionListItem::Playlist(playlist, ..) => &playlist.name,
+            ActionListItem::Episode(episode, ..) => &episode.name,
         }
     }
 
@@ -127,6 +269,9 @@ impl ActionListItem {
             ActionListItem::Playlist(.., actions) => {
                 actions.iter().map(|a| format!("{a:?}")).collect::<Vec<_>>()
             }
+            ActionListItem::Episode(.., actions) => {
+                actions.iter().map(|a| format!("{a:?}")).collect::<Vec<_>>()
+            }
         }
     }
 }
@@ -1,18 +1,34 @@
-use crate::{client::Client, config, state::SharedState};
+use crate::{
+    client::{Client, ClientRequest, PlayerRequest},
+    config,
+    state::SharedState,
+};
 use librespot_connect::spirc::Spirc;
 use librespot_core::{
     config::{ConnectConfig, DeviceType},
     spotify_id,
 };
-use librespot_playback::mixer::MixerConfig;
+use librespot_playback::mixer::{MixerConfig, VolumeGetter};
 use librespot_playback::{
     audio_backend,
     config::{AudioFormat, Bitrate, PlayerConfig},
     mixer::{self, Mixer},
     player,
 };
-use rspotify::model::TrackId;
+use rspotify::model::{AudioFeatures, Modality, TrackId};
 use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Weak,
+};
+
+/// the interval to update the volume fades of the auto-mix mode
+const AUTO_MIX_FADE_UPDATE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// the tempo difference (in BPM) considered as distant as one step on the Camelot wheel
+/// when ordering tracks for the auto-mix mode
+const AUTO_MIX_BPM_PER_KEY_STEP: f32 = 4.0;
 
 #[cfg(not(any(
     feature = "rodio-backend",
@@ -159,6 +175,94 @@ fn execute_player_event_hook_command(
     Ok(())
 }
 
+#[derive(Default)]
+/// A helper struct to prevent the system from sleeping while the integrated device is playing.
+///
+/// The inhibition is held by a child process, which exits when being released
+/// or when the application exits. The child process is spawned in its own process group,
+/// so that releasing the inhibition also kills the helper processes it spawns.
+struct SleepInhibitor {
+    child: Option<std::process::Child>,
+}
+
+impl SleepInhibitor {
+    /// gets the command to spawn a process holding a sleep inhibition
+    fn command() -> Option<std::process::Command> {
+        let pid = std::process::id().to_string();
+        if cfg!(target_os = "linux") {
+            // use a logind inhibitor, which lasts until the application's process exits
+            let mut cmd = std::process::Command::new("systemd-inhibit");
+            cmd.args([
+                "--what=sleep:idle",
+                "--who=spotify_player",
+                "--why=Playing music",
+                "--mode=block",
+                "tail",
+                &format!("--pid={pid}"),
+                "-f",
+                "/dev/null",
+            ]);
+            Some(cmd)
+        } else if cfg!(target_os = "macos") {
+            let mut cmd = std::process::Command::new("caffeinate");
+            cmd.args(["-i", "-w", &pid]);
+            Some(cmd)
+        } else {
+            None
+        }
+    }
+
+    fn inhibit(&mut self) {
+        if self.child.is_some() {
+            return;
+        }
+        let mut cmd = match Self::command() {
+            Some(cmd) => cmd,
+            None => {
+                tracing::warn!("Inhibiting system sleep is not supported on the current platform");
+                return;
+            }
+        };
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+        match cmd
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        {
+            Ok(child) => {
+                tracing::info!("Inhibited system sleep while playing");
+                self.child = Some(child);
+            }
+            Err(err) => tracing::warn!("Failed to inhibit system sleep: {err:#}"),
+        }
+    }
+
+    fn release(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            // kill the whole process group of the child, e.g. the `tail` process spawned by `systemd-inhibit`
+            #[cfg(unix)]
+            let result = match unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGTERM) } {
+                0 => Ok(()),
+                _ => Err(std::io::Error::last_os_error()),
+            };
+            #[cfg(not(unix))]
+            let result = child.kill();
+            if let Err(err) = result.and_then(|_| child.wait()) {
+                tracing::warn!("Failed to release the system sleep inhibition: {err:#}");
+            } else {
+                tracing::info!("Released the system sleep inhibition");
+            }
+        }
+    }
+}
+
+impl Drop for SleepInhibitor {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
 /// Create a new streaming connection
 pub async fn new_connection(client: Client, state: SharedState) -> Spirc {
     let session = client.session().await;
@@ -187,6 +291,14 @@ pub async fn new_connection(client: Client, state: SharedState) -> Spirc {
         Box::new(mixer::softmixer::SoftMixer::open(MixerConfig::default())) as Box<dyn Mixer>;
     mixer.set_volume(volume);
 
+    // the fading factor of the auto-mix mode, stored as `f64` bits like `librespot`'s soft volume
+    let fade = Arc::new(AtomicU64::new(1.0_f64.to_bits()));
+    tokio::task::spawn(update_auto_mix_fade(
+        state.clone(),
+        session.device_id().to_string(),
+        Arc::downgrade(&fade),
+    ));
+
     let backend = audio_backend::find(None).expect("should be able to find an audio backend");
     let player_config = PlayerConfig {
         bitrate: device
@@ -195,6 +307,7 @@ pub async fn new_connection(client: Client, state: SharedState) -> Spirc {
             .parse::<Bitrate>()
             .unwrap_or_default(),
         normalisation: device.normalization,
+        gapless: device.gapless,
         ..Default::default()
     };
 
@@ -206,13 +319,26 @@ pub async fn new_connection(client: Client, state: SharedState) -> Spirc {
     let (player, mut channel) = player::Player::new(
         player_config,
         session.clone(),
-        mixer.get_soft_volume(),
+        Box::new(FadingVolume {
+            volume: mixer.get_soft_volume(),
+            fade,
+        }),
         move || backend(None, AudioFormat::default()),
     );
 
     let player_event_task = tokio::task::spawn({
         async move {
+            let mut sleep_inhibitor = SleepInhibitor::default();
             while let Some(event) = channel.recv().await {
+                if configs.app_config.inhibit_sleep_while_playing {
+                    match event {
+                        player::PlayerEvent::Playing { .. } => sleep_inhibitor.inhibit(),
+                        player::PlayerEvent::Paused { .. }
+                        | player::PlayerEvent::Stopped { .. } => sleep_inhibitor.release(),
+                        _ => {}
+                    }
+                }
+
                 match PlayerEvent::from_librespot_player_event(event) {
                     Err(err) => {
                         tracing::warn!("Failed to convert a `librespot` player event into `spotify_player` player event: {err:#}");
@@ -263,3 +389,190 @@ pub async fn new_connection(client: Client, state: SharedState) -> Spirc {
 
     spirc
 }
+
+/// A volume getter that attenuates the mixer's volume by the fading factor of the auto-mix mode
+struct FadingVolume {
+    volume: Box<dyn VolumeGetter + Send>,
+    fade: Arc<AtomicU64>,
+}
+
+impl VolumeGetter for FadingVolume {
+    fn attenuation_factor(&self) -> f64 {
+        self.volume.attenuation_factor() * f64::from_bits(self.fade.load(Ordering::Relaxed))
+    }
+}
+
+/// Periodically update the fading factor of the integrated device's volume, which fades
+/// the volume out at the end of a track and back in at the start of the next one
+/// while the auto-mix mode is enabled.
+///
+/// The task stops once the integrated player (the factor's owner) is dropped.
+async fn update_auto_mix_fade(state: SharedState, device_id: String, fade: Weak<AtomicU64>) {
+    let fade_duration = std::time::Duration::from_secs(
+        config::get_config().app_config.device.fade_duration_in_secs,
+    );
+    let mut interval = tokio::time::interval(AUTO_MIX_FADE_UPDATE_INTERVAL);
+    loop {
+        interval.tick().await;
+        let Some(fade) = fade.upgrade() else {
+            return;
+        };
+
+        let factor = {
+            let player = state.player.read();
+            let is_integrated_device = player
+                .buffered_playback
+                .as_ref()
+                .is_some_and(|p| p.device_id.as_deref() == Some(device_id.as_str()));
+            match (player.current_playing_track(), player.playback_progress()) {
+                (Some(track), Some(progress))
+                    if player.is_auto_mix_enabled
+                        && is_integrated_device
+                        && !fade_duration.is_zero() =>
+                {
+                    let progress = progress.num_milliseconds() as f64;
+                    let remaining = track.duration.num_milliseconds() as f64 - progress;
+                    (progress.min(remaining) / fade_duration.as_millis() as f64).clamp(0.0, 1.0)
+                }
+                _ => 1.0,
+            }
+        };
+        fade.store(factor.to_bits(), Ordering::Relaxed);
+    }
+}
+
+/// Order tracks for the auto-mix mode, starting from the first track and repeatedly
+/// picking the remaining track closest in tempo and key to the previously picked one.
+///
+/// Tracks without audio features are placed at the end, in their original order.
+pub fn order_tracks_for_auto_mix(
+    track_ids: &[TrackId<'static>],
+    features: &[AudioFeatures],
+) -> Vec<TrackId<'static>> {
+    let features = features
+        .iter()
+        .map(|f| (&f.id, f))
+        .collect::<HashMap<_, _>>();
+    let Some((first, rest)) = track_ids.split_first() else {
+        return vec![];
+    };
+    let (mut remaining, unknown): (Vec<_>, Vec<_>) =
+        rest.iter().partition(|id| features.contains_key(id));
+
+    let mut ordered = vec![first.clone()];
+    let mut previous = features.get(first).copied();
+    while !remaining.is_empty() {
+        let next = match previous {
+            Some(previous) => remaining
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    auto_mix_distance(previous, features[*a])
+                        .total_cmp(&auto_mix_distance(previous, features[*b]))
+                })
+                .map(|(i, _)| i)
+                .expect("non-empty"),
+            None => 0,
+        };
+        let id = remaining.remove(next);
+        previous = features.get(id).copied();
+        ordered.push(id.clone());
+    }
+    ordered.extend(unknown.into_iter().cloned());
+    ordered
+}
+
+/// The distance between two tracks in the auto-mix mode, measured in BPM
+fn auto_mix_distance(a: &AudioFeatures, b: &AudioFeatures) -> f32 {
+    let key_steps = match (camelot_position(a), camelot_position(b)) {
+        (Some((a_number, a_is_major)), Some((b_number, b_is_major))) => {
+            let steps = (a_number - b_number).rem_euclid(12);
+            steps.min(12 - steps) as f32 + if a_is_major == b_is_major { 0.0 } else { 1.0 }
+        }
+        _ => 0.0,
+    };
+    (a.tempo - b.tempo).abs() + key_steps * AUTO_MIX_BPM_PER_KEY_STEP
+}
+
+/// The position of a track's key on the Camelot wheel as a `(number, is_major)` pair,
+/// in which relative major and minor keys share the same (zero-based) number
+fn camelot_position(features: &AudioFeatures) -> Option<(i32, bool)> {
+    if features.key < 0 || features.mode == Modality::NoResult {
+        return None;
+    }
+    let is_major = features.mode == Modality::Major;
+    // C major (key 0) is at 8B, and A minor (key 9), its relative minor, is at 8A
+    let offset = if is_major { 7 } else { 4 };
+    Some(((7 * features.key + offset) % 12, is_major))
+}
+
+/// Get the name of the system's default audio sink using `pactl`,
+/// which is supported by both PulseAudio and PipeWire (via `pipewire-pulse`)
+#[cfg(target_os = "linux")]
+async fn get_default_audio_sink() -> anyhow::Result<String> {
+    // run `pactl` in a blocking thread to avoid stalling the async runtime's workers
+    let output = tokio::task::spawn_blocking(|| {
+        std::process::Command::new("pactl")
+            .arg("get-default-sink")
+            .output()
+    })
+    .await??;
+    if !output.status.success() {
+        anyhow::bail!(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Start a watcher that pauses the integrated device's playback when the system's
+/// default audio sink changes or disappears (e.g. headphones are unplugged)
+#[cfg(target_os = "linux")]
+pub async fn start_audio_sink_watcher(
+    client: Client,
+    state: SharedState,
+    client_pub: flume::Sender<ClientRequest>,
+) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+    let mut last_sink = match get_default_audio_sink().await {
+        Ok(sink) => sink,
+        Err(err) => {
+            tracing::warn!(
+                "Failed to get the default audio sink, stop watching audio sink changes: {err:#}"
+            );
+            return;
+        }
+    };
+
+    loop {
+        interval.tick().await;
+
+        // a failure to get the default sink is treated as a sink change,
+        // which usually happens when the last available sink disappears
+        let sink = get_default_audio_sink().await.unwrap_or_default();
+        if sink == last_sink {
+            continue;
+        }
+        tracing::info!("The default audio sink changed from {last_sink:?} to {sink:?}");
+        last_sink = sink;
+
+        let device_id = client.session().await.device_id().to_string();
+        let is_integrated_device_playing = state
+            .player
+            .read()
+            .buffered_playback
+            .as_ref()
+            .map(|p| p.is_playing && p.device_id.as_deref() == Some(device_id.as_str()))
+            .unwrap_or_default();
+        if !is_integrated_device_playing {
+            continue;
+        }
+
+        if let Err(err) = client_pub.send(ClientRequest::Player(PlayerRequest::Pause)) {
+            tracing::warn!("Failed to pause the playback on an audio sink change: {err:#}");
+            continue;
+        }
+        state
+            .ui
+            .lock()
+            .show_toast(crate::i18n::tr("Paused: audio output changed"));
+    }
+}
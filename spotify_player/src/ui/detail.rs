@@ -0,0 +1,236 @@
+use super::{page::format_std_duration, utils::construct_and_render_block, *};
+use crate::utils::map_join;
+
+/// the width percentage of the detail panel
+const DETAIL_PANEL_PERCENTAGE: u16 = 30;
+
+/// Split the given area into two, the first one for the current page
+/// and the second one for the detail panel (if shown on the current page)
+pub fn split_rect_for_detail_panel(ui: &UIStateGuard, rect: Rect) -> (Rect, Option<Rect>) {
+    let has_detail_panel = ui.is_detail_panel_shown
+        && matches!(
+            ui.current_page().page_type(),
+            PageType::Library | PageType::Search | PageType::Context | PageType::Browse
+        );
+    if !has_detail_panel {
+        return (rect, None);
+    }
+
+    let chunks = Layout::horizontal([
+        Constraint::Fill(0),
+        Constraint::Percentage(DETAIL_PANEL_PERCENTAGE),
+    ])
+    .split(rect);
+    (chunks[0], Some(chunks[1]))
+}
+
+/// Render a panel showing details of the selected item in the current page
+pub fn render_detail_panel(
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    // 1. Get data
+    let data = state.data.read();
+    let item = selected_item(ui, &data);
+
+    // 2. Construct the panel's layout
+    let rect = construct_and_render_block(
+        "Details",
+        &ui.theme,
+        Borders::TOP | Borders::RIGHT | Borders::BOTTOM,
+        frame,
+        rect,
+    );
+
+    // 3. Construct the panel's widget
+    let lines = match item {
+        Some(item) => construct_item_detail_lines(ui, &data, &item),
+        None => vec![Line::from(tr("No item selected"))],
+    };
+
+    // 4. Render the panel's widget
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), rect);
+}
+
+/// Get the selected item in the currently focused window of the current page (if any)
+fn selected_item(ui: &mut UIStateGuard, data: &DataReadGuard) -> Option<Item> {
+    let selected = ui.current_page_mut().selected()?;
+
+    match ui.current_page() {
+        PageState::Library { state } => match state.focus {
+            LibraryFocusState::Playlists => ui
+                .search_filtered_items(&data.user_data.playlists)
+                .get(selected)
+                .map(|&p| Item::Playlist(p.clone())),
+            LibraryFocusState::SavedAlbums => ui
+                .search_filtered_items(&data.user_data.saved_albums)
+                .get(selected)
+                .map(|&a| Item::Album(a.clone())),
+            LibraryFocusState::FollowedArtists => ui
+                .search_filtered_items(&data.user_data.followed_artists)
+                .get(selected)
+                .map(|&a| Item::Artist(a.clone())),
+            LibraryFocusState::SavedEpisodes => None,
+        },
+        PageState::Search {
+            state,
+            current_query,
+            ..
+        } => {
+            let results = data.caches.search.get(current_query)?;
+            match state.focus {
+                SearchFocusState::Input => None,
+                SearchFocusState::Tracks => {
+                    results.tracks.get(selected).map(|t| Item::Track(t.clone()))
+                }
+                SearchFocusState::Albums => {
+                    results.albums.get(selected).map(|a| Item::Album(a.clone()))
+                }
+                SearchFocusState::Artists => results
+                    .artists
+                    .get(selected)
+                    .map(|a| Item::Artist(a.clone())),
+                SearchFocusState::Playlists => results
+                    .playlists
+                    .get(selected)
+                    .map(|p| Item::Playlist(p.clone())),
+            }
+        }
+        PageState::Context {
+            id: Some(id),
+            state: Some(page_state),
+            ..
+        } => match data.caches.context.get(&id.uri())? {
+            Context::Artist {
+                top_tracks,
+                albums,
+                related_artists,
+                ..
+            } => match page_state {
+                ContextPageUIState::Artist { focus, .. } => match focus {
+                    ArtistFocusState::TopTracks => ui
+                        .track_table_items(top_tracks)
+                        .get(selected)
+                        .map(|&t| Item::Track(t.clone())),
+                    ArtistFocusState::Albums => ui
+                        .search_filtered_items(albums)
+                        .get(selected)
+                        .map(|&a| Item::Album(a.clone())),
+                    ArtistFocusState::RelatedArtists => ui
+                        .search_filtered_items(related_artists)
+                        .get(selected)
+                        .map(|&a| Item::Artist(a.clone())),
+                },
+                _ => None,
+            },
+            context => ui
+                .track_table_items(context.tracks())
+                .get(selected)
+                .map(|&t| Item::Track(t.clone())),
+        },
+        PageState::Browse {
+            state: BrowsePageUIState::CategoryPlaylistList { category, .. },
+        } => ui
+            .search_filtered_items(data.browse.category_playlists.get(&category.id)?)
+            .get(selected)
+            .map(|&p| Item::Playlist(p.clone())),
+        PageState::Browse {
+            state: BrowsePageUIState::FeaturedPlaylists { .. },
+        } => ui
+            .search_filtered_items(data.browse.featured_playlists.as_ref()?)
+            .get(selected)
+            .map(|&p| Item::Playlist(p.clone())),
+        PageState::Browse {
+            state: BrowsePageUIState::NewReleases { .. },
+        } => ui
+            .search_filtered_items(data.browse.new_releases.as_ref()?)
+            .get(selected)
+            .map(|&a| Item::Album(a.clone())),
+        _ => None,
+    }
+}
+
+/// Construct the lines describing an item's details
+fn construct_item_detail_lines<'a>(
+    ui: &UIStateGuard,
+    data: &DataReadGuard,
+    item: &Item,
+) -> Vec<Line<'a>> {
+    let field = |label: &str, value: String| utils::construct_field_line(&ui.theme, label, value);
+
+    let (name, mut lines) = match item {
+        Item::Track(track) => {
+            let mut lines = vec![
+                field("Artists", track.artists_info()),
+                field(
+                    "Album",
+                    track
+                        .album
+                        .as_ref()
+                        .map(|a| format!("{} ({})", a.name, a.year()))
+                        .unwrap_or_default(),
+                ),
+                field("Duration", format_std_duration(&track.duration)),
+            ];
+            if track.explicit {
+                lines.push(Line::from(tr("Explicit")));
+            }
+            if let Some(ref reason) = track.unavailable_reason {
+                lines.push(field("Unavailable", tr(reason).to_string()));
+            }
+            (&track.name, lines)
+        }
+        Item::Album(album) => {
+            let mut lines = vec![
+                field("Artists", map_join(&album.artists, |a| &a.name, ", ")),
+                field("Released", album.release_date.clone()),
+            ];
+            // the album's tracks are only available if the album's context has been loaded
+            if let Some(Context::Album { tracks, .. }) = data.caches.context.get(&album.id.uri()) {
+                let duration = tracks.iter().map(|t| t.duration).sum();
+                lines.push(field("Tracks", tracks.len().to_string()));
+                lines.push(field("Duration", format_std_duration(&duration)));
+            }
+            (&album.name, lines)
+        }
+        Item::Artist(artist) => {
+            let mut lines = vec![];
+            if let Some(followers) = artist.followers {
+                lines.push(field("Followers", followers.to_string()));
+            }
+            if let Some(popularity) = artist.popularity {
+                lines.push(field("Popularity", popularity.to_string()));
+            }
+            if !artist.genres.is_empty() {
+                lines.push(field("Genres", artist.genres.join(", ")));
+            }
+            (&artist.name, lines)
+        }
+        Item::Playlist(playlist) => {
+            let mut lines = vec![field("Owner", playlist.owner.0.clone())];
+            if playlist.collaborative {
+                lines.push(Line::from(tr("Collaborative")));
+            }
+            // the playlist's tracks are only available if the playlist's context has been loaded
+            if let Some(Context::Playlist { tracks, .. }) =
+                data.caches.context.get(&playlist.id.uri())
+            {
+                lines.push(field("Tracks", tracks.len().to_string()));
+            }
+            if !playlist.desc.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::styled(
+                    playlist.desc.clone(),
+                    ui.theme.playlist_desc(),
+                ));
+            }
+            (&playlist.name, lines)
+        }
+    };
+
+    lines.insert(0, Line::styled(name.clone(), ui.theme.playback_track()));
+    lines.insert(1, Line::from(""));
+    lines
+}
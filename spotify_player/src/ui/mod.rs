@@ -1,9 +1,10 @@
-use crate::{config, state::*};
+use crate::{config, i18n::tr, state::*};
 use anyhow::{Context as AnyhowContext, Result};
 use tui::{layout::*, style::*, text::*, widgets::*, Frame};
 
 type Terminal = tui::Terminal<tui::backend::CrosstermBackend<std::io::Stdout>>;
 
+mod detail;
 mod page;
 mod playback;
 mod popup;
@@ -23,12 +24,13 @@ pub fn run(state: SharedState) -> Result<()> {
         {
             let mut ui = state.ui.lock();
             if !ui.is_running {
+                state.data.write().store_context_views();
                 clean_up(terminal).context("clean up UI resources")?;
                 std::process::exit(0);
             }
 
             let terminal_size = terminal.size()?;
-            if Some(terminal_size) != last_terminal_size {
+            if Some(terminal_size) != last_terminal_size || ui.needs_full_redraw {
                 last_terminal_size = Some(terminal_size);
                 #[cfg(feature = "image")]
                 {
@@ -37,6 +39,13 @@ pub fn run(state: SharedState) -> Result<()> {
                 }
             }
 
+            if ui.needs_full_redraw {
+                // the terminal's content may be invalidated, e.g. after the application is resumed,
+                // so the whole terminal needs to be cleared and repainted
+                ui.needs_full_redraw = false;
+                terminal.clear()?;
+            }
+
             if let Err(err) = terminal.draw(|frame| {
                 // set the background and foreground colors for the application
                 let rect = frame.size();
@@ -62,6 +71,7 @@ fn init_ui() -> Result<Terminal> {
         crossterm::terminal::EnterAlternateScreen,
         crossterm::event::EnableMouseCapture
     )?;
+    enable_bracketed_paste();
     let backend = tui::backend::CrosstermBackend::new(stdout);
     let mut terminal = tui::Terminal::new(backend)?;
     terminal.clear()?;
@@ -70,13 +80,60 @@ fn init_ui() -> Result<Terminal> {
 
 /// Clean up UI resources before quitting the application
 fn clean_up(mut terminal: Terminal) -> Result<()> {
+    restore_terminal()?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Restore the terminal to its original state by disabling raw mode,
+/// leaving the alternate screen and showing the cursor.
+///
+/// This function can be called outside of the UI loop, e.g. when the application panics.
+pub fn restore_terminal() -> Result<()> {
     crossterm::terminal::disable_raw_mode()?;
     crossterm::execute!(
-        terminal.backend_mut(),
+        std::io::stdout(),
         crossterm::terminal::LeaveAlternateScreen,
         crossterm::event::DisableMouseCapture,
+        crossterm::event::DisableBracketedPaste,
+        crossterm::cursor::Show,
     )?;
-    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Enable bracketed paste, so that a pasted text is received as a single event
+fn enable_bracketed_paste() {
+    // bracketed paste is not supported by legacy Windows terminals
+    if let Err(err) = crossterm::execute!(std::io::stdout(), crossterm::event::EnableBracketedPaste)
+    {
+        tracing::warn!("Failed to enable bracketed paste: {err:#}");
+    }
+}
+
+/// Suspend the application by stopping the current process.
+///
+/// The terminal is restored before stopping the process and set up again
+/// once the process is resumed (e.g. via the shell's `fg` command).
+#[cfg(unix)]
+pub fn suspend(ui: &mut UIStateGuard) -> Result<()> {
+    restore_terminal()?;
+
+    // `SIGSTOP` is used instead of `SIGTSTP` because the latter is handled by the application
+    // to trigger this function. The function call blocks until the process receives `SIGCONT`.
+    // SAFETY: `raise` doesn't have any memory safety requirements
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture
+    )?;
+    enable_bracketed_paste();
+    ui.needs_full_redraw = true;
+
     Ok(())
 }
 
@@ -84,6 +141,9 @@ fn clean_up(mut terminal: Terminal) -> Result<()> {
 fn render_application(frame: &mut Frame, state: &SharedState, ui: &mut UIStateGuard, rect: Rect) {
     // rendering order: shortcut help popup -> playback window -> other popups -> main layout
 
+    // the focused window's area is registered again when rendering the focused window
+    ui.focused_window_area = None;
+
     let rect = popup::render_shortcut_help_popup(frame, ui, rect);
 
     // render playback window before other popups to ensure no popup is rendered on top
@@ -92,7 +152,12 @@ fn render_application(frame: &mut Frame, state: &SharedState, ui: &mut UIStateGu
 
     let (rect, is_active) = popup::render_popup(frame, state, ui, rect);
 
+    let is_active = is_active && !ui.is_playback_window_focused;
     render_main_layout(is_active, frame, state, ui, rect);
+
+    // overlays are rendered last to be placed on top of the main layout
+    popup::render_volume_overlay(frame, state, ui, rect);
+    popup::render_toast(frame, ui, rect);
 }
 
 /// Render the application's main layout
@@ -103,6 +168,8 @@ fn render_main_layout(
     ui: &mut UIStateGuard,
     rect: Rect,
 ) {
+    let (rect, detail_panel_rect) = detail::split_rect_for_detail_panel(ui, rect);
+
     let page_type = ui.current_page().page_type();
     match page_type {
         PageType::Library => page::render_library_page(is_active, frame, state, ui, rect),
@@ -111,7 +178,20 @@ fn render_main_layout(
         PageType::Browse => page::render_browse_page(is_active, frame, state, ui, rect),
         #[cfg(feature = "lyric-finder")]
         PageType::Lyric => page::render_lyric_page(is_active, frame, state, ui, rect),
-        PageType::Queue => page::render_queue_page(frame, state, ui, rect),
+        PageType::Queue => page::render_queue_page(is_active, frame, state, ui, rect),
+        PageType::RecentlyPlayed => {
+            page::render_recently_played_page(is_active, frame, state, ui, rect)
+        }
+        PageType::Chapters => page::render_chapters_page(is_active, frame, state, ui, rect),
+        PageType::Show => page::render_show_page(is_active, frame, state, ui, rect),
         PageType::CommandHelp => page::render_commands_help_page(frame, ui, rect),
+        PageType::PlaylistComparison => {
+            page::render_playlist_comparison_page(is_active, frame, state, ui, rect)
+        }
+    }
+
+    // the detail panel is rendered after the page to reflect the page's updated selection
+    if let Some(rect) = detail_panel_rect {
+        detail::render_detail_panel(frame, state, ui, rect);
     }
 }
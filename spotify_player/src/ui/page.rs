@@ -1,6 +1,6 @@
-use std::collections::{btree_map::Entry, BTreeMap};
+use std::collections::{btree_map::Entry, BTreeMap, HashSet};
 
-use crate::utils::format_duration;
+use crate::{command::Command, utils::format_duration};
 
 use super::{utils::construct_and_render_block, *};
 
@@ -10,6 +10,10 @@ const COMMAND_TABLE_CONSTRAINTS: [Constraint; 3] = [
     Constraint::Percentage(50),
 ];
 
+/// the duration after the user scrolls the lyric page during which the page isn't auto-scrolled
+#[cfg(feature = "lyric-finder")]
+const LYRIC_SCROLL_IDLE_DURATION: std::time::Duration = std::time::Duration::from_secs(10);
+
 // UI codes to render a page.
 // A `render_*_page` function should follow (not strictly) the below steps
 // 1. get data from the application's states
@@ -37,6 +41,7 @@ pub fn render_search_page(
     };
 
     let search_results = data.caches.search.get(current_query);
+    let playing_marker = utils::playing_item_marker(state);
 
     // 2. Construct the page's layout
     let rect = construct_and_render_block("Search", &ui.theme, Borders::ALL, frame, rect);
@@ -47,15 +52,22 @@ pub fn render_search_page(
     let rect = chunks[1];
 
     // track/album/artist/playlist search results layout (2x2 table)
-    let chunks = Layout::vertical([Constraint::Ratio(1, 2); 2])
-        .split(rect)
-        .iter()
-        .flat_map(|rect| {
-            Layout::horizontal([Constraint::Ratio(1, 2); 2])
-                .split(*rect)
-                .to_vec()
-        })
-        .collect::<Vec<_>>();
+    let sizes = &ui.window_sizes;
+    let chunks = Layout::vertical([
+        Constraint::Percentage(sizes.search_top_windows),
+        Constraint::Fill(0),
+    ])
+    .split(rect)
+    .iter()
+    .flat_map(|rect| {
+        Layout::horizontal([
+            Constraint::Percentage(sizes.search_left_windows),
+            Constraint::Fill(0),
+        ])
+        .split(*rect)
+        .to_vec()
+    })
+    .collect::<Vec<_>>();
 
     let track_rect = construct_and_render_block(
         "Tracks",
@@ -83,9 +95,10 @@ pub fn render_search_page(
                 s.tracks
                     .iter()
                     .map(|a| {
-                        (
+                        utils::mark_playing_item(
                             format!("{} • {}", a.display_name(), a.artists_info()),
-                            false,
+                            &a.id.uri(),
+                            playing_marker.as_ref(),
                         )
                     })
                     .collect::<Vec<_>>()
@@ -94,7 +107,7 @@ pub fn render_search_page(
 
         let is_active = is_active && focus_state == SearchFocusState::Tracks;
 
-        utils::construct_list_widget(&ui.theme, track_items, is_active)
+        utils::construct_list_widget(ui, track_items, is_active)
     };
 
     let (album_list, n_albums) = {
@@ -109,7 +122,7 @@ pub fn render_search_page(
 
         let is_active = is_active && focus_state == SearchFocusState::Albums;
 
-        utils::construct_list_widget(&ui.theme, album_items, is_active)
+        utils::construct_list_widget(ui, album_items, is_active)
     };
 
     let (artist_list, n_artists) = {
@@ -124,7 +137,7 @@ pub fn render_search_page(
 
         let is_active = is_active && focus_state == SearchFocusState::Artists;
 
-        utils::construct_list_widget(&ui.theme, artist_items, is_active)
+        utils::construct_list_widget(ui, artist_items, is_active)
     };
 
     let (playlist_list, n_playlists) = {
@@ -139,7 +152,7 @@ pub fn render_search_page(
 
         let is_active = is_active && focus_state == SearchFocusState::Playlists;
 
-        utils::construct_list_widget(&ui.theme, playlist_items, is_active)
+        utils::construct_list_widget(ui, playlist_items, is_active)
     };
 
     // 4. Render the page's widgets
@@ -150,6 +163,14 @@ pub fn render_search_page(
     );
 
     // Render the search result windows.
+    for (rect, focus, n_items) in [
+        (track_rect, SearchFocusState::Tracks, n_tracks),
+        (album_rect, SearchFocusState::Albums, n_albums),
+        (artist_rect, SearchFocusState::Artists, n_artists),
+        (playlist_rect, SearchFocusState::Playlists, n_playlists),
+    ] {
+        ui.set_focused_window_area(rect, is_active && focus_state == focus, false, n_items);
+    }
     // Need mutable access to the list/table states stored inside the page state for rendering.
     let page_state = match ui.current_page_mut() {
         PageState::Search { state, .. } => state,
@@ -203,19 +224,17 @@ pub fn render_context_page(
     };
 
     // 2. Construct the page's layout
-    let rect = construct_and_render_block(
-        &context_page_type.title(),
-        &ui.theme,
-        Borders::ALL,
-        frame,
-        rect,
-    );
+    let mut title = context_page_type.title();
+    if let Some(filters) = track_filters_desc(ui) {
+        title = format!("{title} ({}: {filters})", tr("filtered"));
+    }
+    let rect = construct_and_render_block(&title, &ui.theme, Borders::ALL, frame, rect);
 
     // 3+4. Construct and render the page's widgets
     let id = match id {
         None => {
             frame.render_widget(
-                Paragraph::new("Cannot determine the current page's context"),
+                Paragraph::new(tr("Cannot determine the current page's context")),
                 rect,
             );
             return;
@@ -270,7 +289,7 @@ pub fn render_context_page(
                         rect,
                         is_active,
                         state,
-                        ui.search_filtered_items(tracks),
+                        ui.filtered_tracks(tracks),
                         ui,
                         &data,
                     );
@@ -281,7 +300,7 @@ pub fn render_context_page(
                         rect,
                         is_active,
                         state,
-                        ui.search_filtered_items(tracks),
+                        ui.filtered_tracks(tracks),
                         ui,
                         &data,
                     );
@@ -292,16 +311,57 @@ pub fn render_context_page(
                         rect,
                         is_active,
                         state,
-                        ui.search_filtered_items(tracks),
+                        ui.filtered_tracks(tracks),
                         ui,
                         &data,
                     );
                 }
             }
         }
-        None => {
-            frame.render_widget(Paragraph::new("Loading..."), rect);
+        None => match data.context_loading_status.get(&id.uri()) {
+            Some(LoadingStatus::Failed) => {
+                let retry_key = config::get_config()
+                    .keymap_config
+                    .find_key_sequence_from_command(Command::RefreshPlayback)
+                    .map(|k| {
+                        format!(
+                            ", {}",
+                            tr("press `{key}` to retry").replace("{key}", &k.to_string())
+                        )
+                    })
+                    .unwrap_or_default();
+                frame.render_widget(
+                    Paragraph::new(format!("{}{retry_key}", tr("Failed to load the context"))),
+                    rect,
+                );
+            }
+            _ => utils::render_loading_window(frame, rect),
+        },
+    }
+}
+
+/// An album window's widget, which is either a list or a grid of albums
+enum AlbumWindow<'a> {
+    List(Box<List<'a>>, usize),
+    Grid(utils::AlbumGrid<'a>),
+}
+
+/// Render an album window.
+///
+/// Returns the cover image URLs of the rendered albums that are not yet in the image cache.
+fn render_album_window(
+    frame: &mut Frame,
+    window: AlbumWindow,
+    rect: Rect,
+    state: &mut ListState,
+    data: &DataReadGuard,
+) -> Vec<String> {
+    match window {
+        AlbumWindow::List(list, len) => {
+            utils::render_list_window(frame, *list, rect, len, state);
+            vec![]
         }
+        AlbumWindow::Grid(grid) => utils::render_album_grid_window(frame, grid, rect, state, data),
     }
 }
 
@@ -325,13 +385,9 @@ pub fn render_library_page(
     // Horizontally split the library page into 3 windows:
     // - a playlists window
     // - a saved albums window
-    // - a followed artists window
-    let chunks = Layout::horizontal([
-        Constraint::Percentage(40),
-        Constraint::Percentage(40),
-        Constraint::Percentage(20),
-    ])
-    .split(rect);
+    // - a followed artists window, above a saved episodes window
+    let chunks =
+        Layout::horizontal(ui.window_sizes.library_windows.map(Constraint::Percentage)).split(rect);
     let playlist_rect = construct_and_render_block(
         "Playlists",
         &ui.theme,
@@ -346,13 +402,27 @@ pub fn render_library_page(
         frame,
         chunks[1],
     );
-    let artist_rect =
-        construct_and_render_block("Artists", &ui.theme, Borders::ALL, frame, chunks[2]);
+    let artist_chunks =
+        Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).split(chunks[2]);
+    let artist_rect = construct_and_render_block(
+        "Artists",
+        &ui.theme,
+        Borders::TOP | Borders::LEFT | Borders::RIGHT,
+        frame,
+        artist_chunks[0],
+    );
+    let episode_rect = construct_and_render_block(
+        "Saved Episodes",
+        &ui.theme,
+        Borders::ALL,
+        frame,
+        artist_chunks[1],
+    );
 
     // 3. Construct the page's widgets
     // Construct the playlist window
     let (playlist_list, n_playlists) = utils::construct_list_widget(
-        &ui.theme,
+        ui,
         ui.search_filtered_items(&data.user_data.playlists)
             .into_iter()
             .map(|p| (p.to_string(), curr_context_uri == Some(p.id.uri())))
@@ -360,26 +430,71 @@ pub fn render_library_page(
         is_active && focus_state == LibraryFocusState::Playlists,
     );
     // Construct the saved album window
-    let (album_list, n_albums) = utils::construct_list_widget(
-        &ui.theme,
-        ui.search_filtered_items(&data.user_data.saved_albums)
-            .into_iter()
-            .map(|a| (a.to_string(), curr_context_uri == Some(a.id.uri())))
-            .collect(),
-        is_active && focus_state == LibraryFocusState::SavedAlbums,
-    );
+    let albums = ui
+        .search_filtered_items(&data.user_data.saved_albums)
+        .into_iter()
+        .map(|a| (a, curr_context_uri == Some(a.id.uri())))
+        .collect::<Vec<_>>();
+    let is_album_window_active = is_active && focus_state == LibraryFocusState::SavedAlbums;
+    let album_window = if ui.is_album_grid_view {
+        AlbumWindow::Grid(utils::construct_album_grid_widget(
+            &ui.theme,
+            albums,
+            is_album_window_active,
+        ))
+    } else {
+        let (album_list, n_albums) = utils::construct_list_widget(
+            ui,
+            albums
+                .into_iter()
+                .map(|(a, is_active)| (a.to_string(), is_active))
+                .collect(),
+            is_album_window_active,
+        );
+        AlbumWindow::List(Box::new(album_list), n_albums)
+    };
     // Construct the followed artist window
     let (artist_list, n_artists) = utils::construct_list_widget(
-        &ui.theme,
+        ui,
         ui.search_filtered_items(&data.user_data.followed_artists)
             .into_iter()
             .map(|a| (a.to_string(), curr_context_uri == Some(a.id.uri())))
             .collect(),
         is_active && focus_state == LibraryFocusState::FollowedArtists,
     );
+    // Construct the saved episode window
+    let (episode_list, n_episodes) = utils::construct_list_widget(
+        ui,
+        ui.search_filtered_items(&data.user_data.saved_episodes)
+            .into_iter()
+            .map(|e| (e.to_string(), false))
+            .collect(),
+        is_active && focus_state == LibraryFocusState::SavedEpisodes,
+    );
 
     // 4. Render the page's widgets
     // Render the library page's windows.
+    ui.set_focused_window_area(
+        playlist_rect,
+        is_active && focus_state == LibraryFocusState::Playlists,
+        false,
+        n_playlists,
+    );
+    if let AlbumWindow::List(_, n_albums) = album_window {
+        ui.set_focused_window_area(album_rect, is_album_window_active, false, n_albums);
+    }
+    ui.set_focused_window_area(
+        artist_rect,
+        is_active && focus_state == LibraryFocusState::FollowedArtists,
+        false,
+        n_artists,
+    );
+    ui.set_focused_window_area(
+        episode_rect,
+        is_active && focus_state == LibraryFocusState::SavedEpisodes,
+        false,
+        n_episodes,
+    );
     // Will need mutable access to the list/table states stored inside the page state for rendering.
     let page_state = match ui.current_page_mut() {
         PageState::Library { state } => state,
@@ -393,13 +508,6 @@ pub fn render_library_page(
         n_playlists,
         &mut page_state.playlist_list,
     );
-    utils::render_list_window(
-        frame,
-        album_list,
-        album_rect,
-        n_albums,
-        &mut page_state.saved_album_list,
-    );
     utils::render_list_window(
         frame,
         artist_list,
@@ -407,6 +515,23 @@ pub fn render_library_page(
         n_artists,
         &mut page_state.followed_artist_list,
     );
+    utils::render_list_window(
+        frame,
+        episode_list,
+        episode_rect,
+        n_episodes,
+        &mut page_state.saved_episode_list,
+    );
+    #[cfg_attr(not(feature = "image"), allow(unused_variables))]
+    let missing_cover_urls = render_album_window(
+        frame,
+        album_window,
+        album_rect,
+        &mut page_state.saved_album_list,
+        &data,
+    );
+    #[cfg(feature = "image")]
+    ui.missing_album_cover_urls.extend(missing_cover_urls);
 }
 
 pub fn render_browse_page(
@@ -427,7 +552,7 @@ pub fn render_browse_page(
                     construct_and_render_block("Categories", &ui.theme, Borders::ALL, frame, rect);
 
                 utils::construct_list_widget(
-                    &ui.theme,
+                    ui,
                     ui.search_filtered_items(&data.browse.categories)
                         .into_iter()
                         .map(|c| (c.name.clone(), false))
@@ -442,13 +567,13 @@ pub fn render_browse_page(
                 let playlists = match data.browse.category_playlists.get(&category.id) {
                     Some(playlists) => playlists,
                     None => {
-                        frame.render_widget(Paragraph::new("Loading..."), rect);
+                        utils::render_loading_window(frame, rect);
                         return;
                     }
                 };
 
                 utils::construct_list_widget(
-                    &ui.theme,
+                    ui,
                     ui.search_filtered_items(playlists)
                         .into_iter()
                         .map(|c| (c.name.clone(), false))
@@ -456,11 +581,72 @@ pub fn render_browse_page(
                     is_active,
                 )
             }
+            BrowsePageUIState::FeaturedPlaylists { .. } => {
+                rect = construct_and_render_block(
+                    "Featured Playlists",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    rect,
+                );
+
+                let playlists = match data.browse.featured_playlists {
+                    Some(ref playlists) => playlists,
+                    None => {
+                        utils::render_loading_window(frame, rect);
+                        return;
+                    }
+                };
+
+                utils::construct_list_widget(
+                    ui,
+                    ui.search_filtered_items(playlists)
+                        .into_iter()
+                        .map(|p| (p.name.clone(), false))
+                        .collect(),
+                    is_active,
+                )
+            }
+            BrowsePageUIState::NewReleases { .. } => {
+                rect = construct_and_render_block(
+                    "New Releases",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    rect,
+                );
+
+                let albums = match data.browse.new_releases {
+                    Some(ref albums) => albums,
+                    None => {
+                        utils::render_loading_window(frame, rect);
+                        return;
+                    }
+                };
+
+                // mark the albums saved in the user's library with the liked icon
+                let liked_icon = &config::get_config().app_config.liked_icon;
+                utils::construct_list_widget(
+                    ui,
+                    ui.search_filtered_items(albums)
+                        .into_iter()
+                        .map(|a| {
+                            if data.user_data.saved_albums.iter().any(|s| s.id == a.id) {
+                                (format!("{a} {liked_icon}"), false)
+                            } else {
+                                (a.to_string(), false)
+                            }
+                        })
+                        .collect(),
+                    is_active,
+                )
+            }
         },
         _ => return,
     };
 
     // 4. Render the page's widget
+    ui.set_focused_window_area(rect, is_active, false, len);
     let list_state = match ui.current_page_mut().focus_window_state_mut() {
         Some(MutableWindowState::List(list_state)) => list_state,
         _ => return,
@@ -484,33 +670,59 @@ pub fn render_lyric_page(
     let chunks = Layout::vertical([Constraint::Length(1), Constraint::Fill(0)]).split(rect);
 
     // 3. Construct the page's widgets
-    let (track, artists, scroll_offset) = match ui.current_page_mut() {
-        PageState::Lyric {
-            track,
-            artists,
-            scroll_offset,
-        } => (track, artists, scroll_offset),
+    let (track, artists) = match ui.current_page() {
+        PageState::Lyric { track, artists, .. } => (track, artists),
         _ => return,
     };
 
-    let (desc, lyric) = match data.caches.lyrics.get(&format!("{track} {artists}")) {
+    let lyric = match data.caches.lyrics.get(&format!("{track} {artists}")) {
         None => {
-            frame.render_widget(Paragraph::new("Loading..."), rect);
+            utils::render_loading_window(frame, rect);
             return;
         }
-        Some(lyric_finder::LyricResult::None) => {
-            frame.render_widget(Paragraph::new("Lyric not found"), rect);
+        Some(None) => {
+            frame.render_widget(Paragraph::new(tr("Lyric not found")), rect);
             return;
         }
-        Some(lyric_finder::LyricResult::Some {
-            track,
-            artists,
-            lyric,
-        }) => (format!("{track} by {artists}"), format!("\n{lyric}")),
+        Some(Some(lyric)) => lyric,
+    };
+    let desc = format!("{} by {}", lyric.track, lyric.artists);
+
+    // a synced lyric highlights the line being sung at the current playback progress
+    let current_line = if lyric.is_synced() {
+        state
+            .player
+            .read()
+            .playback_progress()
+            .and_then(|p| p.to_std().ok())
+            .and_then(|p| lyric.current_line(p))
+    } else {
+        None
     };
+    let lines = std::iter::once(Line::from(""))
+        .chain(lyric.lines.iter().enumerate().map(|(id, l)| {
+            if Some(id) == current_line {
+                Line::styled(l.text.clone(), ui.theme.current_playing())
+            } else {
+                Line::from(l.text.clone())
+            }
+        }))
+        .collect::<Vec<_>>();
 
+    let is_scrolled_manually = ui
+        .lyric_scrolled_at
+        .is_some_and(|t| t.elapsed() < LYRIC_SCROLL_IDLE_DURATION);
+    let scroll_offset = match ui.current_page_mut() {
+        PageState::Lyric { scroll_offset, .. } => scroll_offset,
+        _ => return,
+    };
+    let n_rows = lines.len();
+    if let Some(id) = current_line.filter(|_| !is_scrolled_manually) {
+        // auto-scroll to keep the current line (offset by the leading empty line)
+        // in the middle of the page
+        *scroll_offset = (id + 1).saturating_sub(chunks[1].height as usize / 2);
+    }
     // update the scroll offset so that it doesn't exceed the lyric's length
-    let n_rows = lyric.matches('\n').count() + 1;
     if *scroll_offset >= n_rows {
         *scroll_offset = n_rows - 1;
     }
@@ -522,7 +734,7 @@ pub fn render_lyric_page(
 
     // render lyric text
     frame.render_widget(
-        Paragraph::new(lyric).scroll((scroll_offset as u16, 0)),
+        Paragraph::new(lines).scroll((scroll_offset as u16, 0)),
         chunks[1],
     );
 }
@@ -571,7 +783,7 @@ pub fn render_commands_help_page(frame: &mut Frame, ui: &mut UIStateGuard, rect:
                 Row::new(vec![
                     Cell::from(format!("{command:?}")),
                     Cell::from(format!("[{keys}]")),
-                    Cell::from(command.desc()),
+                    Cell::from(tr(command.desc())),
                 ])
             })
             .collect::<Vec<_>>(),
@@ -579,9 +791,9 @@ pub fn render_commands_help_page(frame: &mut Frame, ui: &mut UIStateGuard, rect:
     )
     .header(
         Row::new(vec![
-            Cell::from("Command"),
-            Cell::from("Shortcuts"),
-            Cell::from("Description"),
+            Cell::from(tr("Command")),
+            Cell::from(tr("Shortcuts")),
+            Cell::from(tr("Description")),
         ])
         .style(ui.theme.table_header()),
     );
@@ -591,6 +803,7 @@ pub fn render_commands_help_page(frame: &mut Frame, ui: &mut UIStateGuard, rect:
 }
 
 pub fn render_queue_page(
+    is_active: bool,
     frame: &mut Frame,
     state: &SharedState,
     ui: &mut UIStateGuard,
@@ -623,42 +836,106 @@ pub fn render_queue_page(
 
     // 1. Get data
     let player = state.player.read();
-    let queue = match player.queue {
-        Some(ref q) => &q.queue,
+    let (currently_playing, queue) = match player.queue {
+        Some(ref q) => (&q.currently_playing, &q.queue),
         None => return,
     };
-    let scroll_offset = match ui.current_page_mut() {
-        PageState::Queue {
-            ref mut scroll_offset,
-        } => {
-            if !queue.is_empty() && *scroll_offset >= queue.len() {
-                *scroll_offset = queue.len() - 1
-            }
-            *scroll_offset
+    let data = state.data.read();
+    // Spotify's queue lists the user-queued items before the playing context's next items,
+    // so the user-queued items are the items preceding the first item of the (cached) playing context.
+    // If the cached context doesn't have all the context's tracks, no item is marked as queued
+    // because the context's next items can't be told apart from the user-queued items.
+    let n_queued_items = match player
+        .playing_context_id()
+        .and_then(|id| data.caches.context.get(&id.uri()))
+        .filter(|context| context.has_complete_tracks())
+    {
+        Some(context) => {
+            let context_uris = context
+                .tracks()
+                .iter()
+                .map(|t| t.id.uri())
+                .collect::<HashSet<_>>();
+            queue
+                .iter()
+                .position(|x| x.id().is_some_and(|id| context_uris.contains(&id.uri())))
+                .unwrap_or(queue.len())
         }
-        _ => return,
+        None => 0,
     };
-
+    let queued_icon = &config::get_config().app_config.queued_icon;
+    let playing_marker = utils::playing_item_marker(state);
     // 2. Construct the page's layout
     let rect = construct_and_render_block("Queue", &ui.theme, Borders::ALL, frame, rect);
+    let chunks = Layout::vertical([Constraint::Length(3), Constraint::Fill(0)]).split(rect);
+
+    // 3. Construct the page's widgets
+
+    // the queue's currently playing item and context can belong to any Spotify Connect device
+    let desc = match currently_playing {
+        Some(item) => {
+            let device = player
+                .playback
+                .as_ref()
+                .map(|p| p.device.name.as_str())
+                .unwrap_or_default();
+            let context = player
+                .playing_context_id()
+                .map(|id| match data.caches.context.get(&id.uri()) {
+                    Some(context) => context.description(),
+                    None => id.uri(),
+                })
+                .unwrap_or_default();
+            vec![
+                Line::from(format!(
+                    "{} ({}): {} • {}",
+                    tr("Current Playing"),
+                    device,
+                    get_playable_name(item),
+                    get_playable_artists(item)
+                )),
+                Line::from(context),
+                Line::from(
+                    player
+                        .queue_remaining()
+                        .map(utils::format_queue_remaining)
+                        .unwrap_or_default(),
+                ),
+            ]
+        }
+        None => vec![],
+    };
+    frame.render_widget(Paragraph::new(desc).style(ui.theme.page_desc()), chunks[0]);
 
-    // 3. Construct the page's widget
     let queue_table = Table::new(
         queue
             .iter()
             .enumerate()
-            .skip(scroll_offset)
             .map(|(i, x)| {
+                // the playing item can also appear in the queue, e.g. when it is queued again
+                let (number, style) = match playing_marker {
+                    Some((ref uri, icon)) if x.id().is_some_and(|id| id.uri() == *uri) => {
+                        (icon.to_string(), ui.theme.current_playing())
+                    }
+                    _ => ((i + 1).to_string(), Style::default()),
+                };
                 Row::new(vec![
-                    Cell::from(format!("{}", i + 1)),
+                    Cell::from(number),
+                    Cell::from(if i < n_queued_items {
+                        queued_icon.as_str()
+                    } else {
+                        ""
+                    }),
                     Cell::from(get_playable_name(x)),
                     Cell::from(get_playable_artists(x)),
                     Cell::from(get_playable_duration(x)),
                 ])
+                .style(style)
             })
             .collect::<Vec<_>>(),
         [
             Constraint::Percentage(5),
+            Constraint::Length(queued_icon.chars().count() as u16),
             Constraint::Percentage(40),
             Constraint::Percentage(35),
             Constraint::Percentage(20),
@@ -667,15 +944,434 @@ pub fn render_queue_page(
     .header(
         Row::new(vec![
             Cell::from("#"),
-            Cell::from("Title"),
-            Cell::from("Artists"),
-            Cell::from("Duration"),
+            Cell::from(""),
+            Cell::from(tr("Title")),
+            Cell::from(tr("Artists")),
+            Cell::from(tr("Duration")),
         ])
         .style(ui.theme.table_header()),
-    );
+    )
+    .highlight_style(ui.theme.selection(is_active));
+    let queue_table = if config::get_config().app_config.screen_reader_mode {
+        queue_table.highlight_symbol(utils::SCREEN_READER_HIGHLIGHT_SYMBOL)
+    } else {
+        queue_table
+    };
 
     // 4. Render page's widget
-    frame.render_widget(queue_table, rect);
+    let n_items = queue.len();
+    ui.set_focused_window_area(chunks[1], is_active, true, n_items);
+    if let PageState::Queue {
+        queue_table: table_state,
+    } = ui.current_page_mut()
+    {
+        utils::render_table_window(frame, queue_table, chunks[1], n_items, table_state);
+    }
+}
+
+pub fn render_recently_played_page(
+    is_active: bool,
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    // 1. Get data
+    let playing_marker = utils::playing_item_marker(state);
+    let data = state.data.read();
+    let tracks = ui.search_filtered_items(&data.user_data.recently_played_tracks);
+
+    // 2. Construct the page's layout
+    let rect = construct_and_render_block(
+        "Recently Played Tracks",
+        &ui.theme,
+        Borders::ALL,
+        frame,
+        rect,
+    );
+
+    // 3. Construct the page's widgets
+    let n_tracks = tracks.len();
+    let rows = tracks
+        .into_iter()
+        .enumerate()
+        .map(|(id, t)| {
+            let (number, style) = match playing_marker {
+                Some((ref uri, icon)) if *uri == t.track.id.uri() => {
+                    (icon.to_string(), ui.theme.current_playing())
+                }
+                _ => ((id + 1).to_string(), Style::default()),
+            };
+            Row::new(vec![
+                Cell::from(number),
+                Cell::from(t.track.display_name()),
+                Cell::from(t.track.artists_info()),
+                Cell::from(t.track.album_info()),
+                Cell::from(
+                    t.played_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string(),
+                ),
+            ])
+            .style(style)
+        })
+        .collect::<Vec<_>>();
+
+    let track_table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Fill(4),
+            Constraint::Fill(3),
+            Constraint::Fill(5),
+            Constraint::Length(16),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from(tr("Title")),
+            Cell::from(tr("Artists")),
+            Cell::from(tr("Album")),
+            Cell::from(tr("Played")),
+        ])
+        .style(ui.theme.table_header()),
+    )
+    .column_spacing(2)
+    .highlight_style(ui.theme.selection(is_active));
+    let track_table = if config::get_config().app_config.screen_reader_mode {
+        track_table.highlight_symbol(utils::SCREEN_READER_HIGHLIGHT_SYMBOL)
+    } else {
+        track_table
+    };
+
+    // 4. Render the page's widget
+    ui.set_focused_window_area(rect, is_active, true, n_tracks);
+    if let PageState::RecentlyPlayed {
+        track_table: table_state,
+    } = ui.current_page_mut()
+    {
+        utils::render_table_window(frame, track_table, rect, n_tracks, table_state);
+    }
+}
+
+pub fn render_chapters_page(
+    is_active: bool,
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    // 1. Get data
+    let playing_episode_uri = state
+        .player
+        .read()
+        .current_playing_episode()
+        .map(|e| e.id.uri());
+    let data = state.data.read();
+    let (audiobook_id, audiobook_name) = match ui.current_page() {
+        PageState::Chapters {
+            audiobook_id,
+            audiobook_name,
+            ..
+        } => (audiobook_id, audiobook_name),
+        _ => return,
+    };
+
+    // 2. Construct the page's layout
+    let title = format!("{}: {audiobook_name}", tr("Chapters"));
+    let rect = construct_and_render_block(&title, &ui.theme, Borders::ALL, frame, rect);
+
+    // 3. Construct the page's widgets
+    let chapters = match data.caches.chapters.get(audiobook_id) {
+        Some(chapters) => chapters,
+        None => {
+            utils::render_loading_window(frame, rect);
+            return;
+        }
+    };
+    if chapters.is_empty() {
+        frame.render_widget(
+            Paragraph::new(tr(
+                "No chapters found. The playing show may not be an audiobook.",
+            )),
+            rect,
+        );
+        return;
+    }
+
+    let chapters = ui.search_filtered_items(chapters);
+    let n_chapters = chapters.len();
+    let rows = chapters
+        .into_iter()
+        .map(|c| {
+            let style = if playing_episode_uri.as_ref() == Some(&c.id.uri()) {
+                ui.theme.current_playing()
+            } else if c.fully_played {
+                // dim the finished chapters
+                Style::default().add_modifier(Modifier::DIM)
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                Cell::from(c.chapter_number.to_string()),
+                Cell::from(c.name.clone()),
+                Cell::from(format_std_duration(&c.duration)),
+                Cell::from(
+                    c.resume_position
+                        .as_ref()
+                        .map(format_std_duration)
+                        .unwrap_or_default(),
+                ),
+            ])
+            .style(style)
+        })
+        .collect::<Vec<_>>();
+
+    let chapter_table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from(tr("Title")),
+            Cell::from(tr("Duration")),
+            Cell::from(tr("Resume")),
+        ])
+        .style(ui.theme.table_header()),
+    )
+    .column_spacing(2)
+    .highlight_style(ui.theme.selection(is_active));
+    let chapter_table = if config::get_config().app_config.screen_reader_mode {
+        chapter_table.highlight_symbol(utils::SCREEN_READER_HIGHLIGHT_SYMBOL)
+    } else {
+        chapter_table
+    };
+
+    // 4. Render the page's widget
+    ui.set_focused_window_area(rect, is_active, true, n_chapters);
+    if let PageState::Chapters {
+        chapter_table: table_state,
+        ..
+    } = ui.current_page_mut()
+    {
+        utils::render_table_window(frame, chapter_table, rect, n_chapters, table_state);
+    }
+}
+
+pub fn render_show_page(
+    is_active: bool,
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    // 1. Get data
+    let playing_episode_uri = state
+        .player
+        .read()
+        .current_playing_episode()
+        .map(|e| e.id.uri());
+    let data = state.data.read();
+    let (show_id, show_name) = match ui.current_page() {
+        PageState::Show {
+            show_id, show_name, ..
+        } => (show_id, show_name),
+        _ => return,
+    };
+
+    // 2. Construct the page's layout
+    let title = format!("{}: {show_name}", tr("Show"));
+    let rect = construct_and_render_block(&title, &ui.theme, Borders::ALL, frame, rect);
+
+    // 3. Construct the page's widgets
+    let episodes = match data.caches.show_episodes.get(show_id.id()) {
+        Some(episodes) => ui.search_filtered_items(episodes),
+        None => {
+            utils::render_loading_window(frame, rect);
+            return;
+        }
+    };
+
+    let n_episodes = episodes.len();
+    let rows = episodes
+        .into_iter()
+        .enumerate()
+        .map(|(id, e)| {
+            let style = if playing_episode_uri.as_ref() == Some(&e.id.uri()) {
+                ui.theme.current_playing()
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                Cell::from((id + 1).to_string()),
+                Cell::from(e.name.clone()),
+                Cell::from(e.release_date.clone()),
+                Cell::from(format_std_duration(&e.duration)),
+                Cell::from(
+                    e.resume_position
+                        .as_ref()
+                        .map(format_std_duration)
+                        .unwrap_or_default(),
+                ),
+            ])
+            .style(style)
+        })
+        .collect::<Vec<_>>();
+
+    let episode_table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Cell::from("#"),
+            Cell::from(tr("Title")),
+            Cell::from(tr("Released")),
+            Cell::from(tr("Duration")),
+            Cell::from(tr("Resume")),
+        ])
+        .style(ui.theme.table_header()),
+    )
+    .column_spacing(2)
+    .highlight_style(ui.theme.selection(is_active));
+    let episode_table = if config::get_config().app_config.screen_reader_mode {
+        episode_table.highlight_symbol(utils::SCREEN_READER_HIGHLIGHT_SYMBOL)
+    } else {
+        episode_table
+    };
+
+    // 4. Render the page's widget
+    ui.set_focused_window_area(rect, is_active, true, n_episodes);
+    if let PageState::Show {
+        episode_table: table_state,
+        ..
+    } = ui.current_page_mut()
+    {
+        utils::render_table_window(frame, episode_table, rect, n_episodes, table_state);
+    }
+}
+
+pub fn render_playlist_comparison_page(
+    is_active: bool,
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    // 1. Get data
+    let playing_marker = utils::playing_item_marker(state);
+    let data = state.data.read();
+
+    let (playlists, focus_state) = match ui.current_page() {
+        PageState::PlaylistComparison { playlists, state } => (playlists, state.focus),
+        _ => return,
+    };
+    let (first, second) = playlists.as_ref();
+
+    // 2. Construct the page's layout
+    let rect = construct_and_render_block(
+        &format!("{} ↔ {}", first.name, second.name),
+        &ui.theme,
+        Borders::ALL,
+        frame,
+        rect,
+    );
+
+    let comparison = match data.compare_playlists(first, second) {
+        Some(comparison) => comparison,
+        None => {
+            utils::render_loading_window(frame, rect);
+            return;
+        }
+    };
+
+    // Horizontally split the page into 3 windows:
+    // - a window of tracks only in the first playlist
+    // - a window of tracks only in the second playlist
+    // - a window of tracks in both playlists
+    let chunks = Layout::horizontal([Constraint::Ratio(1, 3); 3]).split(rect);
+    let titles = [
+        format!("{} {}", tr("Only in"), first.name),
+        format!("{} {}", tr("Only in"), second.name),
+        tr("In both").to_string(),
+    ];
+    let focuses = [
+        PlaylistComparisonFocusState::FirstOnly,
+        PlaylistComparisonFocusState::SecondOnly,
+        PlaylistComparisonFocusState::Common,
+    ];
+
+    // 3. Construct the page's widgets
+    let windows = comparison
+        .into_iter()
+        .zip(titles)
+        .zip(focuses)
+        .enumerate()
+        .map(|(i, ((tracks, title), focus))| {
+            let borders = if i == 2 {
+                Borders::ALL
+            } else {
+                Borders::TOP | Borders::LEFT | Borders::BOTTOM
+            };
+            let rect = construct_and_render_block(
+                &format!("{title} ({})", tracks.len()),
+                &ui.theme,
+                borders,
+                frame,
+                chunks[i],
+            );
+            let (list, n_tracks) = utils::construct_list_widget(
+                ui,
+                tracks
+                    .into_iter()
+                    .map(|t| {
+                        utils::mark_playing_item(
+                            t.to_string(),
+                            &t.id.uri(),
+                            playing_marker.as_ref(),
+                        )
+                    })
+                    .collect(),
+                is_active && focus_state == focus,
+            );
+            (list, rect, n_tracks, is_active && focus_state == focus)
+        })
+        .collect::<Vec<_>>();
+
+    // 4. Render the page's widgets
+    for (_, rect, n_tracks, is_focused) in &windows {
+        ui.set_focused_window_area(*rect, *is_focused, false, *n_tracks);
+    }
+    let page_state = match ui.current_page_mut() {
+        PageState::PlaylistComparison { state, .. } => state,
+        _ => return,
+    };
+    let list_states = [
+        &mut page_state.first_only_list,
+        &mut page_state.second_only_list,
+        &mut page_state.common_list,
+    ];
+    for ((list, rect, n_tracks, _), list_state) in windows.into_iter().zip(list_states) {
+        utils::render_list_window(frame, list, rect, n_tracks, list_state);
+    }
+}
+
+pub(super) fn format_std_duration(duration: &std::time::Duration) -> String {
+    format!("{}:{:02}", duration.as_secs() / 60, duration.as_secs() % 60)
 }
 
 /// Render windows for an artist context page, which includes
@@ -693,7 +1389,7 @@ fn render_artist_context_page_windows(
 ) {
     // 1. Get data
     let (tracks, albums, artists) = (
-        ui.search_filtered_items(artist_data.0),
+        ui.filtered_tracks(artist_data.0),
         ui.search_filtered_items(artist_data.1),
         ui.search_filtered_items(artist_data.2),
     );
@@ -708,11 +1404,20 @@ fn render_artist_context_page_windows(
 
     // 2. Construct the page's layout
     // top tracks window
-    let chunks = Layout::vertical([Constraint::Length(12), Constraint::Fill(0)]).split(rect);
+    let sizes = &ui.window_sizes;
+    let chunks = Layout::vertical([
+        Constraint::Length(sizes.artist_top_tracks_window),
+        Constraint::Fill(0),
+    ])
+    .split(rect);
     let top_tracks_rect = chunks[0];
 
     // albums and related artitsts windows
-    let chunks = Layout::horizontal([Constraint::Ratio(1, 2); 2]).split(chunks[1]);
+    let chunks = Layout::horizontal([
+        Constraint::Percentage(sizes.artist_albums_window),
+        Constraint::Fill(0),
+    ])
+    .split(chunks[1]);
     let albums_rect = construct_and_render_block(
         "Albums",
         &ui.theme,
@@ -725,17 +1430,24 @@ fn render_artist_context_page_windows(
 
     // 3. Construct the page's widgets
     // album list widget
-    let (album_list, n_albums) = {
-        let album_items = albums
-            .into_iter()
-            .map(|a| (format!("{1} • {0}", a.name, a.year()), false))
-            .collect::<Vec<_>>();
-
-        utils::construct_list_widget(
-            &ui.theme,
-            album_items,
-            is_active && focus_state == ArtistFocusState::Albums,
-        )
+    let album_window = {
+        let is_album_window_active = is_active && focus_state == ArtistFocusState::Albums;
+        if ui.is_album_grid_view {
+            AlbumWindow::Grid(utils::construct_album_grid_widget(
+                &ui.theme,
+                albums.into_iter().map(|a| (a, false)).collect(),
+                is_album_window_active,
+            ))
+        } else {
+            let album_items = albums
+                .into_iter()
+                .map(|a| (format!("{1} • {0}", a.name, a.year()), false))
+                .collect::<Vec<_>>();
+
+            let (album_list, n_albums) =
+                utils::construct_list_widget(ui, album_items, is_album_window_active);
+            AlbumWindow::List(Box::new(album_list), n_albums)
+        }
     };
 
     // artist list widget
@@ -746,7 +1458,7 @@ fn render_artist_context_page_windows(
             .collect::<Vec<_>>();
 
         utils::construct_list_widget(
-            &ui.theme,
+            ui,
             artist_items,
             is_active && focus_state == ArtistFocusState::RelatedArtists,
         )
@@ -763,6 +1475,20 @@ fn render_artist_context_page_windows(
         data,
     );
 
+    if let AlbumWindow::List(_, n_albums) = album_window {
+        ui.set_focused_window_area(
+            albums_rect,
+            is_active && focus_state == ArtistFocusState::Albums,
+            false,
+            n_albums,
+        );
+    }
+    ui.set_focused_window_area(
+        related_artists_rect,
+        is_active && focus_state == ArtistFocusState::RelatedArtists,
+        false,
+        n_artists,
+    );
     let (album_list_state, artist_list_state) = match ui.current_page_mut() {
         PageState::Context {
             state:
@@ -776,7 +1502,6 @@ fn render_artist_context_page_windows(
         _ => return,
     };
 
-    utils::render_list_window(frame, album_list, albums_rect, n_albums, album_list_state);
     utils::render_list_window(
         frame,
         artist_list,
@@ -784,6 +1509,27 @@ fn render_artist_context_page_windows(
         n_artists,
         artist_list_state,
     );
+    #[cfg_attr(not(feature = "image"), allow(unused_variables))]
+    let missing_cover_urls =
+        render_album_window(frame, album_window, albums_rect, album_list_state, data);
+    #[cfg(feature = "image")]
+    ui.missing_album_cover_urls.extend(missing_cover_urls);
+}
+
+/// Get a description of the active track filters, if any
+fn track_filters_desc(ui: &UIStateGuard) -> Option<String> {
+    let mut filters = vec![];
+    if let Some((from, to)) = ui.track_year_filter {
+        filters.push(format!("{} {from}-{to}", tr("Year")));
+    }
+    if let Some((from, to)) = ui.track_tempo_filter {
+        filters.push(format!("{from}-{to} BPM"));
+    }
+    if filters.is_empty() {
+        None
+    } else {
+        Some(filters.join(", "))
+    }
 }
 
 fn render_track_table(
@@ -797,75 +1543,173 @@ fn render_track_table(
 ) {
     let configs = config::get_config();
     // get the current playing track's URI to decorate such track (if exists) in the track table
-    let mut playing_track_uri = "".to_string();
-    let mut playing_id = "";
-    if let Some(ref playback) = state.player.read().playback {
-        if let Some(rspotify_model::PlayableItem::Track(ref track)) = playback.item {
-            playing_track_uri = track.id.as_ref().map(|id| id.uri()).unwrap_or_default();
-
-            playing_id = if playback.is_playing {
-                &configs.app_config.play_icon
+    let (playing_track_uri, playing_id) = utils::playing_item_marker(state).unwrap_or_default();
+
+    let visual_selection_range = ui.visual_selection_range();
+
+    // the optional columns shown in the track table, which are toggled per context
+    let columns = match ui.current_page() {
+        PageState::Context { id: Some(id), .. } => data
+            .context_views
+            .get(&id.uri())
+            .map(|view| view.columns)
+            .unwrap_or_default(),
+        _ => TrackTableColumns::default(),
+    };
+
+    // construct the row of the `id`-th track in the track table,
+    // which is numbered by the track's position in the ungrouped track table
+    let track_row = |id: usize, number: usize, t: &Track| {
+        let is_visually_selected = visual_selection_range
+            .as_ref()
+            .is_some_and(|range| range.contains(&id));
+        let (number, style) = if playing_track_uri == t.id.uri() {
+            (playing_id.to_string(), ui.theme.current_playing())
+        } else if !t.is_playable() {
+            // dim unplayable tracks
+            (
+                (number + 1).to_string(),
+                Style::default().add_modifier(Modifier::DIM),
+            )
+        } else {
+            ((number + 1).to_string(), Style::default())
+        };
+        // highlight the tracks in the visual selection like the selected track
+        let style = if is_visually_selected {
+            ui.theme.selection(true)
+        } else {
+            style
+        };
+        let mut cells = vec![
+            Cell::from(if data.user_data.is_liked_track(t) {
+                &configs.app_config.liked_icon
             } else {
-                &configs.app_config.pause_icon
-            };
+                ""
+            }),
+            Cell::from(number),
+            Cell::from(utils::highlight_search_matches(
+                ui,
+                match t.unavailable_reason {
+                    Some(ref reason) => format!("{} ({})", t.display_name(), tr(reason)),
+                    None => t.display_name().to_string(),
+                },
+            )),
+            Cell::from(utils::highlight_search_matches(ui, t.artists_info())),
+        ];
+        if columns.album {
+            cells.push(Cell::from(t.album_info()));
         }
-    }
+        if columns.year {
+            cells.push(Cell::from(
+                t.year().map(|y| y.to_string()).unwrap_or_default(),
+            ));
+        }
+        if columns.popularity {
+            cells.push(Cell::from(
+                t.popularity.map(|p| p.to_string()).unwrap_or_default(),
+            ));
+        }
+        cells.push(Cell::from(format!(
+            "{}:{:02}",
+            t.duration.as_secs() / 60,
+            t.duration.as_secs() % 60,
+        )));
+        Row::new(cells).style(style)
+    };
+    let group_header_row = |header: String| {
+        Row::new(vec![Cell::from(""), Cell::from(""), Cell::from(header)])
+            .style(ui.theme.table_header())
+    };
 
+    let is_grouped = ui.is_track_table_grouped;
     let n_tracks = tracks.len();
-    let rows = tracks
-        .into_iter()
-        .enumerate()
-        .map(|(id, t)| {
-            let (id, style) = if playing_track_uri == t.id.uri() {
-                (playing_id.to_string(), ui.theme.current_playing())
+    // the row positions of the track table's items, which differ from the items' positions
+    // in a grouped track table because of the group header rows
+    let mut item_rows = vec![];
+    let rows = if is_grouped {
+        let mut rows = vec![];
+        let mut number = 0;
+        for group in group_tracks(&tracks) {
+            let n_group_tracks = group.tracks.len();
+            if ui.collapsed_track_groups.contains(&group.name) {
+                // a collapsed group is represented by a header row in place of its first track
+                item_rows.push(rows.len());
+                rows.push(group_header_row(format!(
+                    "▸ {} ({} {})",
+                    group.name,
+                    n_group_tracks,
+                    tr("songs")
+                )));
             } else {
-                ((id + 1).to_string(), Style::default())
-            };
-            Row::new(vec![
-                Cell::from(if data.user_data.is_liked_track(t) {
-                    &configs.app_config.liked_icon
-                } else {
-                    ""
-                }),
-                Cell::from(id),
-                Cell::from(t.display_name()),
-                Cell::from(t.artists_info()),
-                Cell::from(t.album_info()),
-                Cell::from(format!(
-                    "{}:{:02}",
-                    t.duration.as_secs() / 60,
-                    t.duration.as_secs() % 60,
-                )),
-            ])
-            .style(style)
-        })
-        .collect::<Vec<_>>();
+                rows.push(group_header_row(format!("▾ {}", group.name)));
+                for (i, t) in group.tracks.into_iter().enumerate() {
+                    let row = track_row(item_rows.len(), number + i, t);
+                    item_rows.push(rows.len());
+                    rows.push(row);
+                }
+            }
+            number += n_group_tracks;
+        }
+        rows
+    } else {
+        tracks
+            .into_iter()
+            .enumerate()
+            .map(|(id, t)| track_row(id, id, t))
+            .collect()
+    };
 
-    let track_table = Table::new(
-        rows,
-        [
-            Constraint::Length(configs.app_config.liked_icon.chars().count() as u16),
-            Constraint::Length(4),
-            Constraint::Fill(4),
-            Constraint::Fill(3),
-            Constraint::Fill(5),
-            Constraint::Fill(1),
-        ],
-    )
-    .header(
-        Row::new(vec![
-            Cell::from(""),
-            Cell::from("#"),
-            Cell::from("Title"),
-            Cell::from("Artists"),
-            Cell::from("Album"),
-            Cell::from("Duration"),
-        ])
-        .style(ui.theme.table_header()),
-    )
-    .column_spacing(2)
-    .highlight_style(ui.theme.selection(is_active));
+    let mut constraints = vec![
+        Constraint::Length(configs.app_config.liked_icon.chars().count() as u16),
+        Constraint::Length(
+            [
+                &configs.app_config.play_icon,
+                &configs.app_config.pause_icon,
+            ]
+            .iter()
+            .map(|icon| icon.chars().count() as u16)
+            .fold(4, std::cmp::max),
+        ),
+        Constraint::Fill(4),
+        Constraint::Fill(3),
+    ];
+    let mut header = vec![
+        Cell::from(""),
+        Cell::from("#"),
+        Cell::from(tr("Title")),
+        Cell::from(tr("Artists")),
+    ];
+    if columns.album {
+        constraints.push(Constraint::Fill(5));
+        header.push(Cell::from(tr("Album")));
+    }
+    if columns.year {
+        constraints.push(Constraint::Length(tr("Year").chars().count().max(4) as u16));
+        header.push(Cell::from(tr("Year")));
+    }
+    if columns.popularity {
+        constraints.push(Constraint::Length(tr("Popularity").chars().count() as u16));
+        header.push(Cell::from(tr("Popularity")));
+    }
+    constraints.push(Constraint::Fill(1));
+    header.push(Cell::from(tr("Duration")));
+
+    let track_table = Table::new(rows, constraints)
+        .header(Row::new(header).style(ui.theme.table_header()))
+        .column_spacing(2)
+        .highlight_style(ui.theme.selection(is_active));
+    let track_table = if configs.app_config.screen_reader_mode {
+        track_table.highlight_symbol(utils::SCREEN_READER_HIGHLIGHT_SYMBOL)
+    } else {
+        track_table
+    };
 
+    if is_active {
+        ui.set_focused_window_area(rect, true, true, n_tracks);
+        if let (true, Some(area)) = (is_grouped, ui.focused_window_area.as_mut()) {
+            area.item_rows = Some(item_rows.clone());
+        }
+    }
     if let PageState::Context {
         state: Some(state), ..
     } = ui.current_page_mut()
@@ -878,6 +1722,16 @@ fn render_track_table(
             ContextPageUIState::Album { track_table } => track_table,
             ContextPageUIState::Tracks { track_table } => track_table,
         };
-        utils::render_table_window(frame, track_table, rect, n_tracks, track_table_state);
+        if is_grouped {
+            utils::render_grouped_table_window(
+                frame,
+                track_table,
+                rect,
+                &item_rows,
+                track_table_state,
+            );
+        } else {
+            utils::render_table_window(frame, track_table, rect, n_tracks, track_table_state);
+        }
     }
 }
@@ -1,18 +1,35 @@
-use super::{utils::construct_and_render_block, *};
+use super::{utils::construct_and_render_block_with_title_style, *};
+
+/// the width of the volume gauge next to the playback's progress bar
+const VOLUME_GAUGE_WIDTH: u16 = 16;
 
 /// Render a playback window showing information about the current playback, which includes
 /// - track title, artists, album
 /// - playback metadata (playing state, repeat state, shuffle state, volume, device, etc)
 /// - cover image (if `image` feature is enabled)
 /// - playback progress bar
+/// - volume gauge
 pub fn render_playback_window(
     frame: &mut Frame,
     state: &SharedState,
     ui: &mut UIStateGuard,
     rect: Rect,
 ) -> Rect {
-    let (rect, other_rect) = split_rect_for_playback_window(rect);
-    let rect = construct_and_render_block("Playback", &ui.theme, Borders::ALL, frame, rect);
+    let (rect, other_rect) =
+        split_rect_for_playback_window(rect, ui.window_sizes.playback_window());
+    let title_style = if ui.is_playback_window_focused {
+        ui.theme.selection(true)
+    } else {
+        ui.theme.block_title()
+    };
+    let rect = construct_and_render_block_with_title_style(
+        "Playback",
+        title_style,
+        &ui.theme,
+        Borders::ALL,
+        frame,
+        rect,
+    );
 
     let player = state.player.read();
     if let Some(ref playback) = player.playback {
@@ -101,7 +118,22 @@ pub fn render_playback_window(
             };
 
             if let Some(ref playback) = player.buffered_playback {
-                let playback_text = construct_playback_text(ui, track, playback);
+                let is_liked = track.id.as_ref().is_some_and(|id| {
+                    state
+                        .data
+                        .read()
+                        .user_data
+                        .saved_tracks
+                        .contains_key(&id.uri())
+                });
+                let queue_remaining = player.queue_remaining();
+                let mut playback_text =
+                    construct_playback_text(ui, track, playback, is_liked, queue_remaining);
+                if let Some(ref queue) = player.queue {
+                    playback_text
+                        .lines
+                        .extend(construct_next_up_lines(ui, &queue.queue));
+                }
                 let playback_desc = Paragraph::new(playback_text).wrap(Wrap { trim: false });
                 frame.render_widget(playback_desc, metadata_rect);
             }
@@ -110,6 +142,21 @@ pub fn render_playback_window(
                 player.playback_progress().expect("non-empty playback"),
                 track.duration,
             );
+            // render a volume gauge next to the progress bar, except in the screen reader mode
+            // in which the volume is already described by the playback's metadata
+            let progress_bar_rect = match player.buffered_playback {
+                Some(ref playback) if !config::get_config().app_config.screen_reader_mode => {
+                    let chunks = Layout::horizontal([
+                        Constraint::Fill(0),
+                        Constraint::Length(VOLUME_GAUGE_WIDTH),
+                    ])
+                    .spacing(1)
+                    .split(progress_bar_rect);
+                    render_volume_gauge(frame, ui, playback, chunks[1]);
+                    chunks[0]
+                }
+                _ => progress_bar_rect,
+            };
             render_playback_progress_bar(frame, ui, progress, track, progress_bar_rect);
         }
     } else {
@@ -131,11 +178,12 @@ pub fn render_playback_window(
         }
 
         frame.render_widget(
-            Paragraph::new(
-                "No playback found.\n \
-                 Please make sure there is a running Spotify device and try to connect to one using the `SwitchDevice` command.\n \
-                 You may also need to set up Spotify Connect to see available devices as in https://github.com/aome510/spotify-player#spotify-connect."
-            )
+            Paragraph::new(format!(
+                "{}\n {}\n {} https://github.com/aome510/spotify-player#spotify-connect.",
+                tr("No playback found."),
+                tr("Please make sure there is a running Spotify device and try to connect to one using the `SwitchDevice` command."),
+                tr("You may also need to set up Spotify Connect to see available devices as in"),
+            ))
             .wrap(Wrap { trim: true }),
             rect,
         );
@@ -148,6 +196,8 @@ fn construct_playback_text(
     ui: &UIStateGuard,
     track: &rspotify_model::FullTrack,
     playback: &PlaybackMetadata,
+    is_liked: bool,
+    queue_remaining: Option<(usize, chrono::Duration)>,
 ) -> Text<'static> {
     // Construct a "styled" text (`playback_text`) from playback's data
     // based on a user-configurable format string (app_config.playback_format)
@@ -198,19 +248,37 @@ fn construct_playback_text(
                 ui.theme.playback_artists(),
             ),
             "{album}" => (track.album.name.to_owned(), ui.theme.playback_album()),
+            "{liked}" => (
+                if is_liked {
+                    configs.app_config.liked_icon.clone()
+                } else {
+                    String::new()
+                },
+                ui.theme.playback_track(),
+            ),
+            "{queue}" => (
+                queue_remaining
+                    .map(super::utils::format_queue_remaining)
+                    .unwrap_or_default(),
+                ui.theme.playback_metadata(),
+            ),
             "{metadata}" => (
                 format!(
-                    "repeat: {} | shuffle: {} | volume: {} | device: {}",
+                    "{}: {} | {}: {} | {}: {} | {}: {}",
+                    tr("repeat"),
                     if playback.fake_track_repeat_state {
                         "track (fake)"
                     } else {
                         <&'static str>::from(playback.repeat_state)
                     },
+                    tr("shuffle"),
                     playback.shuffle_state,
+                    tr("volume"),
                     match playback.mute_state {
-                        Some(volume) => format!("{volume}% (muted)"),
+                        Some(volume) => format!("{volume}% ({})", tr("muted")),
                         None => format!("{}%", playback.volume.unwrap_or_default()),
                     },
+                    tr("device"),
                     playback.device_name,
                 ),
                 ui.theme.playback_metadata(),
@@ -230,6 +298,58 @@ fn construct_playback_text(
     playback_text
 }
 
+/// Construct the lines previewing the next upcoming items in the playback's queue
+fn construct_next_up_lines(
+    ui: &UIStateGuard,
+    queue: &[rspotify_model::PlayableItem],
+) -> Vec<Line<'static>> {
+    let configs = config::get_config();
+
+    queue
+        .iter()
+        .take(configs.app_config.playback_next_up_count)
+        .map(|item| {
+            let text = match item {
+                rspotify_model::PlayableItem::Track(track) => format!(
+                    "{}: {} • {}",
+                    tr("Next"),
+                    track.name,
+                    crate::utils::map_join(&track.artists, |a| &a.name, ", ")
+                ),
+                rspotify_model::PlayableItem::Episode(episode) => {
+                    format!("{}: {} • {}", tr("Next"), episode.name, episode.show.name)
+                }
+            };
+            Line::styled(text, ui.theme.playback_metadata())
+        })
+        .collect()
+}
+
+fn render_volume_gauge(
+    frame: &mut Frame,
+    ui: &UIStateGuard,
+    playback: &PlaybackMetadata,
+    rect: Rect,
+) {
+    let (ratio, label) = match playback.mute_state {
+        Some(_) => (0.0, tr("muted").to_string()),
+        None => {
+            let volume = playback.volume.unwrap_or_default();
+            (f64::from(volume.min(100)) / 100.0, format!("{volume:>3}%"))
+        }
+    };
+    frame.render_widget(
+        LineGauge::default()
+            .gauge_style(ui.theme.playback_progress_bar())
+            .ratio(ratio)
+            .label(Span::styled(
+                label,
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+        rect,
+    );
+}
+
 fn render_playback_progress_bar(
     frame: &mut Frame,
     ui: &mut UIStateGuard,
@@ -237,12 +357,28 @@ fn render_playback_progress_bar(
     track: &rspotify_model::FullTrack,
     rect: Rect,
 ) {
-    // Negative numbers can sometimes appear from progress.num_seconds() so this stops
-    // them coming through into the ratios
-    let ratio =
-        (progress.num_seconds() as f64 / track.duration.num_seconds() as f64).clamp(0.0, 1.0);
+    // Negative numbers can sometimes appear from progress.num_milliseconds() so this stops
+    // them coming through into the ratios.
+    // The ratio is computed in milliseconds to advance the progress bar smoothly between renders
+    let ratio = (progress.num_milliseconds() as f64 / track.duration.num_milliseconds() as f64)
+        .clamp(0.0, 1.0);
+
+    let configs = config::get_config();
+    if configs.app_config.screen_reader_mode {
+        // render the progress as a plain text instead of a gauge in the screen reader mode
+        frame.render_widget(
+            Paragraph::new(format!(
+                "{}/{}",
+                crate::utils::format_duration(&progress),
+                crate::utils::format_duration(&track.duration),
+            )),
+            rect,
+        );
+        ui.playback_progress_bar_rect = rect;
+        return;
+    }
 
-    match config::get_config().app_config.progress_bar_type {
+    match configs.app_config.progress_bar_type {
         config::ProgressBarType::Line => frame.render_widget(
             LineGauge::default()
                 .gauge_style(ui.theme.playback_progress_bar())
@@ -330,9 +466,8 @@ fn render_playback_cover_image(state: &SharedState, ui: &mut UIStateGuard) -> Re
 
 /// Split the given area into two, the first one for the playback window
 /// and the second one for the main application's layout (popup, page, etc).
-fn split_rect_for_playback_window(rect: Rect) -> (Rect, Rect) {
+fn split_rect_for_playback_window(rect: Rect, playback_width: usize) -> (Rect, Rect) {
     let configs = config::get_config();
-    let playback_width = configs.app_config.playback_window_width;
     // the playback window's width should not be smaller than the cover image's width + 1
     #[cfg(feature = "image")]
     let playback_width = std::cmp::max(configs.app_config.cover_img_width + 1, playback_width);
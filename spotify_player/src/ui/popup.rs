@@ -56,6 +56,151 @@ pub fn render_popup(
                 );
                 (chunks[0], true)
             }
+            PopupState::BookmarkCreate { name, .. } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let name_input = construct_and_render_block(
+                    "Enter Name for New Bookmark:",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+                frame.render_widget(name.widget(true), name_input);
+                (chunks[0], true)
+            }
+            PopupState::TrackYearFilter { years } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let years_input = construct_and_render_block(
+                    "Enter Release Years (e.g. 1995, 1990s, 1990-1999):",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+                frame.render_widget(years.widget(true), years_input);
+                (chunks[0], true)
+            }
+            PopupState::TrackTempoFilter { tempos } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let tempos_input = construct_and_render_block(
+                    "Enter Tempos in BPM (e.g. 124, 120-128):",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+                frame.render_widget(tempos.widget(true), tempos_input);
+                (chunks[0], true)
+            }
+            PopupState::SeekPosition { position } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let position_input = construct_and_render_block(
+                    "Enter Position to Seek to (e.g. 90, 1:30, 1:02:30):",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+                frame.render_widget(position.widget(true), position_input);
+                (chunks[0], true)
+            }
+            PopupState::TrackNumber { number } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let number_input = construct_and_render_block(
+                    "Enter Track Number to Go to:",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+                frame.render_widget(number.widget(true), number_input);
+                (chunks[0], true)
+            }
+            PopupState::Authenticate {
+                username,
+                password,
+                current_field,
+            } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let popup_chunks =
+                    Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .split(chunks[1]);
+
+                let username_input = construct_and_render_block(
+                    "Authentication Required, Enter Username:",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    popup_chunks[0],
+                );
+                let password_input = construct_and_render_block(
+                    "Enter Password:",
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    popup_chunks[1],
+                );
+
+                frame.render_widget(
+                    username.widget(AuthenticateCurrentField::Username == *current_field),
+                    username_input,
+                );
+                frame.render_widget(
+                    password.masked_widget(AuthenticateCurrentField::Password == *current_field),
+                    password_input,
+                );
+                (chunks[0], true)
+            }
+            PopupState::PartyModePassphrase { passphrase } => {
+                let chunks =
+                    Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+
+                let title = if ui.party_mode_passphrase.is_some() {
+                    "Enter Passphrase to Disable Party Mode:"
+                } else {
+                    "Enter Passphrase to Enable Party Mode:"
+                };
+                let passphrase_input =
+                    construct_and_render_block(title, &ui.theme, Borders::ALL, frame, chunks[1]);
+                frame.render_widget(passphrase.masked_widget(true), passphrase_input);
+                (chunks[0], true)
+            }
+            PopupState::TrackCredits(track) => {
+                let chunks =
+                    Layout::vertical([Constraint::Fill(0), Constraint::Length(10)]).split(rect);
+
+                let rect = construct_and_render_block(
+                    &format!("{} {}", tr("Credits of"), track.name),
+                    &ui.theme,
+                    Borders::ALL,
+                    frame,
+                    chunks[1],
+                );
+
+                match state.data.read().caches.track_credits.get(&track.id.uri()) {
+                    Some(credits) => {
+                        frame.render_widget(
+                            Paragraph::new(construct_track_credits_lines(ui, credits))
+                                .wrap(Wrap { trim: false }),
+                            rect,
+                        );
+                    }
+                    None => utils::render_loading_window(frame, rect),
+                }
+                (chunks[0], false)
+            }
             PopupState::Search { query } => {
                 let chunks =
                     Layout::vertical([Constraint::Fill(0), Constraint::Length(3)]).split(rect);
@@ -63,14 +208,17 @@ pub fn render_popup(
                 let rect =
                     construct_and_render_block("Search", &ui.theme, Borders::ALL, frame, chunks[1]);
 
-                frame.render_widget(Paragraph::new(format!("/{query}")), rect);
+                let input_chunks =
+                    Layout::horizontal([Constraint::Length(1), Constraint::Fill(0)]).split(rect);
+                frame.render_widget(Paragraph::new("/"), input_chunks[0]);
+                frame.render_widget(query.widget(true), input_chunks[1]);
                 (chunks[0], true)
             }
             PopupState::ActionList(item, _) => {
                 let rect = render_list_popup(
                     frame,
                     rect,
-                    &format!("Actions on {}", item.name()),
+                    &format!("{} {}", tr("Actions on"), item.name()),
                     item.actions_desc()
                         .into_iter()
                         .enumerate()
@@ -91,23 +239,119 @@ pub fn render_popup(
                 let items = player
                     .devices
                     .iter()
-                    .map(|d| (format!("{} | {}", d.name, d.id), current_device_id == d.id))
+                    .map(|d| {
+                        let device_type: &'static str = (&d.device_type).into();
+                        let volume = d
+                            .volume
+                            .map(|v| format!("{v}%"))
+                            .unwrap_or_else(|| "-".to_string());
+                        let status = if d.is_active { " | active" } else { "" };
+                        (
+                            format!("{} | {device_type} | {volume}{status}", d.name),
+                            current_device_id == d.id,
+                        )
+                    })
                     .collect();
 
                 let rect = render_list_popup(frame, rect, "Devices", items, 5, ui);
                 (rect, false)
             }
+            PopupState::GuestRequestList(_) => {
+                let items = ui
+                    .guest_requests
+                    .iter()
+                    .map(|t| (format!("{} • {}", t.name, t.artists_info()), false))
+                    .collect();
+
+                let rect = render_list_popup(frame, rect, "Guest Requests", items, 7, ui);
+                (rect, false)
+            }
+            PopupState::SkippedTrackList {
+                actions, tracks, ..
+            } => {
+                let items = actions
+                    .iter()
+                    .map(|a| match a {
+                        SkippedTracksAction::RemoveFromPlaylist(_) => {
+                            tr("Remove all from the playlist").to_string()
+                        }
+                        SkippedTracksAction::AddToBlocklist => {
+                            tr("Add all to the blocklist").to_string()
+                        }
+                    })
+                    .chain(tracks.iter().map(|(t, n)| {
+                        format!("{} • {} ({n} {})", t.name, t.artists_info(), tr("skips"))
+                    }))
+                    .map(|s| (s, false))
+                    .collect();
+
+                let rect = render_list_popup(frame, rect, "Most Skipped Tracks", items, 12, ui);
+                (rect, false)
+            }
+            PopupState::LibraryCleanup {
+                items,
+                marked,
+                confirming,
+                ..
+            } => {
+                let n_marked = marked.iter().filter(|&&m| m).count();
+                let action = if *confirming {
+                    format!(
+                        "{} {n_marked} {}",
+                        tr("Confirm removing"),
+                        tr("items (choose again to confirm)")
+                    )
+                } else {
+                    format!("{} {n_marked} {}", tr("Remove"), tr("marked items"))
+                };
+                let items = std::iter::once(action)
+                    .chain(items.iter().zip(marked).map(|(item, &marked)| {
+                        let (kind, name) = match item {
+                            Item::Playlist(p) => (tr("Playlist"), p.name.as_str()),
+                            Item::Album(a) => (tr("Album"), a.name.as_str()),
+                            Item::Artist(a) => (tr("Artist"), a.name.as_str()),
+                            Item::Track(t) => (tr("Track"), t.name.as_str()),
+                        };
+                        format!("[{}] {kind}: {name}", if marked { "x" } else { " " })
+                    }))
+                    .map(|s| (s, false))
+                    .collect();
+
+                let rect = render_list_popup(frame, rect, "Library Cleanup", items, 12, ui);
+                (rect, false)
+            }
             PopupState::ThemeList(themes, ..) => {
                 let items = themes.iter().map(|t| (t.name.clone(), false)).collect();
 
                 let rect = render_list_popup(frame, rect, "Themes", items, 7, ui);
                 (rect, false)
             }
+            PopupState::GenreList(_, genres, _) => {
+                let items = genres.iter().map(|g| (g.clone(), false)).collect();
+
+                let rect = render_list_popup(frame, rect, "Genres", items, 7, ui);
+                (rect, false)
+            }
             PopupState::UserPlaylistList(action, _) => {
                 let data = state.data.read();
                 let playlists = match action {
                     PlaylistPopupAction::Browse => data.user_data.playlists.iter().collect(),
-                    PlaylistPopupAction::AddTrack(_) => data.user_data.modifiable_playlists(),
+                    PlaylistPopupAction::AddTrack(_) | PlaylistPopupAction::AddTracks(_) => {
+                        data.user_data.modifiable_playlists()
+                    }
+                    PlaylistPopupAction::Compare(playlist) => data
+                        .user_data
+                        .playlists
+                        .iter()
+                        .filter(|p| p.id != playlist.id)
+                        .collect(),
+                    PlaylistPopupAction::MergeFrom => data.user_data.playlists.iter().collect(),
+                    PlaylistPopupAction::MergeInto(playlist) => data
+                        .user_data
+                        .modifiable_playlists()
+                        .into_iter()
+                        .filter(|p| p.id != playlist.id)
+                        .collect(),
                 };
                 let items = playlists
                     .into_iter()
@@ -153,6 +397,37 @@ pub fn render_popup(
     }
 }
 
+/// Construct the lines describing a track's credits
+fn construct_track_credits_lines<'a>(ui: &UIStateGuard, credits: &TrackCredits) -> Vec<Line<'a>> {
+    let field = |label: &str, value: String| utils::construct_field_line(&ui.theme, label, value);
+
+    let mut lines = vec![];
+    // The Spotify API doesn't specify the artists' roles, other than that
+    // the first artist is the track's main artist
+    if let Some((main_artist, featured_artists)) = credits.artists.split_first() {
+        lines.push(field("Main Artist", main_artist.clone()));
+        if !featured_artists.is_empty() {
+            lines.push(field("Featured Artists", featured_artists.join(", ")));
+        }
+    }
+    lines.push(field("Album", credits.album.clone()));
+    lines.push(field("Disc Number", credits.disc_number.to_string()));
+    lines.push(field("Track Number", credits.track_number.to_string()));
+    lines.push(field(
+        "ISRC",
+        credits.isrc.clone().unwrap_or_else(|| "-".to_string()),
+    ));
+    lines.push(field(
+        "Available Markets",
+        format!(
+            "({}) {}",
+            credits.available_markets.len(),
+            credits.available_markets.join(", ")
+        ),
+    ));
+    lines
+}
+
 /// A helper function to render a list popup
 fn render_list_popup(
     frame: &mut Frame,
@@ -165,8 +440,9 @@ fn render_list_popup(
     let chunks = Layout::vertical([Constraint::Fill(0), Constraint::Length(length)]).split(rect);
 
     let rect = construct_and_render_block(title, &ui.theme, Borders::ALL, frame, chunks[1]);
-    let (list, len) = utils::construct_list_widget(&ui.theme, items, true);
+    let (list, len) = utils::construct_list_widget(ui, items, true);
 
+    ui.set_focused_window_area(rect, true, false, len);
     utils::render_list_window(
         frame,
         list,
@@ -225,3 +501,105 @@ pub fn render_shortcut_help_popup(frame: &mut Frame, ui: &mut UIStateGuard, rect
         chunks[0]
     }
 }
+
+/// Render the volume overlay (if shown) at the center of the given area.
+///
+/// The overlay is shown for a short duration whenever the playback's volume changes,
+/// either by a volume command or by another source like a Spotify Connect device.
+pub fn render_volume_overlay(
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) {
+    let (volume, mute_state) = match state.player.read().buffered_playback {
+        Some(ref playback) => (playback.volume, playback.mute_state),
+        None => return,
+    };
+    if let Some(last_volume) = ui.last_volume.replace((volume, mute_state)) {
+        if last_volume != (volume, mute_state) {
+            ui.show_volume_overlay();
+        }
+    }
+
+    let elapsed = match ui.volume_overlay_shown_at {
+        Some(shown_at) if shown_at.elapsed() < VOLUME_OVERLAY_DURATION => shown_at.elapsed(),
+        Some(_) => {
+            ui.volume_overlay_shown_at = None;
+            return;
+        }
+        None => return,
+    };
+
+    let (ratio, label) = match mute_state {
+        Some(volume) => (
+            0.0,
+            format!("{}: {volume}% ({})", tr("volume"), tr("muted")),
+        ),
+        None => {
+            let volume = volume.unwrap_or_default();
+            (
+                f64::from(volume.min(100)) / 100.0,
+                format!("{}: {volume}%", tr("volume")),
+            )
+        }
+    };
+
+    let width = std::cmp::min(40, rect.width);
+    // 3 for the gauge and top/bottom borders
+    let height = std::cmp::min(3, rect.height);
+    let overlay_rect = Rect {
+        x: rect.x + (rect.width - width) / 2,
+        y: rect.y + (rect.height - height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, overlay_rect);
+    let overlay_rect = construct_and_render_block("", &ui.theme, Borders::ALL, frame, overlay_rect);
+    if config::get_config().app_config.screen_reader_mode {
+        frame.render_widget(Paragraph::new(label), overlay_rect);
+        return;
+    }
+
+    // fade the overlay out by dimming it during the last third of its duration
+    let style = if elapsed > VOLUME_OVERLAY_DURATION * 2 / 3 {
+        Style::default().add_modifier(Modifier::DIM)
+    } else {
+        Style::default()
+    };
+    frame.render_widget(
+        Gauge::default()
+            .gauge_style(ui.theme.playback_progress_bar().patch(style))
+            .ratio(ratio)
+            .label(Span::styled(label, style.add_modifier(Modifier::BOLD))),
+        overlay_rect,
+    );
+}
+
+/// Render a toast (if any) at the bottom right corner of the given area
+pub fn render_toast(frame: &mut Frame, ui: &mut UIStateGuard, rect: Rect) {
+    let message = match ui.toast {
+        Some(ref toast) if toast.created_at.elapsed() < TOAST_DURATION => toast.message.clone(),
+        Some(_) => {
+            ui.toast = None;
+            return;
+        }
+        None => return,
+    };
+
+    // +2 for left/right borders
+    let width = std::cmp::min(message.chars().count() as u16 + 2, rect.width);
+    // 3 for the message and top/bottom borders
+    let height = std::cmp::min(3, rect.height);
+    let toast_rect = Rect {
+        x: rect.x + rect.width - width,
+        y: rect.y + rect.height - height,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, toast_rect);
+    let toast_rect = construct_and_render_block("", &ui.theme, Borders::ALL, frame, toast_rect);
+    frame.render_widget(Paragraph::new(message), toast_rect);
+}
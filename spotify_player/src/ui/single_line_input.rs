@@ -10,6 +10,10 @@ pub struct LineInput {
     // cursor. Otherwise, you have to shuffle back and forth between String and String::chars().
     line: Vec<char>,
     cursor: usize,
+    /// Previously submitted inputs, ordered from the oldest to the newest
+    history: Vec<String>,
+    /// The position of the displayed input in the history, `None` if not browsing the history
+    history_pos: Option<usize>,
 }
 
 pub enum InputEffect {
@@ -31,19 +35,33 @@ impl LineInput {
         Self {
             line: str,
             cursor: 0,
+            history: vec![],
+            history_pos: None,
         }
     }
 
+    /// Create an empty input whose history can be browsed using `up`/`down` keys
+    pub fn with_history(history: Vec<String>) -> Self {
+        Self {
+            history,
+            ..Self::default()
+        }
+    }
+
+    /// Handle a key input, returns `None` if the key is not consumed by the input
+    ///
+    /// Supported keys (in addition to inserting characters):
+    /// - `left`/`right`, `home`/`C-a`, `end`/`C-e`: move the cursor
+    /// - `M-b`/`M-f`, `C-left`/`C-right`: move the cursor by one word
+    /// - `backspace`/`delete`: delete the character before/at the cursor
+    /// - `C-w`/`M-backspace`: delete the word before the cursor
+    /// - `C-u`/`C-k`: delete the text before/after the cursor
+    /// - `up`/`down`: browse the input's history (if any)
     pub fn input(&mut self, key: &Key) -> Option<InputEffect> {
         match key {
             Key::None(c) => match c {
                 KeyCode::Char(c) => {
-                    if self.cursor == self.line.len() {
-                        self.line.push(*c);
-                    } else {
-                        self.line.insert(self.cursor, *c);
-                    }
-                    self.cursor += 1;
+                    self.insert(*c);
                     Some(InputEffect::TextChanged)
                 }
                 KeyCode::Backspace => {
@@ -56,26 +74,121 @@ impl LineInput {
                         Some(InputEffect::TextChanged)
                     }
                 }
-                KeyCode::Left => {
-                    if self.cursor == 0 {
-                        Some(InputEffect::Ack)
-                    } else {
-                        self.cursor -= 1;
-                        Some(InputEffect::CursorMoved)
-                    }
-                }
-                KeyCode::Right => {
+                KeyCode::Delete => {
                     if self.cursor == self.line.len() {
                         Some(InputEffect::Ack)
                     } else {
-                        self.cursor += 1;
-                        Some(InputEffect::CursorMoved)
+                        self.line.remove(self.cursor);
+                        Some(InputEffect::TextChanged)
                     }
                 }
+                KeyCode::Left => self.move_cursor(self.cursor.saturating_sub(1)),
+                KeyCode::Right => self.move_cursor(std::cmp::min(self.cursor + 1, self.line.len())),
+                KeyCode::Home => self.move_cursor(0),
+                KeyCode::End => self.move_cursor(self.line.len()),
+                KeyCode::Up if !self.history.is_empty() => {
+                    let pos = match self.history_pos {
+                        None => self.history.len() - 1,
+                        Some(pos) => pos.saturating_sub(1),
+                    };
+                    self.set_text_from_history(Some(pos));
+                    Some(InputEffect::TextChanged)
+                }
+                KeyCode::Down if !self.history.is_empty() => {
+                    let pos = match self.history_pos {
+                        None => return Some(InputEffect::Ack),
+                        Some(pos) if pos + 1 < self.history.len() => Some(pos + 1),
+                        // go past the newest entry to get an empty input
+                        Some(_) => None,
+                    };
+                    self.set_text_from_history(pos);
+                    Some(InputEffect::TextChanged)
+                }
+                _ => None,
+            },
+            Key::Ctrl(c) => match c {
+                KeyCode::Char('a') => self.move_cursor(0),
+                KeyCode::Char('e') => self.move_cursor(self.line.len()),
+                KeyCode::Left => self.move_cursor(self.prev_word_boundary()),
+                KeyCode::Right => self.move_cursor(self.next_word_boundary()),
+                KeyCode::Char('w') => self.delete_range(self.prev_word_boundary(), self.cursor),
+                KeyCode::Char('u') => self.delete_range(0, self.cursor),
+                KeyCode::Char('k') => self.delete_range(self.cursor, self.line.len()),
                 _ => None,
             },
-            _ => None,
+            Key::Alt(c) => match c {
+                KeyCode::Char('b') => self.move_cursor(self.prev_word_boundary()),
+                KeyCode::Char('f') => self.move_cursor(self.next_word_boundary()),
+                KeyCode::Backspace => self.delete_range(self.prev_word_boundary(), self.cursor),
+                _ => None,
+            },
+            Key::Unknown => None,
+        }
+    }
+
+    /// Insert a text at the cursor, e.g. when pasting a text from the clipboard.
+    /// Line breaks in the text are replaced with spaces.
+    pub fn insert_str(&mut self, text: &str) {
+        text.chars()
+            .map(|c| if c == '\n' || c == '\r' { ' ' } else { c })
+            .for_each(|c| self.insert(c));
+    }
+
+    fn insert(&mut self, c: char) {
+        self.line.insert(self.cursor, c);
+        self.cursor += 1;
+    }
+
+    fn move_cursor(&mut self, cursor: usize) -> Option<InputEffect> {
+        if self.cursor == cursor {
+            Some(InputEffect::Ack)
+        } else {
+            self.cursor = cursor;
+            Some(InputEffect::CursorMoved)
+        }
+    }
+
+    /// Delete the characters in the range `[start, end)` and move the cursor to `start`
+    fn delete_range(&mut self, start: usize, end: usize) -> Option<InputEffect> {
+        if start >= end {
+            return Some(InputEffect::Ack);
+        }
+        self.line.drain(start..end);
+        self.cursor = start;
+        Some(InputEffect::TextChanged)
+    }
+
+    /// Get the position of the start of the word before the cursor
+    fn prev_word_boundary(&self) -> usize {
+        let mut pos = self.cursor;
+        while pos > 0 && self.line[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        while pos > 0 && !self.line[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        pos
+    }
+
+    /// Get the position of the end of the word after the cursor
+    fn next_word_boundary(&self) -> usize {
+        let mut pos = self.cursor;
+        while pos < self.line.len() && self.line[pos].is_whitespace() {
+            pos += 1;
+        }
+        while pos < self.line.len() && !self.line[pos].is_whitespace() {
+            pos += 1;
         }
+        pos
+    }
+
+    fn set_text_from_history(&mut self, pos: Option<usize>) {
+        self.history_pos = pos;
+        self.line = match pos {
+            Some(pos) => self.history[pos].chars().collect(),
+            None => vec![],
+        };
+        self.cursor = self.line.len();
     }
 
     pub fn widget(&self, is_active: bool) -> impl Widget {
@@ -107,6 +220,30 @@ impl LineInput {
         Paragraph::new(formatted_line)
     }
 
+    /// An input widget with each character of the input masked, e.g. for a passphrase
+    pub fn masked_widget(&self, is_active: bool) -> impl Widget {
+        Self {
+            line: vec!['*'; self.line.len()],
+            cursor: self.cursor,
+            history: vec![],
+            history_pos: None,
+        }
+        .widget(is_active)
+    }
+
+    /// Submit the current input and add it to the input's history
+    pub fn submit(&mut self) -> String {
+        let text = self.get_text();
+        self.history.retain(|h| *h != text);
+        self.history.push(text.clone());
+        self.history_pos = None;
+        text
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
     pub fn is_empty(&self) -> bool {
         self.line.is_empty()
     }
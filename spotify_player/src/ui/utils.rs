@@ -11,7 +11,28 @@ pub fn construct_and_render_block(
     frame: &mut Frame,
     rect: Rect,
 ) -> Rect {
-    let mut title = title.to_string();
+    construct_and_render_block_with_title_style(
+        title,
+        theme.block_title(),
+        theme,
+        borders,
+        frame,
+        rect,
+    )
+}
+
+/// Construct and render a block with a custom style for the block's title.
+///
+/// This is mainly used to highlight the title of a focused block.
+pub fn construct_and_render_block_with_title_style(
+    title: &str,
+    title_style: Style,
+    theme: &config::Theme,
+    borders: Borders,
+    frame: &mut Frame,
+    rect: Rect,
+) -> Rect {
+    let mut title = crate::i18n::tr(title).to_string();
 
     let configs = config::get_config();
 
@@ -41,36 +62,158 @@ pub fn construct_and_render_block(
     }
 
     // Set `title` for the block
-    block = block.title(Span::styled(title, theme.block_title()));
+    block = block.title(Span::styled(title, title_style));
 
     frame.render_widget(block, rect);
     inner_rect
 }
 
+/// Construct a line displaying a labeled field, e.g. an item's detail
+pub fn construct_field_line<'a>(theme: &config::Theme, label: &str, value: String) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(
+            format!("{}: ", crate::i18n::tr(label)),
+            theme.table_header(),
+        ),
+        Span::raw(value),
+    ])
+}
+
+/// The symbol marking the selected item of a list/table in the screen reader mode
+pub const SCREEN_READER_HIGHLIGHT_SYMBOL: &str = "> ";
+
+/// Render a loading placeholder consisting of a spinner and some skeleton rows
+pub fn render_loading_window(frame: &mut Frame, rect: Rect) {
+    if config::get_config().app_config.screen_reader_mode {
+        frame.render_widget(Paragraph::new(tr("Loading...")), rect);
+        return;
+    }
+
+    const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+    let elapsed_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    let spinner = SPINNER_FRAMES[(elapsed_ms / 100) as usize % SPINNER_FRAMES.len()];
+
+    let skeleton_style = Style::default().add_modifier(Modifier::DIM);
+    let mut lines = vec![Line::from(format!("{spinner} {}", tr("Loading...")))];
+    lines.extend((0..rect.height.saturating_sub(1)).map(|i| {
+        // vary the skeleton rows' widths to look more like actual rows
+        let width = (rect.width as usize) * (6 - (i as usize % 3)) / 8;
+        Line::styled("░".repeat(width), skeleton_style)
+    }));
+
+    frame.render_widget(Paragraph::new(lines), rect);
+}
+
+/// Format the number of items in the queue and the queue's remaining play time,
+/// e.g. `14 tracks · 52 min left`
+pub fn format_queue_remaining((n_items, remaining): (usize, chrono::Duration)) -> String {
+    // round up the remaining minutes so that a non-empty queue never shows `0 min left`
+    let minutes = (remaining.num_seconds() + 59) / 60;
+    format!("{n_items} {} · {minutes} {}", tr("tracks"), tr("min left"))
+}
+
+/// Construct a line of a text, in which the characters matching the search popup's query
+/// (if any) are highlighted
+pub fn highlight_search_matches<'a>(ui: &UIState, text: String) -> Line<'a> {
+    let positions = ui.search_match_positions(&text);
+    if positions.is_empty() {
+        return Line::from(text);
+    }
+
+    let style = ui.theme.search_match();
+    let mut spans = vec![];
+    let mut span = String::new();
+    let mut is_match = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_c_match = positions.binary_search(&i).is_ok();
+        if is_c_match != is_match && !span.is_empty() {
+            let span = std::mem::take(&mut span);
+            spans.push(if is_match {
+                Span::styled(span, style)
+            } else {
+                Span::raw(span)
+            });
+        }
+        is_match = is_c_match;
+        span.push(c);
+    }
+    spans.push(if is_match {
+        Span::styled(span, style)
+    } else {
+        Span::raw(span)
+    });
+    Line::from(spans)
+}
+
 /// Construct a generic list widget
 pub fn construct_list_widget<'a>(
-    theme: &config::Theme,
+    ui: &UIState,
     items: Vec<(String, bool)>,
     is_active: bool,
 ) -> (List<'a>, usize) {
+    let theme = &ui.theme;
     let n_items = items.len();
+    let screen_reader_mode = config::get_config().app_config.screen_reader_mode;
 
-    (
-        List::new(
-            items
-                .into_iter()
-                .map(|(s, is_active)| {
-                    ListItem::new(s).style(if is_active {
-                        theme.current_playing()
+    let list = List::new(
+        items
+            .into_iter()
+            .map(|(s, is_active)| {
+                if is_active {
+                    // avoid signaling the active item only by color in the screen reader mode
+                    let s = if screen_reader_mode {
+                        format!("{s} (active)")
                     } else {
-                        Style::default()
-                    })
-                })
-                .collect::<Vec<_>>(),
-        )
-        .highlight_style(theme.selection(is_active)),
-        n_items,
+                        s
+                    };
+                    ListItem::new(highlight_search_matches(ui, s)).style(theme.current_playing())
+                } else {
+                    ListItem::new(highlight_search_matches(ui, s))
+                }
+            })
+            .collect::<Vec<_>>(),
     )
+    .highlight_style(theme.selection(is_active));
+
+    if screen_reader_mode {
+        (
+            list.highlight_symbol(SCREEN_READER_HIGHLIGHT_SYMBOL),
+            n_items,
+        )
+    } else {
+        (list, n_items)
+    }
+}
+
+/// Get the URI of the playback's current playing item and the icon marking the item
+/// in the tables and lists, which indicates whether the playback is playing or paused
+pub fn playing_item_marker(state: &SharedState) -> Option<(String, &'static str)> {
+    let configs = config::get_config();
+    let player = state.player.read();
+    let playback = player.playback.as_ref()?;
+    let uri = playback.item.as_ref()?.id()?.uri();
+    let icon = if playback.is_playing {
+        &configs.app_config.play_icon
+    } else {
+        &configs.app_config.pause_icon
+    };
+    Some((uri, icon))
+}
+
+/// Mark a list item's text with the playing item's icon if the item is the playing item
+pub fn mark_playing_item(
+    s: String,
+    uri: &str,
+    marker: Option<&(String, &'static str)>,
+) -> (String, bool) {
+    match marker {
+        Some((playing_uri, icon)) if playing_uri == uri => (format!("{icon} {s}"), true),
+        _ => (s, false),
+    }
 }
 
 // Adjust the `selected` position of a `ListState` if that position is out of index
@@ -112,3 +255,187 @@ pub fn render_table_window(
     adjust_table_state(state, len);
     frame.render_stateful_widget(widget, rect, state);
 }
+
+/// Render a table window whose items are interleaved with non-selectable rows (e.g. group headers).
+/// `item_rows` are the row positions of the table's items.
+pub fn render_grouped_table_window(
+    frame: &mut Frame,
+    widget: Table,
+    rect: Rect,
+    item_rows: &[usize],
+    state: &mut TableState,
+) {
+    adjust_table_state(state, item_rows.len());
+    let mut row_state = state.clone();
+    row_state.select(state.selected().and_then(|id| item_rows.get(id).copied()));
+    frame.render_stateful_widget(widget, rect, &mut row_state);
+    *state.offset_mut() = row_state.offset();
+}
+
+/// the width of an album grid's cell
+const ALBUM_GRID_CELL_WIDTH: u16 = 16;
+/// the height of an album cover thumbnail in an album grid's cell.
+/// Each terminal cell of the thumbnail renders two vertically stacked pixels.
+#[cfg(feature = "image")]
+const ALBUM_GRID_THUMBNAIL_HEIGHT: u16 = ALBUM_GRID_CELL_WIDTH / 2;
+/// the spacing between two cells of an album grid
+const ALBUM_GRID_SPACING: u16 = 1;
+
+/// A grid of albums, which is an alternative view of an album list
+pub struct AlbumGrid<'a> {
+    cells: Vec<AlbumGridCell<'a>>,
+    highlight_style: Style,
+    current_playing_style: Style,
+}
+
+struct AlbumGridCell<'a> {
+    album: &'a Album,
+    is_active: bool,
+}
+
+/// Construct an album grid widget
+pub fn construct_album_grid_widget<'a>(
+    theme: &config::Theme,
+    items: Vec<(&'a Album, bool)>,
+    is_active: bool,
+) -> AlbumGrid<'a> {
+    AlbumGrid {
+        cells: items
+            .into_iter()
+            .map(|(album, is_active)| AlbumGridCell { album, is_active })
+            .collect(),
+        highlight_style: theme.selection(is_active),
+        current_playing_style: theme.current_playing(),
+    }
+}
+
+/// Render an album grid window, whose selected cell is determined by a `ListState`.
+///
+/// Returns the cover image URLs of the rendered albums that are not yet in the image cache.
+#[cfg_attr(not(feature = "image"), allow(unused_variables, unused_mut))]
+pub fn render_album_grid_window(
+    frame: &mut Frame,
+    grid: AlbumGrid,
+    rect: Rect,
+    state: &mut ListState,
+    data: &DataReadGuard,
+) -> Vec<String> {
+    adjust_list_state(state, grid.cells.len());
+
+    #[cfg(feature = "image")]
+    let cell_height = ALBUM_GRID_THUMBNAIL_HEIGHT + 2;
+    #[cfg(not(feature = "image"))]
+    let cell_height = 2;
+
+    let n_columns = ((rect.width + ALBUM_GRID_SPACING)
+        / (ALBUM_GRID_CELL_WIDTH + ALBUM_GRID_SPACING))
+        .max(1) as usize;
+    let n_rows =
+        ((rect.height + ALBUM_GRID_SPACING) / (cell_height + ALBUM_GRID_SPACING)).max(1) as usize;
+
+    // scroll the grid so that the selected cell's row is visible
+    let selected = state.selected().unwrap_or_default();
+    let selected_row = selected / n_columns;
+    let mut first_row = state.offset() / n_columns;
+    if selected_row < first_row {
+        first_row = selected_row;
+    } else if selected_row >= first_row + n_rows {
+        first_row = selected_row + 1 - n_rows;
+    }
+    *state.offset_mut() = first_row * n_columns;
+
+    let screen_reader_mode = config::get_config().app_config.screen_reader_mode;
+    let mut missing_cover_urls = vec![];
+
+    for (i, cell) in grid
+        .cells
+        .iter()
+        .enumerate()
+        .skip(first_row * n_columns)
+        .take(n_rows * n_columns)
+    {
+        let (row, column) = ((i / n_columns - first_row) as u16, (i % n_columns) as u16);
+        let cell_rect = Rect {
+            x: rect.x + column * (ALBUM_GRID_CELL_WIDTH + ALBUM_GRID_SPACING),
+            y: rect.y + row * (cell_height + ALBUM_GRID_SPACING),
+            width: ALBUM_GRID_CELL_WIDTH,
+            height: cell_height,
+        }
+        .intersection(rect);
+
+        #[cfg(feature = "image")]
+        let cell_rect = {
+            let chunks = Layout::vertical([
+                Constraint::Length(ALBUM_GRID_THUMBNAIL_HEIGHT),
+                Constraint::Fill(0),
+            ])
+            .split(cell_rect);
+
+            if let Some(ref url) = cell.album.image_url {
+                match data.caches.images.get(url) {
+                    Some(image) => render_album_thumbnail(frame, image, chunks[0]),
+                    None => missing_cover_urls.push(url.clone()),
+                }
+            }
+
+            chunks[1]
+        };
+
+        let is_selected = i == selected;
+        let mut name = cell.album.name.clone();
+        if screen_reader_mode {
+            // avoid signaling the selected and active cells only by styles in the screen reader mode
+            if is_selected {
+                name = format!("{SCREEN_READER_HIGHLIGHT_SYMBOL}{name}");
+            }
+            if cell.is_active {
+                name = format!("{name} (active)");
+            }
+        }
+        let style = if is_selected {
+            grid.highlight_style
+        } else if cell.is_active {
+            grid.current_playing_style
+        } else {
+            Style::default()
+        };
+
+        let text = vec![
+            Line::styled(name, style),
+            Line::from(format!(
+                "{} • {}",
+                cell.album.year(),
+                crate::utils::map_join(&cell.album.artists, |a| &a.name, ", ")
+            )),
+        ];
+        frame.render_widget(Paragraph::new(text), cell_rect);
+    }
+
+    missing_cover_urls
+}
+
+/// Render an album's cover thumbnail using the upper half block character,
+/// whose foreground and background colors are the colors of two vertically stacked pixels
+#[cfg(feature = "image")]
+fn render_album_thumbnail(frame: &mut Frame, image: &image::DynamicImage, rect: Rect) {
+    let thumbnail = image
+        .resize_exact(
+            rect.width as u32,
+            rect.height as u32 * 2,
+            image::imageops::FilterType::Triangle,
+        )
+        .to_rgb8();
+
+    let buffer = frame.buffer_mut();
+    for y in 0..rect.height {
+        for x in 0..rect.width {
+            let top = thumbnail.get_pixel(x as u32, y as u32 * 2);
+            let bottom = thumbnail.get_pixel(x as u32, y as u32 * 2 + 1);
+            buffer
+                .get_mut(rect.x + x, rect.y + y)
+                .set_symbol("▀")
+                .set_fg(Color::Rgb(top[0], top[1], top[2]))
+                .set_bg(Color::Rgb(bottom[0], bottom[1], bottom[2]));
+        }
+    }
+}
@@ -33,6 +33,113 @@ where
     })
 }
 
+/// the score of a fuzzy matched character
+const FUZZY_SCORE_MATCH: i64 = 16;
+/// the bonus score of a fuzzy matched character at the start of a word
+const FUZZY_BONUS_WORD_START: i64 = 8;
+/// the bonus score of a fuzzy matched character right after the previously matched character
+const FUZZY_BONUS_CONSECUTIVE: i64 = 4;
+/// the penalty of an unmatched character between two fuzzy matched characters
+const FUZZY_PENALTY_GAP: i64 = 1;
+
+/// fuzzy matches a text against a query, in which the query's characters must appear in
+/// the text in order (case-insensitively). Like skim's and fzf's matchers, the shortest
+/// match is preferred and the match is scored higher for characters matched consecutively
+/// or at the start of words.
+///
+/// Returns the match's score and the positions (in characters) of the matched characters.
+pub fn fuzzy_match(text: &str, query: &str) -> Option<(i64, Vec<usize>)> {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let text = text.chars().collect::<Vec<_>>();
+    let query = query.chars().map(lower).collect::<Vec<_>>();
+    if query.is_empty() {
+        return Some((0, vec![]));
+    }
+
+    // find the earliest end of a match, then the latest start of a match ending there
+    let mut n_matched = 0;
+    let end = text.iter().position(|&c| {
+        if lower(c) == query[n_matched] {
+            n_matched += 1;
+        }
+        n_matched == query.len()
+    })?;
+    let start = (0..=end)
+        .rev()
+        .find(|&i| {
+            if lower(text[i]) == query[n_matched - 1] {
+                n_matched -= 1;
+            }
+            n_matched == 0
+        })
+        .unwrap_or_default();
+
+    let mut score = 0;
+    let mut positions = Vec::with_capacity(query.len());
+    for (i, &c) in text.iter().enumerate().take(end + 1).skip(start) {
+        if positions.len() < query.len() && lower(c) == query[positions.len()] {
+            score += FUZZY_SCORE_MATCH;
+            if i == 0 || !text[i - 1].is_alphanumeric() {
+                score += FUZZY_BONUS_WORD_START;
+            }
+            if i > 0 && positions.last() == Some(&(i - 1)) {
+                score += FUZZY_BONUS_CONSECUTIVE;
+            }
+            positions.push(i);
+        } else {
+            score -= FUZZY_PENALTY_GAP;
+        }
+    }
+    Some((score, positions))
+}
+
+/// parses a range of years, which is either a single year (`1995`), a decade (`1990s`),
+/// or an inclusive range of years (`1990-1999`)
+pub fn parse_year_range(text: &str) -> Option<(i32, i32)> {
+    let text = text.trim();
+    if let Some(decade) = text.strip_suffix('s') {
+        let decade = decade.trim().parse::<i32>().ok()?;
+        return Some((decade, decade + 9));
+    }
+    parse_range(text)
+}
+
+/// parses a playback position, which is either a number of seconds (`90`),
+/// minutes and seconds (`1:30`), or hours, minutes and seconds (`1:02:30`)
+pub fn parse_position(text: &str) -> Option<chrono::Duration> {
+    let mut secs: i64 = 0;
+    for (i, part) in text.trim().split(':').enumerate() {
+        let value = part.trim().parse::<i64>().ok().filter(|v| *v >= 0)?;
+        // minutes and seconds of a multi-part position are less than 60
+        if i > 0 && value >= 60 {
+            return None;
+        }
+        secs = secs.checked_mul(60)?.checked_add(value)?;
+    }
+    chrono::Duration::try_seconds(secs)
+}
+
+/// parses a range of tempos in BPM, which is either a single tempo (`124`)
+/// or an inclusive range of tempos (`120-128`)
+pub fn parse_tempo_range(text: &str) -> Option<(u32, u32)> {
+    parse_range(text.trim().trim_end_matches("BPM").trim_end_matches("bpm"))
+}
+
+/// parses either a single value or an inclusive range of values (`from-to`)
+fn parse_range<T: std::str::FromStr + PartialOrd + Copy>(text: &str) -> Option<(T, T)> {
+    let text = text.trim();
+    match text.split_once(['-', '–']) {
+        Some((from, to)) => {
+            let (from, to) = (from.trim().parse().ok()?, to.trim().parse().ok()?);
+            (from <= to).then_some((from, to))
+        }
+        None => {
+            let value = text.parse().ok()?;
+            Some((value, value))
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub fn get_track_album_image_url(track: &rspotify::model::FullTrack) -> Option<&str> {
     if track.album.images.is_empty() {